@@ -19,9 +19,10 @@ use arrow::array::{Array, ArrayRef, BinaryViewArray, StringArray, StructArray};
 use arrow::util::test_util::seedable_rng;
 use arrow_schema::{DataType, Field, FieldRef, Fields};
 use criterion::{Criterion, criterion_group, criterion_main};
-use parquet_variant::{EMPTY_VARIANT_METADATA_BYTES, Variant, VariantBuilder};
+use parquet_variant::{EMPTY_VARIANT_METADATA_BYTES, Variant, VariantBuilder, VariantPath};
 use parquet_variant_compute::{
-    GetOptions, VariantArray, VariantArrayBuilder, json_to_variant, variant_get,
+    GetOptions, GetStrategy, VariantArray, VariantArrayBuilder, json_to_variant, variant_get,
+    variant_get_many,
 };
 use parquet_variant_json::append_json;
 use rand::Rng;
@@ -143,37 +144,153 @@ pub fn variant_get_bench(c: &mut Criterion) {
     let variant_array = create_primitive_variant_array(8192);
     let input = ArrayRef::from(variant_array);
 
-    let options = GetOptions {
-        path: vec![].into(),
-        as_type: None,
-        cast_options: Default::default(),
-    };
+    let options = GetOptions::new();
 
     c.bench_function("variant_get_primitive", |b| {
         b.iter(|| variant_get(&input.clone(), options.clone()))
     });
 }
 
+pub fn variant_get_primitive_as_int64_bench(c: &mut Criterion) {
+    let variant_array = create_primitive_variant_array(8192);
+    let input = ArrayRef::from(variant_array);
+
+    let field: FieldRef = Arc::new(Field::new("result", DataType::Int64, true));
+    let options = GetOptions::new().with_as_type(Some(field));
+
+    c.bench_function("variant_get_primitive_as_int64", |b| {
+        b.iter(|| variant_get(&input.clone(), options.clone()))
+    });
+}
+
 pub fn variant_get_shredded_utf8_bench(c: &mut Criterion) {
     let variant_array = create_shredded_utf8_variant_array(8192);
     let input = ArrayRef::from(variant_array);
 
     let field: FieldRef = Arc::new(Field::new("typed_value", DataType::Utf8, true));
-    let options = GetOptions {
-        path: vec![].into(),
-        as_type: Some(field),
-        cast_options: Default::default(),
-    };
+    let options = GetOptions::new().with_as_type(Some(field));
 
     c.bench_function("variant_get_shredded_utf8", |b| {
         b.iter(|| variant_get(&input.clone(), options.clone()))
     });
 }
 
+pub fn variant_get_strategy_bench(c: &mut Criterion) {
+    let json = (0..8192).map(|i| format!(r#"{{"a": {{"b": {{"c": {i}, "name": "row {i}"}}}}}}"#));
+    let json: ArrayRef = Arc::new(StringArray::from_iter_values(json));
+    let nested = ArrayRef::from(json_to_variant(&json).unwrap());
+    let primitive = ArrayRef::from(create_primitive_variant_array(8192));
+
+    let cases = [
+        ("top-level int64", &primitive, "$", DataType::Int64),
+        ("$.a.b.c int64", &nested, "$.a.b.c", DataType::Int64),
+        ("$.a.b.name utf8", &nested, "$.a.b.name", DataType::Utf8),
+    ];
+    for (name, input, path, data_type) in cases {
+        let field: FieldRef = Arc::new(Field::new("result", data_type, true));
+        for strategy in [GetStrategy::Rowwise, GetStrategy::Columnar] {
+            let options = GetOptions::new_with_path(VariantPath::try_from(path).unwrap())
+                .with_as_type(Some(field.clone()))
+                .with_strategy(strategy);
+            c.bench_function(&format!("variant_get {name} {strategy:?}"), |b| {
+                b.iter(|| variant_get(input, options.clone()).unwrap())
+            });
+        }
+    }
+}
+
+pub fn variant_get_field_shared_metadata_bench(c: &mut Criterion) {
+    // Wide objects whose rows all have the same fields, and so identical metadata dictionaries
+    let json = (0..8192).map(|i| {
+        let mut json = String::from("{");
+        for field in 0..32 {
+            write!(json, r#""field_{field}": {i},"#).unwrap();
+        }
+        write!(json, r#""nested": {{"value": {i}}}}}"#).unwrap();
+        json
+    });
+    let json: ArrayRef = Arc::new(StringArray::from_iter_values(json));
+    let input = ArrayRef::from(json_to_variant(&json).unwrap());
+
+    let field: FieldRef = Arc::new(Field::new("result", DataType::Int64, true));
+    let options = GetOptions::new_with_path(VariantPath::try_from("nested.value").unwrap())
+        .with_as_type(Some(field));
+
+    c.bench_function("variant_get_field_shared_metadata", |b| {
+        b.iter(|| variant_get(&input, options.clone()))
+    });
+}
+
+pub fn variant_get_many_bench(c: &mut Criterion) {
+    let json = (0..8192).map(|i| {
+        let mut json = String::from("{");
+        for field in 0..10 {
+            write!(json, r#""field_{field}": {i},"#).unwrap();
+        }
+        write!(json, r#""name": "row {i}"}}"#).unwrap();
+        json
+    });
+    let json: ArrayRef = Arc::new(StringArray::from_iter_values(json));
+    let input = ArrayRef::from(json_to_variant(&json).unwrap());
+
+    let field: FieldRef = Arc::new(Field::new("result", DataType::Int64, true));
+    let requests: Vec<_> = (0..10)
+        .map(|field_index| {
+            let path: VariantPath = format!("field_{field_index}").parse().unwrap();
+            GetOptions::new_with_path(path)
+                .with_as_type(Some(field.clone()))
+                .with_strategy(GetStrategy::Rowwise)
+        })
+        .collect();
+
+    c.bench_function("variant_get_many 10 fields individually", |b| {
+        b.iter(|| {
+            requests
+                .iter()
+                .map(|options| variant_get(&input, options.clone()).unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("variant_get_many 10 fields", |b| {
+        b.iter(|| variant_get_many(&input, &requests).unwrap())
+    });
+}
+
+pub fn variant_array_builder_copy_bench(c: &mut Criterion) {
+    let json: ArrayRef = Arc::new(StringArray::from_iter_values(json_repeated_struct(8192)));
+    let input = json_to_variant(&json).unwrap();
+
+    c.bench_function("variant_array_builder_copy append_variant", |b| {
+        b.iter(|| {
+            let mut builder = VariantArrayBuilder::new(input.len());
+            input
+                .iter()
+                .flatten()
+                .for_each(|v| builder.append_variant(v));
+            builder.build()
+        })
+    });
+    c.bench_function("variant_array_builder_copy append_variant_ref", |b| {
+        b.iter(|| {
+            let mut builder = VariantArrayBuilder::new(input.len());
+            input
+                .iter()
+                .flatten()
+                .for_each(|v| builder.append_variant_ref(&v));
+            builder.build()
+        })
+    });
+}
+
 criterion_group!(
     benches,
     variant_get_bench,
+    variant_get_primitive_as_int64_bench,
     variant_get_shredded_utf8_bench,
+    variant_get_strategy_bench,
+    variant_get_field_shared_metadata_bench,
+    variant_array_builder_copy_bench,
+    variant_get_many_bench,
     benchmark_batch_json_string_to_variant
 );
 criterion_main!(benches);
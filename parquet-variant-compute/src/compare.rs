@@ -0,0 +1,415 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for comparing the values of VariantArrays.
+
+use crate::{VariantArray, resolve_path, unshred_variant};
+use arrow::array::{BooleanArray, UInt32Array};
+use arrow::compute::SortOptions;
+use arrow::error::{ArrowError, Result};
+use parquet_variant::{Variant, VariantPath, variant_cmp, variant_partial_cmp};
+use std::cmp::Ordering;
+
+/// Compares two [`VariantArray`]s row by row, returning true where the values are equal.
+///
+/// Equality is semantic, as defined by [`variant_cmp`]: e.g. `Int64(1)` equals `Double(1.0)`,
+/// objects with the same fields are equal regardless of the order their fields were inserted in
+/// or their metadata dictionaries, and lists are equal if all their elements are. As with other
+/// Arrow comparison kernels, the result is null wherever either input row is null. A
+/// [`Variant::Null`] value is not a null row, and equals another `Variant::Null`.
+///
+/// # Errors
+/// - If `a` and `b` have different lengths
+/// - If either input is shredded and unshredding fails
+///
+/// [`Variant::Null`]: parquet_variant::Variant::Null
+pub fn variant_eq(a: &VariantArray, b: &VariantArray) -> Result<BooleanArray> {
+    if a.len() != b.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare VariantArrays of different lengths {} and {}",
+            a.len(),
+            b.len()
+        )));
+    }
+    let (a, b) = (unshred_variant(a)?, unshred_variant(b)?);
+    Ok(a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| Some(variant_cmp(&a?, &b?).is_eq()))
+        .collect())
+}
+
+/// Returns the indices that sort `array`, ordering values by [`variant_cmp`].
+///
+/// Null rows are placed first or last according to `options.nulls_first`, and `options.descending`
+/// reverses the order of the other rows. The sort is stable, so equal values (e.g. `Int64(1)` and
+/// `Double(1.0)`) keep their relative order. Pass the result to [`take_variant`] to produce the
+/// sorted array.
+///
+/// # Errors
+/// - If `array` is shredded and unshredding fails
+/// - If `array` has more than `u32::MAX` rows
+///
+/// [`take_variant`]: crate::take_variant
+pub fn sort_variant_to_indices(array: &VariantArray, options: SortOptions) -> Result<UInt32Array> {
+    let array = unshred_variant(array)?;
+    let len = u32::try_from(array.len())
+        .map_err(|_| ArrowError::ComputeError(format!("Cannot sort {} rows", array.len())))?;
+    let values: Vec<_> = array.iter().collect();
+    let mut indices: Vec<u32> = (0..len).collect();
+    indices.sort_by(|&a, &b| match (&values[a as usize], &values[b as usize]) {
+        (Some(a), Some(b)) if options.descending => variant_cmp(b, a),
+        (Some(a), Some(b)) => variant_cmp(a, b),
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) if options.nulls_first => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) if options.nulls_first => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+    });
+    Ok(UInt32Array::from(indices))
+}
+
+/// Compares the value found at `path` in every row of `array` with `scalar`, returning true where
+/// they are equal.
+///
+/// Values are compared with [`variant_partial_cmp`], so e.g. `Int64(1)` equals `Double(1.0)`. As
+/// with other Arrow comparison kernels, the result is null for null rows, rows where `path` does
+/// not resolve, and rows where the value is of a different kind than `scalar` (e.g. a string
+/// compared with a number). This includes [`Variant::Null`] values, unless `scalar` is also
+/// `Variant::Null`.
+///
+/// # Errors
+/// - If `path` contains a wildcard
+///
+/// # Example
+/// ```
+/// # use arrow::array::{ArrayRef, BooleanArray, StringArray};
+/// # use parquet_variant::{Variant, VariantPath};
+/// # use parquet_variant_compute::{json_to_variant, variant_gt_scalar};
+/// # use std::sync::Arc;
+/// let json: ArrayRef = Arc::new(StringArray::from(vec![
+///     r#"{"age": 30}"#,
+///     r#"{"age": 12}"#,
+///     r#"{"age": "unknown"}"#,
+///     r#"{"name": "x"}"#,
+/// ]));
+/// let array = json_to_variant(&json).unwrap();
+///
+/// let path = VariantPath::try_from("age").unwrap();
+/// let adults = variant_gt_scalar(&array, &path, &Variant::from(18)).unwrap();
+/// assert_eq!(adults, BooleanArray::from(vec![Some(true), Some(false), None, None]));
+/// ```
+pub fn variant_eq_scalar(
+    array: &VariantArray,
+    path: &VariantPath,
+    scalar: &Variant<'_, '_>,
+) -> Result<BooleanArray> {
+    compare_with_scalar(array, path, scalar, Ordering::is_eq)
+}
+
+/// Like [`variant_eq_scalar`], but returns true where the value at `path` differs from `scalar`.
+pub fn variant_neq_scalar(
+    array: &VariantArray,
+    path: &VariantPath,
+    scalar: &Variant<'_, '_>,
+) -> Result<BooleanArray> {
+    compare_with_scalar(array, path, scalar, Ordering::is_ne)
+}
+
+/// Like [`variant_eq_scalar`], but returns true where the value at `path` is less than `scalar`.
+pub fn variant_lt_scalar(
+    array: &VariantArray,
+    path: &VariantPath,
+    scalar: &Variant<'_, '_>,
+) -> Result<BooleanArray> {
+    compare_with_scalar(array, path, scalar, Ordering::is_lt)
+}
+
+/// Like [`variant_eq_scalar`], but returns true where the value at `path` is less than or equal
+/// to `scalar`.
+pub fn variant_lt_eq_scalar(
+    array: &VariantArray,
+    path: &VariantPath,
+    scalar: &Variant<'_, '_>,
+) -> Result<BooleanArray> {
+    compare_with_scalar(array, path, scalar, Ordering::is_le)
+}
+
+/// Like [`variant_eq_scalar`], but returns true where the value at `path` is greater than
+/// `scalar`.
+pub fn variant_gt_scalar(
+    array: &VariantArray,
+    path: &VariantPath,
+    scalar: &Variant<'_, '_>,
+) -> Result<BooleanArray> {
+    compare_with_scalar(array, path, scalar, Ordering::is_gt)
+}
+
+/// Like [`variant_eq_scalar`], but returns true where the value at `path` is greater than or
+/// equal to `scalar`.
+pub fn variant_gt_eq_scalar(
+    array: &VariantArray,
+    path: &VariantPath,
+    scalar: &Variant<'_, '_>,
+) -> Result<BooleanArray> {
+    compare_with_scalar(array, path, scalar, Ordering::is_ge)
+}
+
+/// Compares the value at `path` in every row of `array` with `scalar`, applying `op` to the
+/// ordering of the rows that can be compared
+fn compare_with_scalar(
+    array: &VariantArray,
+    path: &VariantPath,
+    scalar: &Variant<'_, '_>,
+    op: impl Fn(Ordering) -> bool,
+) -> Result<BooleanArray> {
+    let values = resolve_path(array, path)?;
+    Ok(values
+        .iter()
+        .map(|value| variant_partial_cmp(&value?, scalar).map(&op))
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{VariantArrayBuilder, json_to_variant, shred_variant};
+    use arrow::array::{ArrayRef, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use parquet_variant::{Variant, VariantBuilderExt};
+    use std::sync::Arc;
+
+    fn from_json(json: Vec<Option<&str>>) -> VariantArray {
+        let json: ArrayRef = Arc::new(StringArray::from(json));
+        json_to_variant(&json).unwrap()
+    }
+
+    #[test]
+    fn test_variant_eq() {
+        let a = from_json(vec![
+            Some("1"),
+            Some("1"),
+            Some(r#""one""#),
+            Some("[1, 2]"),
+            Some("[1, 2]"),
+            Some("null"),
+            Some("null"),
+            None,
+            None,
+        ]);
+        let b = from_json(vec![
+            Some("1.0"),
+            Some(r#""1""#),
+            Some(r#""one""#),
+            Some("[1.0, 2]"),
+            Some("[1, 2, 3]"),
+            Some("null"),
+            None,
+            Some("1"),
+            None,
+        ]);
+        let result = variant_eq(&a, &b).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(true),
+                None,
+                None,
+                None,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_variant_eq_objects() {
+        // Objects built with different field orders and metadata dictionaries
+        let mut a = VariantArrayBuilder::new(3);
+        a.new_object()
+            .with_field("x", 1i64)
+            .with_field("y", "s")
+            .finish();
+        a.new_object().with_field("x", 1i64).finish();
+        a.new_object().with_field("x", 1i64).finish();
+        let a = a.build();
+
+        let mut b = VariantArrayBuilder::new(3);
+        b.new_object()
+            .with_field("y", "s")
+            .with_field("x", 1.0)
+            .finish();
+        b.new_object()
+            .with_field("x", 1i8)
+            .with_field("y", Variant::Null)
+            .finish();
+        b.new_object().with_field("z", 1i64).finish();
+        let b = b.build();
+
+        let result = variant_eq(&a, &b).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, false, false]));
+
+        // Shredded inputs compare by value, with `x` in `typed_value` and `y` left in `value`
+        let shredded = shred_variant(
+            &a,
+            &DataType::Struct(vec![Field::new("x", DataType::Int64, true)].into()),
+        )
+        .unwrap();
+        let result = variant_eq(&shredded, &a).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, true, true]));
+        let result = variant_eq(&shredded, &b).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, false, false]));
+    }
+
+    #[test]
+    fn test_variant_eq_exact_numbers() {
+        // Integers are not rounded to doubles before comparing
+        let two_pow_53 = 1i64 << 53;
+        let a = VariantArray::from_iter([
+            Variant::Int64(two_pow_53 + 1),
+            Variant::Int64(two_pow_53),
+            Variant::Int64(i64::MAX),
+        ]);
+        let b = VariantArray::from_iter([
+            Variant::Double(two_pow_53 as f64),
+            Variant::Double(two_pow_53 as f64),
+            Variant::Double(2f64.powi(63)),
+        ]);
+        let result = variant_eq(&a, &b).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false, true, false]));
+    }
+
+    #[test]
+    fn test_sort_variant_to_indices() {
+        let array = from_json(vec![
+            Some(r#""b""#),
+            Some("2.5"),
+            None,
+            Some("null"),
+            Some("1"),
+            Some("true"),
+            Some("[1]"),
+            Some(r#""a""#),
+            Some("1.0"),
+        ]);
+
+        let sort = |descending, nulls_first| {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            let indices = sort_variant_to_indices(&array, options).unwrap();
+            indices.values().to_vec()
+        };
+        assert_eq!(sort(false, true), vec![2, 3, 5, 4, 8, 1, 7, 0, 6]);
+        assert_eq!(sort(false, false), vec![3, 5, 4, 8, 1, 7, 0, 6, 2]);
+        assert_eq!(sort(true, true), vec![2, 6, 0, 7, 1, 4, 8, 5, 3]);
+        assert_eq!(sort(true, false), vec![6, 0, 7, 1, 4, 8, 5, 3, 2]);
+
+        // Composes with take_variant to produce the sorted array
+        let indices = sort_variant_to_indices(&array, SortOptions::default()).unwrap();
+        let sorted = crate::take_variant(&array, &indices).unwrap();
+        assert!(sorted.is_null(0));
+        assert_eq!(sorted.value(1), Variant::Null);
+        assert!(matches!(sorted.value(8), Variant::List(_)));
+        assert_eq!(sorted.value(7), Variant::from("b"));
+    }
+
+    #[test]
+    fn test_sort_variant_to_indices_large_numbers() {
+        // These values all round to the same double, but are still ordered exactly
+        let array = VariantArray::from_iter([
+            Variant::Double(2f64.powi(63)),
+            Variant::Int64(i64::MAX),
+            Variant::Int64(i64::MAX - 1),
+            Variant::Int64(i64::MAX),
+            Variant::Double(2f64.powi(63)),
+            Variant::Int64(i64::MAX - 1),
+        ]);
+        let indices = sort_variant_to_indices(&array, SortOptions::default()).unwrap();
+        assert_eq!(indices.values().to_vec(), vec![2, 5, 1, 3, 0, 4]);
+    }
+
+    #[test]
+    fn test_compare_path_with_scalar() {
+        let array = from_json(vec![
+            Some(r#"{"age": 30}"#),
+            Some(r#"{"age": 18}"#),
+            Some(r#"{"age": 12.5}"#),
+            Some(r#"{"age": "unknown"}"#),
+            Some(r#"{"age": null}"#),
+            Some(r#"{"name": "x"}"#),
+            None,
+        ]);
+        let path = VariantPath::try_from("age").unwrap();
+        let eighteen = Variant::from(18i64);
+
+        let gt = variant_gt_scalar(&array, &path, &eighteen).unwrap();
+        let expected = |values: [bool; 3]| {
+            let mut expected: Vec<_> = values.into_iter().map(Some).collect();
+            expected.extend([None, None, None, None]);
+            BooleanArray::from(expected)
+        };
+        assert_eq!(gt, expected([true, false, false]));
+        let gt_eq = variant_gt_eq_scalar(&array, &path, &eighteen).unwrap();
+        assert_eq!(gt_eq, expected([true, true, false]));
+        let lt = variant_lt_scalar(&array, &path, &eighteen).unwrap();
+        assert_eq!(lt, expected([false, false, true]));
+        let lt_eq = variant_lt_eq_scalar(&array, &path, &eighteen).unwrap();
+        assert_eq!(lt_eq, expected([false, true, true]));
+        let eq = variant_eq_scalar(&array, &path, &eighteen).unwrap();
+        assert_eq!(eq, expected([false, true, false]));
+        let neq = variant_neq_scalar(&array, &path, &eighteen).unwrap();
+        assert_eq!(neq, expected([true, false, true]));
+
+        // Comparing with a string only resolves the string-valued row
+        let result = variant_eq_scalar(&array, &path, &Variant::from("unknown")).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![None, None, None, Some(true), None, None, None])
+        );
+
+        // Shredded inputs compare by value
+        let shredded = shred_variant(
+            &array,
+            &DataType::Struct(vec![Field::new("age", DataType::Int64, true)].into()),
+        )
+        .unwrap();
+        assert_eq!(variant_gt_scalar(&shredded, &path, &eighteen).unwrap(), gt);
+    }
+
+    #[test]
+    fn test_compare_path_with_scalar_wildcard() {
+        let array = from_json(vec![Some("[1, 2]")]);
+        let path = VariantPath::try_from("$[*]").unwrap();
+        let err = variant_gt_scalar(&array, &path, &Variant::from(1)).unwrap_err();
+        assert!(err.to_string().contains("Wildcard paths are not supported"));
+    }
+
+    #[test]
+    fn test_variant_eq_length_mismatch() {
+        let a = from_json(vec![Some("1")]);
+        let b = from_json(vec![Some("1"), Some("2")]);
+        let err = variant_eq(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare VariantArrays of different lengths 1 and 2"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for concatenating multiple VariantArrays into one.
+
+use crate::{VariantArray, unshred_variant};
+use arrow::array::Array;
+use arrow::compute::concat;
+use arrow::error::{ArrowError, Result};
+
+/// Concatenates multiple [`VariantArray`]s into a single [`VariantArray`].
+///
+/// Every row of a `VariantArray` carries its own metadata dictionary, so rows from different
+/// inputs keep resolving their field names correctly after concatenation.
+///
+/// Inputs that all share the same (possibly shredded) layout are concatenated as-is. Otherwise,
+/// every input is unshredded first (see [`unshred_variant`]) so that the layouts match, and the
+/// result is unshredded.
+///
+/// # Errors
+/// - If `arrays` is empty
+/// - If unshredding fails
+pub fn concat_variant_arrays(arrays: &[&VariantArray]) -> Result<VariantArray> {
+    let Some(first) = arrays.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "concat_variant_arrays requires at least one array".to_string(),
+        ));
+    };
+
+    let concatenated = if arrays.iter().all(|a| a.data_type() == first.data_type()) {
+        let inner: Vec<&dyn Array> = arrays.iter().map(|a| a.inner() as _).collect();
+        concat(&inner)?
+    } else {
+        // Rebuild each unshredded array so that field nullability also matches
+        let unshredded = arrays
+            .iter()
+            .map(|a| {
+                let a = unshred_variant(a)?;
+                Ok(VariantArray::from_parts(
+                    a.metadata_field().clone(),
+                    a.value_field().cloned(),
+                    None,
+                    a.nulls().cloned(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let inner: Vec<&dyn Array> = unshredded.iter().map(|a| a.inner() as _).collect();
+        concat(&inner)?
+    };
+
+    VariantArray::try_new(&concatenated)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{VariantArrayBuilder, shred_variant};
+    use arrow::datatypes::{DataType, Field};
+    use parquet_variant::{Variant, VariantBuilderExt};
+
+    /// Builds a VariantArray of objects with a single field `name`
+    fn objects(name: &str, values: &[Option<i32>]) -> VariantArray {
+        let mut builder = VariantArrayBuilder::new(values.len());
+        for value in values {
+            match value {
+                Some(value) => builder.new_object().with_field(name, *value).finish(),
+                None => builder.append_null(),
+            }
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn test_concat_different_metadata() {
+        let a = objects("a", &[Some(1), None]);
+        let b = objects("b", &[Some(2), Some(3)]);
+
+        let result = concat_variant_arrays(&[&a, &b]).unwrap();
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.nulls().unwrap().null_count(), 1);
+
+        let field = |i: usize, name: &str| result.value(i).get_object_field(name);
+        assert_eq!(field(0, "a"), Some(Variant::Int32(1)));
+        assert!(result.is_null(1));
+        assert_eq!(field(2, "b"), Some(Variant::Int32(2)));
+        assert_eq!(field(3, "b"), Some(Variant::Int32(3)));
+        assert_eq!(field(3, "a"), None);
+    }
+
+    #[test]
+    fn test_concat_mixed_shredding() {
+        let a = objects("a", &[Some(1), Some(2)]);
+        let b = objects("a", &[None, Some(3)]);
+        let shredding = DataType::Struct(vec![Field::new("a", DataType::Int32, true)].into());
+        let b = shred_variant(&b, &shredding).unwrap();
+        assert_ne!(a.data_type(), b.data_type());
+
+        let result = concat_variant_arrays(&[&a, &b]).unwrap();
+        assert!(result.typed_value_field().is_none());
+        let values: Vec<_> = result
+            .iter()
+            .map(|v| v.and_then(|v| v.get_object_field("a")))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                Some(Variant::Int32(1)),
+                Some(Variant::Int32(2)),
+                None,
+                Some(Variant::Int32(3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_concat_empty() {
+        let err = concat_variant_arrays(&[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: concat_variant_arrays requires at least one array"
+        );
+    }
+}
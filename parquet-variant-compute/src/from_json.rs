@@ -20,40 +20,62 @@
 
 use crate::{VariantArray, VariantArrayBuilder};
 use arrow::array::{Array, ArrayRef, LargeStringArray, StringArray, StringViewArray};
+use arrow::compute::CastOptions;
 use arrow_schema::ArrowError;
-use parquet_variant_json::JsonToVariant;
+use parquet_variant_json::append_json;
+use serde_json::Value;
 
 /// Macro to convert string array to variant array
 macro_rules! string_array_to_variant {
-    ($input:expr, $array:expr, $builder:expr) => {{
-        for i in 0..$input.len() {
-            if $input.is_null(i) {
-                $builder.append_null();
-            } else {
-                $builder.append_json($array.value(i))?;
+    ($array:expr, $builder:expr, $options:expr) => {{
+        for (i, json) in $array.iter().enumerate() {
+            match json {
+                None | Some("") => $builder.append_null(),
+                Some(json) => match serde_json::from_str::<Value>(json) {
+                    Ok(json) => append_json(&json, &mut $builder)?,
+                    Err(_) if $options.safe => $builder.append_null(),
+                    Err(e) => {
+                        return Err(ArrowError::InvalidArgumentError(format!(
+                            "row {i}: JSON format error: {e}"
+                        )));
+                    }
+                },
             }
         }
     }};
 }
 
 /// Parse a batch of JSON strings into a batch of Variants represented as
-/// STRUCT<metadata: BINARY, value: BINARY> where nulls are preserved. The JSON strings in the input
-/// must be valid.
+/// STRUCT<metadata: BINARY, value: BINARY> where nulls are preserved. Empty strings also produce
+/// nulls, and invalid JSON is an error that reports the offending row.
 ///
 /// Supports the following string array types:
 /// - [`StringArray`]
 /// - [`LargeStringArray`]
 /// - [`StringViewArray`]
 pub fn json_to_variant(input: &ArrayRef) -> Result<VariantArray, ArrowError> {
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    json_to_variant_with_options(input, &options)
+}
+
+/// Like [`json_to_variant`], but if `options.safe` is true, invalid JSON produces a null instead
+/// of an error.
+pub fn json_to_variant_with_options(
+    input: &ArrayRef,
+    options: &CastOptions,
+) -> Result<VariantArray, ArrowError> {
     let mut variant_array_builder = VariantArrayBuilder::new(input.len());
 
     // Try each string array type in sequence
     if let Some(string_array) = input.as_any().downcast_ref::<StringArray>() {
-        string_array_to_variant!(input, string_array, variant_array_builder);
+        string_array_to_variant!(string_array, variant_array_builder, options);
     } else if let Some(large_string_array) = input.as_any().downcast_ref::<LargeStringArray>() {
-        string_array_to_variant!(input, large_string_array, variant_array_builder);
+        string_array_to_variant!(large_string_array, variant_array_builder, options);
     } else if let Some(string_view_array) = input.as_any().downcast_ref::<StringViewArray>() {
-        string_array_to_variant!(input, string_view_array, variant_array_builder);
+        string_array_to_variant!(string_view_array, variant_array_builder, options);
     } else {
         return Err(ArrowError::CastError(
             "Expected reference to StringArray, LargeStringArray, or StringViewArray as input"
@@ -66,8 +88,9 @@ pub fn json_to_variant(input: &ArrayRef) -> Result<VariantArray, ArrowError> {
 
 #[cfg(test)]
 mod test {
-    use crate::json_to_variant;
+    use crate::{json_to_variant, json_to_variant_with_options};
     use arrow::array::{Array, ArrayRef, LargeStringArray, StringArray, StringViewArray};
+    use arrow::compute::CastOptions;
     use arrow_schema::ArrowError;
     use parquet_variant::{Variant, VariantBuilder};
     use std::sync::Arc;
@@ -218,4 +241,35 @@ mod test {
         assert!(!value_array.is_null(4));
         Ok(())
     }
+
+    #[test]
+    fn test_json_to_variant_empty_and_invalid() {
+        let input: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("[1, true]"),
+            Some(""),
+            Some("{\"a\": "),
+            None,
+        ]));
+
+        let err = json_to_variant(&input).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Invalid argument error: row 2: JSON format error:"),
+            "{err}"
+        );
+
+        let options = CastOptions {
+            safe: true,
+            ..Default::default()
+        };
+        let variant_array = json_to_variant_with_options(&input, &options).unwrap();
+        assert_eq!(variant_array.len(), 4);
+        let list = variant_array.value(0);
+        let list = list.as_list().expect("list");
+        assert_eq!(list.get(0), Some(Variant::Int8(1)));
+        assert_eq!(list.get(1), Some(Variant::BooleanTrue));
+        assert!(variant_array.is_null(1));
+        assert!(variant_array.is_null(2));
+        assert!(variant_array.is_null(3));
+    }
 }
@@ -0,0 +1,323 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for inferring an Arrow schema from the values of a VariantArray.
+
+use crate::{VariantArray, unshred_variant};
+use arrow::datatypes::{DataType, Field, Fields, TimeUnit};
+use arrow::error::Result;
+use indexmap::IndexMap;
+use parquet_variant::Variant;
+use std::sync::Arc;
+
+/// Infers the fields of a struct type that describes the objects found in `array`.
+///
+/// Only the first `max_rows` rows are scanned (all rows if `None`), and the rest are never read,
+/// even to unshred them. The keys of all objects are unioned, in the order they are first seen,
+/// and each field gets the narrowest type that can hold every value seen for it:
+///
+/// - Integers widen to the largest width seen, e.g. `Int32` and `Int64` produce `Int64`
+/// - Other mixes of numeric types produce `Float64`
+/// - Timestamps widen from microsecond to nanosecond precision
+/// - Nested objects produce nested structs, and lists produce lists of the unified element type
+/// - Any other mix of types, e.g. numbers and strings, falls back to `Utf8`, as do fields that
+///   only ever hold `null`
+///
+/// Null rows, and rows that are not objects, are skipped. All fields are nullable, since any field
+/// may be missing from some rows. The result can be passed to [`shred_variant`] as a
+/// [`DataType::Struct`] to shred every field of `array`.
+///
+/// [`shred_variant`]: crate::shred_variant
+pub fn infer_schema(array: &VariantArray, max_rows: Option<usize>) -> Result<Fields> {
+    let len = max_rows.map_or(array.len(), |max_rows| max_rows.min(array.len()));
+    let array = unshred_variant(&array.slice(0, len))?;
+    let mut fields = IndexMap::new();
+    for value in array.iter() {
+        if let Some(Variant::Object(object)) = value {
+            merge_object_fields(&mut fields, object.iter());
+        }
+    }
+    Ok(to_fields(fields))
+}
+
+/// The type inferred so far for the values at some position
+#[derive(Debug, Clone, PartialEq)]
+enum InferredType {
+    /// Only `null` values have been seen
+    Null,
+    Primitive(DataType),
+    Object(IndexMap<String, InferredType>),
+    List(Box<InferredType>),
+    /// Incompatible values have been seen
+    Conflict,
+}
+
+impl InferredType {
+    fn merge(&mut self, variant: &Variant<'_, '_>) {
+        match (&mut *self, variant) {
+            (_, Variant::Null) | (Self::Conflict, _) => {}
+            (Self::Object(fields), Variant::Object(object)) => {
+                merge_object_fields(fields, object.iter())
+            }
+            (Self::List(element), Variant::List(list)) => {
+                list.iter().for_each(|value| element.merge(&value))
+            }
+            (Self::Null, _) => {
+                *self = Self::from_variant(variant);
+            }
+            (Self::Primitive(current), _) => {
+                *self = primitive_type(variant)
+                    .and_then(|other| widen(current, &other))
+                    .map_or(Self::Conflict, Self::Primitive);
+            }
+            (Self::Object(_) | Self::List(_), _) => *self = Self::Conflict,
+        }
+    }
+
+    fn from_variant(variant: &Variant<'_, '_>) -> Self {
+        let mut inferred = match variant {
+            Variant::Object(_) => Self::Object(IndexMap::new()),
+            Variant::List(_) => Self::List(Box::new(Self::Null)),
+            _ => match primitive_type(variant) {
+                Some(data_type) => return Self::Primitive(data_type),
+                None => return Self::Null,
+            },
+        };
+        inferred.merge(variant);
+        inferred
+    }
+
+    fn into_data_type(self) -> DataType {
+        match self {
+            Self::Null | Self::Conflict => DataType::Utf8,
+            Self::Primitive(data_type) => data_type,
+            Self::Object(fields) => DataType::Struct(to_fields(fields)),
+            Self::List(element) => DataType::List(Arc::new(Field::new_list_field(
+                element.into_data_type(),
+                true,
+            ))),
+        }
+    }
+}
+
+fn merge_object_fields<'m, 'v>(
+    fields: &mut IndexMap<String, InferredType>,
+    object: impl Iterator<Item = (&'m str, Variant<'m, 'v>)>,
+) {
+    for (name, value) in object {
+        match fields.get_mut(name) {
+            Some(inferred) => inferred.merge(&value),
+            None => {
+                fields.insert(name.to_string(), InferredType::from_variant(&value));
+            }
+        }
+    }
+}
+
+fn to_fields(fields: IndexMap<String, InferredType>) -> Fields {
+    fields
+        .into_iter()
+        .map(|(name, inferred)| Field::new(name, inferred.into_data_type(), true))
+        .collect()
+}
+
+/// The Arrow type of a primitive variant value, or `None` for null, objects and lists
+fn primitive_type(variant: &Variant<'_, '_>) -> Option<DataType> {
+    let data_type = match variant {
+        Variant::Null | Variant::Object(_) | Variant::List(_) => return None,
+        Variant::BooleanTrue | Variant::BooleanFalse => DataType::Boolean,
+        Variant::Int8(_) => DataType::Int8,
+        Variant::Int16(_) => DataType::Int16,
+        Variant::Int32(_) => DataType::Int32,
+        Variant::Int64(_) => DataType::Int64,
+        Variant::Float(_) => DataType::Float32,
+        Variant::Double(_) => DataType::Float64,
+        Variant::Decimal4(d) => DataType::Decimal32(9, d.scale() as i8),
+        Variant::Decimal8(d) => DataType::Decimal64(18, d.scale() as i8),
+        Variant::Decimal16(d) => DataType::Decimal128(38, d.scale() as i8),
+        Variant::Date(_) => DataType::Date32,
+        Variant::Time(_) => DataType::Time64(TimeUnit::Microsecond),
+        Variant::TimestampMicros(_) => {
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        }
+        Variant::TimestampNtzMicros(_) => DataType::Timestamp(TimeUnit::Microsecond, None),
+        Variant::TimestampNanos(_) => DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+        Variant::TimestampNtzNanos(_) => DataType::Timestamp(TimeUnit::Nanosecond, None),
+        Variant::Binary(_) => DataType::Binary,
+        Variant::String(_) | Variant::ShortString(_) => DataType::Utf8,
+        Variant::Uuid(_) => DataType::FixedSizeBinary(16),
+    };
+    Some(data_type)
+}
+
+/// The narrowest type that can hold values of both `a` and `b`, if any
+fn widen(a: &DataType, b: &DataType) -> Option<DataType> {
+    use DataType::*;
+    if a == b {
+        return Some(a.clone());
+    }
+    let widened = match (a, b) {
+        (Int8 | Int16 | Int32 | Int64, Int8 | Int16 | Int32 | Int64) => {
+            std::cmp::max_by_key(a, b, |t| t.primitive_width()).clone()
+        }
+        (
+            Decimal32(_, s1) | Decimal64(_, s1) | Decimal128(_, s1),
+            Decimal32(_, s2) | Decimal64(_, s2) | Decimal128(_, s2),
+        ) if s1 == s2 => std::cmp::max_by_key(a, b, |t| t.primitive_width()).clone(),
+        (Timestamp(_, tz1), Timestamp(_, tz2)) if tz1 == tz2 => {
+            Timestamp(TimeUnit::Nanosecond, tz1.clone())
+        }
+        _ if a.is_numeric() && b.is_numeric() => Float64,
+        _ => return None,
+    };
+    Some(widened)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{json_to_variant, shred_variant};
+    use arrow::array::{ArrayRef, BinaryViewArray, StringArray, Time64MicrosecondArray};
+    use arrow::datatypes::Field;
+    use parquet_variant::{EMPTY_VARIANT_METADATA_BYTES, VariantBuilder};
+
+    fn infer(json: &[&str], max_rows: Option<usize>) -> Fields {
+        let json: ArrayRef = Arc::new(StringArray::from(json.to_vec()));
+        infer_schema(&json_to_variant(&json).unwrap(), max_rows).unwrap()
+    }
+
+    #[test]
+    fn test_infer_schema() {
+        let fields = infer(
+            &[
+                r#"{"id": 1, "name": "a", "score": 1}"#,
+                r#"{"id": 10000000000, "score": 1.5, "tags": ["x", null]}"#,
+                r#"{"mixed": 1, "nested": {"flag": true}, "tags": []}"#,
+                r#"{"mixed": "one", "nested": {"count": 2}, "empty": null}"#,
+                r#"[1, 2, 3]"#,
+                "null",
+            ],
+            None,
+        );
+
+        let expected = Fields::from(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("score", DataType::Float64, true),
+            Field::new_list("tags", Field::new_list_field(DataType::Utf8, true), true),
+            Field::new("mixed", DataType::Utf8, true),
+            Field::new_struct(
+                "nested",
+                vec![
+                    Field::new("flag", DataType::Boolean, true),
+                    Field::new("count", DataType::Int8, true),
+                ],
+                true,
+            ),
+            Field::new("empty", DataType::Utf8, true),
+        ]);
+        assert_eq!(fields, expected);
+    }
+
+    #[test]
+    fn test_infer_schema_max_rows_limits_unshredding() {
+        // Row 0 is an object, and row 1 is shredded as a time that is out of range
+        let mut builder = VariantBuilder::new();
+        builder.new_object().with_field("a", 1i8).finish();
+        let (metadata, value) = builder.finish();
+        let array = VariantArray::from_parts(
+            BinaryViewArray::from(vec![metadata.as_slice(), EMPTY_VARIANT_METADATA_BYTES]),
+            Some(BinaryViewArray::from(vec![Some(value.as_slice()), None])),
+            Some(Arc::new(Time64MicrosecondArray::from(vec![None, Some(-1)]))),
+            None,
+        );
+
+        // Rows past the cap are not even unshredded
+        let fields = infer_schema(&array, Some(1)).unwrap();
+        assert_eq!(
+            fields,
+            Fields::from(vec![Field::new("a", DataType::Int8, true)])
+        );
+        let err = infer_schema(&array, None).unwrap_err();
+        assert!(err.to_string().contains("Invalid Time64"), "{err}");
+    }
+
+    #[test]
+    fn test_infer_schema_max_rows() {
+        let json = [r#"{"a": 1}"#, r#"{"a": 1000, "b": true}"#];
+        let fields = infer(&json, Some(1));
+        assert_eq!(
+            fields,
+            Fields::from(vec![Field::new("a", DataType::Int8, true)])
+        );
+
+        let fields = infer(&json, None);
+        assert_eq!(
+            fields,
+            Fields::from(vec![
+                Field::new("a", DataType::Int16, true),
+                Field::new("b", DataType::Boolean, true),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_widen() {
+        use DataType::*;
+        let utc = Some("UTC".into());
+        assert_eq!(widen(&Int32, &Int8), Some(Int32));
+        assert_eq!(widen(&Int16, &Float32), Some(Float64));
+        assert_eq!(
+            widen(&Decimal32(9, 2), &Decimal64(18, 2)),
+            Some(Decimal64(18, 2))
+        );
+        assert_eq!(widen(&Decimal32(9, 2), &Decimal32(9, 3)), Some(Float64));
+        assert_eq!(
+            widen(
+                &Timestamp(TimeUnit::Nanosecond, utc.clone()),
+                &Timestamp(TimeUnit::Microsecond, utc.clone())
+            ),
+            Some(Timestamp(TimeUnit::Nanosecond, utc.clone()))
+        );
+        assert_eq!(
+            widen(
+                &Timestamp(TimeUnit::Microsecond, utc),
+                &Timestamp(TimeUnit::Microsecond, None)
+            ),
+            None
+        );
+        assert_eq!(widen(&Int64, &Utf8), None);
+        assert_eq!(widen(&Boolean, &Int8), None);
+    }
+
+    #[test]
+    fn test_infer_schema_shreds() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"id": 1, "name": "a"}"#),
+            Some(r#"{"id": 2, "name": 3}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let fields = infer_schema(&array, None).unwrap();
+
+        let shredded = shred_variant(&array, &DataType::Struct(fields)).unwrap();
+        let inferred = infer_schema(&shredded, None).unwrap();
+        assert_eq!(inferred, infer_schema(&array, None).unwrap());
+        assert_eq!(shredded.len(), 3);
+        assert!(shredded.typed_value_field().is_some());
+    }
+}
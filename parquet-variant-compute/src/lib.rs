@@ -24,10 +24,32 @@
 //! # Compute Kernels
 //! - [`json_to_variant()`]: Function to convert Arrays of JSON strings to a `VariantArray`.
 //! - [`variant_to_json()`]: Function to convert a `VariantArray` to arrays of JSON strings.
+//! - [`variant_to_json_pretty()`]: Like `variant_to_json`, but with indented multi-line output.
+//! - [`write_variant_json()`]: Stream a `VariantArray` to a writer as delimited JSON.
 //! - [`cast_to_variant()`]: Cast Arrow arrays to `VariantArray`.
 //! - [`variant_get()`]: Convert `VariantArray` (or an inner path) to a strongly-typed Arrow array.
+//! - [`variant_get_scalar()`]: Like `variant_get`, but for a single `Variant`.
+//! - [`variant_get_many()`]: Like `variant_get`, but extracts several paths in one pass.
+//! - [`variant_get_with_stats()`]: Like `variant_get`, but also counts how the path resolved.
+//! - [`compile_variant_get()`]: Validate `variant_get` options once to run them on many batches.
+//! - [`cast_variant()`]: Cast a whole `VariantArray` to a strongly-typed Arrow array.
 //! - [`shred_variant()`]: Shred a `VariantArray` according to the provided shredding schema
 //! - [`unshred_variant()`]: Unshred a `VariantArray` to pure binary variant.
+//! - [`infer_schema()`]: Infer a unified struct schema from the objects in a `VariantArray`.
+//! - [`concat_variant_arrays()`]: Concatenate multiple `VariantArray`s.
+//! - [`variant_eq()`]: Compare two `VariantArray`s for semantic equality row by row.
+//! - [`sort_variant_to_indices()`]: Get the indices that sort a `VariantArray`.
+//! - [`variant_eq_scalar()`], [`variant_gt_scalar()`], etc.: Compare the value at a path with a
+//!   scalar.
+//! - [`filter_variant()`]: Select the rows of a `VariantArray` where a boolean mask is true.
+//! - [`take_variant()`]: Gather the rows of a `VariantArray` at the given indices.
+//! - [`variant_type_of()`]: Report the top-level type of each row of a `VariantArray`.
+//! - [`resolve_path()`]: Get the value at a path in each row, as a `VariantArray`.
+//! - [`explain_path()`]: Report how far a path resolves in a row, and why it stopped.
+//! - [`variant_is_null()`]: Test whether a path is missing or `Variant::Null` in each row.
+//! - [`variant_array_length()`]: Get the length of the list at a path in each row.
+//! - [`variant_object_keys()`]: List the field names of the object at a path in each row.
+//! - [`variant_contains_key()`]: Test whether the object at a path has a given field in each row.
 //!
 //! ## 🚧 Work In Progress
 //!
@@ -41,7 +63,12 @@
 
 mod arrow_to_variant;
 mod cast_to_variant;
+mod compare;
+mod concat;
 mod from_json;
+mod infer_schema;
+mod path_kernels;
+mod select;
 mod shred_variant;
 mod to_json;
 mod type_conversion;
@@ -50,13 +77,31 @@ mod variant_array;
 mod variant_array_builder;
 mod variant_get;
 mod variant_to_arrow;
+mod variant_type_of;
 
-pub use variant_array::{BorrowedShreddingState, ShreddingState, VariantArray, VariantType};
+pub use variant_array::{
+    BorrowedShreddingState, ShreddingState, VariantArray, VariantStats, VariantType,
+};
 pub use variant_array_builder::{VariantArrayBuilder, VariantValueArrayBuilder};
 
 pub use cast_to_variant::{cast_to_variant, cast_to_variant_with_options};
-pub use from_json::json_to_variant;
+pub use compare::{
+    sort_variant_to_indices, variant_eq, variant_eq_scalar, variant_gt_eq_scalar,
+    variant_gt_scalar, variant_lt_eq_scalar, variant_lt_scalar, variant_neq_scalar,
+};
+pub use concat::concat_variant_arrays;
+pub use from_json::{json_to_variant, json_to_variant_with_options};
+pub use infer_schema::infer_schema;
+pub use path_kernels::{
+    PathResolution, PathStop, explain_path, resolve_path, variant_array_length,
+    variant_contains_key, variant_contains_key_with_options, variant_is_null, variant_object_keys,
+};
+pub use select::{filter_variant, take_variant};
 pub use shred_variant::{IntoShreddingField, ShreddedSchemaBuilder, shred_variant};
-pub use to_json::variant_to_json;
+pub use to_json::{variant_to_json, variant_to_json_pretty, write_variant_json};
 pub use unshred_variant::unshred_variant;
-pub use variant_get::{GetOptions, variant_get};
+pub use variant_get::{
+    GetOptions, GetStrategy, MissingBehavior, ResolutionStats, VariantGetPlan, cast_variant,
+    compile_variant_get, variant_get, variant_get_many, variant_get_scalar, variant_get_with_stats,
+};
+pub use variant_type_of::variant_type_of;
@@ -0,0 +1,572 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for kernels that inspect the value found at a path in each row of a VariantArray.
+
+use crate::variant_type_of::type_name;
+use crate::{GetOptions, VariantArray, unshred_variant, variant_get};
+use arrow::array::{ArrayRef, BooleanArray, Int32Array, ListArray, ListBuilder, StringBuilder};
+use arrow::error::{ArrowError, Result};
+use parquet_variant::{Variant, VariantPath, VariantPathElement};
+
+/// Resolves `path` in every row of `array`, returning the values it leads to.
+///
+/// Rows where `path` does not resolve are null; use [`VariantArray::nulls`] for the validity and
+/// [`VariantArray::value`] to read each resolved value. The result is always unshredded, so it is
+/// a convenient starting point for custom kernels that inspect the values at a path row by row.
+///
+/// The values are returned as an array rather than as offsets into the `value` column of `array`,
+/// because values in shredded columns are not stored at any offset of it: they are rebuilt from
+/// `typed_value` instead.
+///
+/// # Errors
+/// - If `path` contains a wildcard
+///
+/// # Example
+/// ```
+/// # use arrow::array::{Array, ArrayRef, StringArray};
+/// # use parquet_variant::{Variant, VariantPath};
+/// # use parquet_variant_compute::{json_to_variant, resolve_path};
+/// # use std::sync::Arc;
+/// let json: ArrayRef = Arc::new(StringArray::from(vec![
+///     r#"{"a": {"b": 1}}"#,
+///     r#"{"a": 2}"#,
+/// ]));
+/// let array = json_to_variant(&json).unwrap();
+///
+/// let values = resolve_path(&array, &VariantPath::try_from("a.b").unwrap()).unwrap();
+/// assert_eq!(values.value(0), Variant::from(1i8));
+/// assert!(values.is_null(1));
+/// ```
+pub fn resolve_path(array: &VariantArray, path: &VariantPath) -> Result<VariantArray> {
+    reject_wildcards(path)?;
+
+    let input = ArrayRef::from(array.clone());
+    let result = variant_get(&input, GetOptions::new_with_path(path.clone()))?;
+    unshred_variant(&VariantArray::try_new(&result)?)
+}
+
+/// Returns true for every row of `array` where `path` resolves to [`Variant::Null`] or does not
+/// resolve at all.
+///
+/// This is the SQL notion of `v:path IS NULL`: null rows of `array` are also reported as true, and
+/// the result itself never contains nulls. To tell null rows apart, use [`VariantArray::nulls`].
+///
+/// # Errors
+/// - If `path` contains a wildcard
+pub fn variant_is_null(array: &VariantArray, path: &VariantPath) -> Result<BooleanArray> {
+    let values = resolve_path(array, path)?;
+    Ok(values
+        .iter()
+        .map(|value| Some(matches!(value, None | Some(Variant::Null))))
+        .collect())
+}
+
+/// Returns the number of elements of the list found at `path` in every row of `array`.
+///
+/// Rows where `path` does not resolve, or resolves to something other than a list, are null.
+///
+/// # Errors
+/// - If `path` contains a wildcard
+/// - If a list has more than `i32::MAX` elements
+pub fn variant_array_length(array: &VariantArray, path: &VariantPath) -> Result<Int32Array> {
+    let values = resolve_path(array, path)?;
+    values
+        .iter()
+        .map(|value| match value {
+            Some(Variant::List(list)) => i32::try_from(list.len()).map(Some).map_err(|_| {
+                ArrowError::ComputeError(format!("List length {} overflows Int32", list.len()))
+            }),
+            _ => Ok(None),
+        })
+        .collect()
+}
+
+/// Returns the field names of the object found at `path` in every row of `array`.
+///
+/// Names are listed in the order of the row's metadata dictionary, which is typically the order
+/// in which field names were first inserted, so objects that share a dictionary list their common
+/// fields in the same order. Rows where `path` does not resolve, or resolves to something other
+/// than an object, are null.
+///
+/// # Errors
+/// - If `path` contains a wildcard
+pub fn variant_object_keys(array: &VariantArray, path: &VariantPath) -> Result<ListArray> {
+    let values = resolve_path(array, path)?;
+    let mut builder = ListBuilder::with_capacity(StringBuilder::new(), values.len());
+    for value in values.iter() {
+        match value {
+            Some(Variant::Object(object)) => {
+                // Objects store their fields sorted by name, so order them by dictionary id
+                let mut fields: Vec<_> = (0..object.len())
+                    .filter_map(|i| Some((object.field_id(i)?, object.field_name(i)?)))
+                    .collect();
+                fields.sort_unstable_by_key(|(id, _)| *id);
+                for (_, name) in fields {
+                    builder.values().append_value(name);
+                }
+                builder.append(true);
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Returns true for every row of `array` where the object found at `path` has a field named `key`.
+///
+/// Rows where `path` does not resolve, or resolves to something other than an object, are false.
+/// See [`variant_contains_key_with_options`] to produce nulls for them instead.
+///
+/// # Errors
+/// - If `path` contains a wildcard
+pub fn variant_contains_key(
+    array: &VariantArray,
+    path: &VariantPath,
+    key: &str,
+) -> Result<BooleanArray> {
+    variant_contains_key_with_options(array, path, key, Some(false))
+}
+
+/// Like [`variant_contains_key`], but rows where `path` does not resolve to an object produce
+/// `non_object` (`None` meaning null).
+pub fn variant_contains_key_with_options(
+    array: &VariantArray,
+    path: &VariantPath,
+    key: &str,
+    non_object: Option<bool>,
+) -> Result<BooleanArray> {
+    let values = resolve_path(array, path)?;
+    Ok(values
+        .iter()
+        .map(|value| match value {
+            Some(Variant::Object(object)) => Some(object.get(key).is_some()),
+            _ => non_object,
+        })
+        .collect())
+}
+
+/// How far a path resolved in one row of a [`VariantArray`], as reported by [`explain_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathResolution {
+    /// The number of leading path elements that resolved. If resolution stopped, this is also the
+    /// index of the element it stopped at.
+    pub resolved: usize,
+    /// Why resolution stopped, or `None` if the whole path resolved
+    pub stopped: Option<PathStop>,
+}
+
+/// The reason a path stopped resolving in a row, as reported by [`explain_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathStop {
+    /// The row is null
+    NullRow,
+    /// The value is [`Variant::Null`], which has no fields or elements
+    NullValue,
+    /// The object has no field with the requested name
+    MissingField,
+    /// The list has only `len` elements
+    IndexOutOfBounds { len: usize },
+    /// The value is not the object or list the path element requires
+    WrongType {
+        /// `"object"` or `"array"`
+        expected: &'static str,
+        /// The type of the value, as reported by [`variant_type_of`](crate::variant_type_of)
+        found: &'static str,
+    },
+}
+
+impl std::fmt::Display for PathStop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NullRow => write!(f, "the row is null"),
+            Self::NullValue => write!(f, "the value is null"),
+            Self::MissingField => write!(f, "the field does not exist"),
+            Self::IndexOutOfBounds { len } => write!(f, "the list only has {len} elements"),
+            Self::WrongType { expected, found } => write!(f, "expected {expected}, found {found}"),
+        }
+    }
+}
+
+/// Reports how far `path` resolves in row `row` of `array`, and why it stopped if it did not
+/// resolve completely. This helps to find out why an extraction, e.g. with
+/// [`variant_get`], produced null for a row.
+///
+/// # Errors
+/// - If `path` contains a wildcard
+/// - If `row` is out of bounds
+///
+/// # Example
+/// ```
+/// # use arrow::array::{ArrayRef, StringArray};
+/// # use parquet_variant::VariantPath;
+/// # use parquet_variant_compute::{PathStop, explain_path, json_to_variant};
+/// # use std::sync::Arc;
+/// let json: ArrayRef = Arc::new(StringArray::from(vec![r#"{"a": {"b": 1}}"#]));
+/// let array = json_to_variant(&json).unwrap();
+///
+/// let path = VariantPath::try_from("a.b.c").unwrap();
+/// let resolution = explain_path(&array, &path, 0).unwrap();
+/// assert_eq!(resolution.resolved, 2);
+/// let stopped = resolution.stopped.unwrap();
+/// assert_eq!(
+///     format!("stopped at {}: {stopped}", path[resolution.resolved]),
+///     "stopped at .c: expected object, found int8"
+/// );
+/// ```
+pub fn explain_path(
+    array: &VariantArray,
+    path: &VariantPath,
+    row: usize,
+) -> Result<PathResolution> {
+    reject_wildcards(path)?;
+    if row >= array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Row {row} is out of bounds for a VariantArray of length {}",
+            array.len()
+        )));
+    }
+
+    let stopped = |resolved, stop| PathResolution {
+        resolved,
+        stopped: Some(stop),
+    };
+    if array.is_null(row) {
+        return Ok(stopped(0, PathStop::NullRow));
+    }
+    let row_array = unshred_variant(&array.slice(row, 1))?;
+    let mut current = row_array.try_value(0)?;
+    for (resolved, element) in path.iter().enumerate() {
+        let next = match (element, &current) {
+            (_, Variant::Null) => Err(PathStop::NullValue),
+            (VariantPathElement::Field { name }, Variant::Object(object)) => {
+                object.get(name).ok_or(PathStop::MissingField)
+            }
+            (VariantPathElement::FieldId { id }, Variant::Object(object)) => {
+                object.get_by_field_id(*id).ok_or(PathStop::MissingField)
+            }
+            (VariantPathElement::Field { .. } | VariantPathElement::FieldId { .. }, other) => {
+                Err(PathStop::WrongType {
+                    expected: "object",
+                    found: type_name(other),
+                })
+            }
+            (VariantPathElement::Index { index }, Variant::List(list)) => list
+                .get(*index)
+                .ok_or(PathStop::IndexOutOfBounds { len: list.len() }),
+            (VariantPathElement::IndexFromEnd { offset }, Variant::List(list)) => list
+                .len()
+                .checked_sub(*offset)
+                .and_then(|i| list.get(i))
+                .ok_or(PathStop::IndexOutOfBounds { len: list.len() }),
+            (_, other) => Err(PathStop::WrongType {
+                expected: "array",
+                found: type_name(other),
+            }),
+        };
+        match next {
+            Ok(next) => current = next,
+            Err(stop) => return Ok(stopped(resolved, stop)),
+        }
+    }
+    Ok(PathResolution {
+        resolved: path.len(),
+        stopped: None,
+    })
+}
+
+/// Rejects paths containing a wildcard, which the kernels in this module cannot follow
+fn reject_wildcards(path: &VariantPath) -> Result<()> {
+    if path
+        .iter()
+        .any(|element| matches!(element, VariantPathElement::Wildcard))
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Wildcard paths are not supported here: {path}"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{VariantArrayBuilder, json_to_variant, shred_variant};
+    use arrow::array::{Array, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use parquet_variant::VariantBuilderExt;
+    use std::sync::Arc;
+
+    fn make_array() -> VariantArray {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": null}"#),
+            Some(r#"{"b": 1}"#),
+            Some(r#"{"a": 1}"#),
+            Some(r#"{"a": [null]}"#),
+            Some("null"),
+            None,
+        ]));
+        json_to_variant(&json).unwrap()
+    }
+
+    fn path(path: &str) -> VariantPath<'_> {
+        VariantPath::try_from(path).unwrap()
+    }
+
+    #[test]
+    fn test_variant_is_null() {
+        let array = make_array();
+
+        let result = variant_is_null(&array, &path("a")).unwrap();
+        assert_eq!(result.null_count(), 0);
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![true, true, false, false, true, true])
+        );
+
+        let result = variant_is_null(&array, &path("a[0]")).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![true, true, true, true, true, true])
+        );
+
+        // The empty path tests the row values themselves
+        let result = variant_is_null(&array, &path("")).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![false, false, false, false, true, true])
+        );
+
+        let err = variant_is_null(&array, &path("a[*]")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Wildcard paths are not supported here: $.a[*]"
+        );
+    }
+
+    #[test]
+    fn test_variant_is_null_shredded() {
+        let schema = DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into());
+        let array = shred_variant(&make_array(), &schema).unwrap();
+
+        let result = variant_is_null(&array, &path("a")).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![true, true, false, false, true, true])
+        );
+    }
+
+    #[test]
+    fn test_resolve_path_shredded() {
+        let schema = DataType::Struct(
+            vec![Field::new_struct(
+                "a",
+                vec![Field::new("x", DataType::Int64, true)],
+                true,
+            )]
+            .into(),
+        );
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"x": 1, "y": "s"}}"#),
+            Some(r#"{"a": "not shredded"}"#),
+            Some(r#"{"b": 1}"#),
+            None,
+        ]));
+        let array = shred_variant(&json_to_variant(&json).unwrap(), &schema).unwrap();
+
+        // Shredded objects are readable row by row
+        let values = resolve_path(&array, &path("a")).unwrap();
+        assert!(values.typed_value_field().is_none());
+        let first = values.value(0);
+        assert_eq!(first.get_object_field("x"), Some(Variant::from(1i64)));
+        assert_eq!(first.get_object_field("y"), Some(Variant::from("s")));
+        assert_eq!(values.value(1), Variant::from("not shredded"));
+        assert!(values.is_null(2));
+        assert!(values.is_null(3));
+
+        let values = resolve_path(&array, &path("a.x")).unwrap();
+        assert_eq!(values.value(0), Variant::from(1i64));
+        assert_eq!(values.nulls().unwrap().null_count(), 3);
+    }
+
+    #[test]
+    fn test_explain_path() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"b": [1, 2]}}"#),
+            Some(r#"{"a": {"b": "x"}}"#),
+            Some(r#"{"a": {"c": 1}}"#),
+            Some(r#"{"a": null}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let schema = DataType::Struct(
+            vec![Field::new_struct(
+                "a",
+                vec![Field::new("b", DataType::Int64, true)],
+                true,
+            )]
+            .into(),
+        );
+        let shredded = shred_variant(&array, &schema).unwrap();
+
+        let stopped = |resolved, stop| PathResolution {
+            resolved,
+            stopped: Some(stop),
+        };
+        let wrong_type = |expected, found| PathStop::WrongType { expected, found };
+        for array in [array, shredded] {
+            let explain = |path, row| explain_path(&array, &self::path(path), row).unwrap();
+            let done = PathResolution {
+                resolved: 3,
+                stopped: None,
+            };
+            assert_eq!(explain("a.b[1]", 0), done);
+            assert_eq!(
+                explain("a.b[2]", 0),
+                stopped(2, PathStop::IndexOutOfBounds { len: 2 })
+            );
+            assert_eq!(
+                explain("a.b.c", 0),
+                stopped(2, wrong_type("object", "array"))
+            );
+            assert_eq!(
+                explain("a.b[0]", 1),
+                stopped(2, wrong_type("array", "string"))
+            );
+            assert_eq!(explain("a.b", 2), stopped(1, PathStop::MissingField));
+            assert_eq!(explain("a.b", 3), stopped(1, PathStop::NullValue));
+            assert_eq!(explain("a.b", 4), stopped(0, PathStop::NullRow));
+        }
+        assert_eq!(
+            PathStop::IndexOutOfBounds { len: 2 }.to_string(),
+            "the list only has 2 elements"
+        );
+
+        let array = make_array();
+        let err = explain_path(&array, &path("a"), 6).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Row 6 is out of bounds for a VariantArray of length 6"
+        );
+        assert!(explain_path(&array, &path("a[*]"), 0).is_err());
+    }
+
+    #[test]
+    fn test_variant_array_length() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"l": []}"#),
+            Some(r#"{"l": [1, "two", {"three": 3}]}"#),
+            Some(r#"{"l": "not a list"}"#),
+            Some(r#"{"m": [1]}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+
+        let result = variant_array_length(&array, &path("l")).unwrap();
+        assert_eq!(
+            result,
+            Int32Array::from(vec![Some(0), Some(3), None, None, None])
+        );
+
+        let result = variant_array_length(&array, &path("")).unwrap();
+        assert_eq!(result.null_count(), 5);
+    }
+
+    #[test]
+    fn test_variant_object_keys() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"o": {"zebra": 1, "apple": 2, "mango": 3}}"#),
+            Some(r#"{"o": {"id": 1}}"#),
+            Some(r#"{"o": {}}"#),
+            Some(r#"{"o": [1]}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+
+        let keys = |array: &VariantArray, path: &VariantPath| -> Vec<Option<Vec<String>>> {
+            let result = variant_object_keys(array, path).unwrap();
+            result
+                .iter()
+                .map(|keys| {
+                    let keys = keys?;
+                    let keys = keys.as_any().downcast_ref::<StringArray>().unwrap();
+                    Some(keys.iter().map(|k| k.unwrap().to_string()).collect())
+                })
+                .collect()
+        };
+        assert_eq!(
+            keys(&array, &path("o")),
+            vec![
+                Some(vec!["apple".into(), "mango".into(), "zebra".into()]),
+                Some(vec!["id".into()]),
+                Some(vec![]),
+                None,
+                None,
+            ]
+        );
+
+        // Keys follow the dictionary order, not the (sorted) order fields are stored in
+        let mut builder = VariantArrayBuilder::new(2);
+        builder
+            .new_object()
+            .with_field("zebra", 1)
+            .with_field("apple", 2)
+            .with_field("mango", 3)
+            .finish();
+        builder.new_object().with_field("mango", 1).finish();
+        let array = builder.build();
+        assert_eq!(
+            keys(&array, &VariantPath::default()),
+            vec![
+                Some(vec!["zebra".into(), "apple".into(), "mango".into()]),
+                Some(vec!["mango".into()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variant_contains_key() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"meta": {"x": 1, "y": null}}"#),
+            Some(r#"{"meta": {"y": 2}}"#),
+            Some(r#"{"meta": "x"}"#),
+            Some(r#"{"other": {"x": 1}}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let meta = path("meta");
+
+        let result = variant_contains_key(&array, &meta, "x").unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![true, false, false, false, false])
+        );
+
+        // A field holding `null` is still present
+        let result = variant_contains_key(&array, &meta, "y").unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![true, true, false, false, false])
+        );
+
+        let result = variant_contains_key_with_options(&array, &meta, "x", None).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), Some(false), None, None, None])
+        );
+    }
+}
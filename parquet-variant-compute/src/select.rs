@@ -0,0 +1,158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for selecting rows of a VariantArray.
+
+use crate::VariantArray;
+use arrow::array::{BooleanArray, UInt32Array};
+use arrow::compute::{TakeOptions, filter, take};
+use arrow::error::{ArrowError, Result};
+
+/// Returns the rows of `array` where `mask` is true.
+///
+/// NULL entries in `mask` are treated as false. Shredded columns are filtered along with the rest
+/// of the array, so the result has the same layout as the input.
+///
+/// # Errors
+/// - If `mask` and `array` have different lengths
+pub fn filter_variant(array: &VariantArray, mask: &BooleanArray) -> Result<VariantArray> {
+    if mask.len() != array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Filter mask length {} does not match VariantArray length {}",
+            mask.len(),
+            array.len()
+        )));
+    }
+    VariantArray::try_new(&filter(array.inner(), mask)?)
+}
+
+/// Gathers the rows of `array` at the positions given by `indices`.
+///
+/// A NULL index produces a NULL row. Shredded columns are gathered along with the rest of the
+/// array, so the result has the same layout as the input.
+///
+/// # Errors
+/// - If any index is out of bounds for `array`
+pub fn take_variant(array: &VariantArray, indices: &UInt32Array) -> Result<VariantArray> {
+    let options = TakeOptions { check_bounds: true };
+    VariantArray::try_new(&take(array.inner(), indices, Some(options))?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{VariantArrayBuilder, shred_variant};
+    use arrow::datatypes::DataType;
+    use parquet_variant::Variant;
+
+    fn make_array() -> VariantArray {
+        let mut builder = VariantArrayBuilder::new(6);
+        builder.append_variant(Variant::from(0i32));
+        builder.append_variant(Variant::from("one"));
+        builder.append_null();
+        builder.append_variant(Variant::from(3i32));
+        builder.append_variant(Variant::from("four"));
+        builder.append_variant(Variant::Null);
+        builder.build()
+    }
+
+    #[test]
+    fn test_filter_variant() {
+        let array = make_array();
+        let mask = BooleanArray::from(vec![
+            Some(false),
+            Some(true),
+            Some(true),
+            None,
+            Some(true),
+            Some(false),
+        ]);
+
+        let result = filter_variant(&array, &mask).unwrap();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![
+                Some(Variant::from("one")),
+                None,
+                Some(Variant::from("four"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_shredded_variant() {
+        let array = shred_variant(&make_array(), &DataType::Int32).unwrap();
+        let mask = BooleanArray::from(vec![true, false, false, true, true, false]);
+
+        let result = filter_variant(&array, &mask).unwrap();
+        assert!(result.typed_value_field().is_some());
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![
+                Some(Variant::from(0i32)),
+                Some(Variant::from(3i32)),
+                Some(Variant::from("four"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_take_variant() {
+        let array = make_array();
+        let indices = UInt32Array::from(vec![Some(3), Some(0), Some(0), None, Some(2)]);
+
+        let result = take_variant(&array, &indices).unwrap();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![
+                Some(Variant::from(3i32)),
+                Some(Variant::from(0i32)),
+                Some(Variant::from(0i32)),
+                None,
+                None,
+            ]
+        );
+        let nulls = result.nulls().unwrap();
+        assert_eq!(
+            nulls.iter().collect::<Vec<_>>(),
+            [true, true, true, false, false]
+        );
+
+        // Shredded arrays keep their layout
+        let shredded = shred_variant(&array, &DataType::Int32).unwrap();
+        let result = take_variant(&shredded, &indices).unwrap();
+        assert!(result.typed_value_field().is_some());
+        assert_eq!(result.value(0), Variant::from(3i32));
+    }
+
+    #[test]
+    fn test_take_variant_out_of_bounds() {
+        let indices = UInt32Array::from(vec![0, 6]);
+        let err = take_variant(&make_array(), &indices).unwrap_err();
+        assert!(err.to_string().contains("out of bounds"), "{err}");
+    }
+
+    #[test]
+    fn test_filter_variant_length_mismatch() {
+        let mask = BooleanArray::from(vec![true]);
+        let err = filter_variant(&make_array(), &mask).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Filter mask length 1 does not match VariantArray length 6"
+        );
+    }
+}
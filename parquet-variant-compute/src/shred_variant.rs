@@ -66,7 +66,51 @@ use std::sync::Arc;
 /// ```
 ///
 /// See [`ShreddedSchemaBuilder`] for a convenient way to build the `as_type`
-/// value passed to this function.
+/// value passed to this function, or [`infer_schema`] to derive it from the data.
+///
+/// Fields of the input that are not part of `as_type` stay in the residual `value` column. Values
+/// that do not match the requested type produce a null `typed_value`, while the residual `value`
+/// keeps the original.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{Array, ArrayRef, AsArray, StringArray};
+/// # use arrow::datatypes::{DataType, Int8Type};
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::{infer_schema, json_to_variant, shred_variant, unshred_variant};
+/// # use std::sync::Arc;
+/// let json: ArrayRef = Arc::new(StringArray::from(vec![
+///     r#"{"id": 1, "name": "Alice"}"#,
+///     r#"{"id": "two", "name": "Bob"}"#,
+/// ]));
+/// let input = json_to_variant(&json).unwrap();
+/// let fields = infer_schema(&input, None).unwrap();
+/// let shredded = shred_variant(&input, &DataType::Struct(fields)).unwrap();
+///
+/// // Each field is shredded into its own (value, typed_value) pair
+/// let typed_value = shredded.typed_value_field().unwrap().as_struct();
+/// assert_eq!(typed_value.num_columns(), 2);
+/// let name = typed_value.column_by_name("name").unwrap().as_struct();
+/// let names = name.column_by_name("typed_value").unwrap().as_string::<i32>();
+/// assert_eq!(names.value(1), "Bob");
+///
+/// // Ids were inferred as strings, since they are not all integers
+/// let id = typed_value.column_by_name("id").unwrap().as_struct();
+/// let ids = id.column_by_name("typed_value").unwrap().as_string::<i32>();
+/// assert!(ids.is_null(0));
+/// assert_eq!(ids.value(1), "two");
+///
+/// // The residual value of the first id keeps the mismatched original
+/// let residual = id.column_by_name("value").unwrap().as_binary_view();
+/// let metadata = shredded.metadata_field().value(0);
+/// assert_eq!(Variant::new(metadata, residual.value(0)), Variant::Int8(1));
+///
+/// // Unshredding produces the original values
+/// let unshredded = unshred_variant(&shredded).unwrap();
+/// assert_eq!(unshredded.value(0).get_object_field("id"), Some(Variant::Int8(1)));
+/// ```
+///
+/// [`infer_schema`]: crate::infer_schema
 pub fn shred_variant(array: &VariantArray, as_type: &DataType) -> Result<VariantArray> {
     if array.typed_value_field().is_some() {
         return Err(ArrowError::InvalidArgumentError(
@@ -584,6 +628,16 @@ impl ShreddedSchemaBuilder {
         let path: VariantPath<'a> = path
             .try_into()
             .map_err(|e| ArrowError::InvalidArgumentError(format!("{:?}", e)))?;
+        // Only object fields can be shredded by path: a field id has no name without a metadata
+        // dictionary, and list elements are shredded through the list's own type
+        if let Some(element) = path
+            .iter()
+            .find(|element| !matches!(element, VariantPathElement::Field { .. }))
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot shred a path that is not a field name: {element}"
+            )));
+        }
         self.root.insert_path(&path, field.into_shredding_field());
         Ok(self)
     }
@@ -625,30 +679,26 @@ impl VariantSchemaNode {
             return;
         };
 
-        match head {
-            VariantPathElement::Field { name } => {
-                // Ensure this node is a Struct node
-                let children = match self {
-                    Self::Struct(children) => children,
-                    _ => {
-                        *self = Self::Struct(BTreeMap::new());
-                        match self {
-                            Self::Struct(children) => children,
-                            _ => unreachable!(),
-                        }
-                    }
-                };
+        let VariantPathElement::Field { name } = head else {
+            unreachable!("Paths that are not field names are rejected by with_path");
+        };
 
-                children
-                    .entry(name.to_string())
-                    .or_default()
-                    .insert_path_elements(tail, field);
-            }
-            VariantPathElement::Index { .. } => {
-                // List support to be added later; reject for now
-                unreachable!("List paths are not supported yet");
+        // Ensure this node is a Struct node
+        let children = match self {
+            Self::Struct(children) => children,
+            _ => {
+                *self = Self::Struct(BTreeMap::new());
+                match self {
+                    Self::Struct(children) => children,
+                    _ => unreachable!(),
+                }
             }
-        }
+        };
+
+        children
+            .entry(name.to_string())
+            .or_default()
+            .insert_path_elements(tail, field);
     }
 
     /// Convert this node to a shredding type.
@@ -2732,6 +2782,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_variant_schema_builder_rejects_non_field_paths() {
+        for (path, element) in [
+            ("a[#0]", "[#0]"),
+            ("a[0]", "[0]"),
+            ("a[-1].b", "[-1]"),
+            ("a[*]", "[*]"),
+        ] {
+            let err = ShreddedSchemaBuilder::default()
+                .with_path(path, &DataType::Int64)
+                .err()
+                .unwrap();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "Invalid argument error: Cannot shred a path that is not a field name: {element}"
+                )
+            );
+        }
+    }
+
     #[test]
     fn test_variant_schema_builder_default() {
         let shredding_type = ShreddedSchemaBuilder::default().build();
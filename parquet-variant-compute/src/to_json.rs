@@ -15,80 +15,113 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Module for transforming a batch of Variants represented as
-//! STRUCT<metadata: BINARY, value: BINARY> into a batch of JSON strings.
+//! Module for transforming a batch of Variants into JSON.
 
-use arrow::array::{Array, ArrayRef, BinaryArray, BooleanBufferBuilder, StringArray, StructArray};
+use crate::{VariantArray, unshred_variant};
+use arrow::array::{ArrayRef, BooleanBufferBuilder, StringArray};
 use arrow::buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer};
-use arrow::datatypes::DataType;
 use arrow_schema::ArrowError;
 use parquet_variant::Variant;
 use parquet_variant_json::VariantToJson;
+use serde_json::ser::{Formatter, PrettyFormatter};
+use std::io::Write;
 
-/// Transform a batch of Variant represented as STRUCT<metadata: BINARY, value: BINARY> to a batch
-/// of JSON strings where nulls are preserved. The JSON strings in the input must be valid.
+/// Transform a batch of Variants to a batch of JSON strings where nulls are preserved.
+///
+/// The input may be any array accepted by [`VariantArray::try_new`]. Shredded variants are
+/// unshredded first (see [`unshred_variant`]). Object fields are emitted in the order they are
+/// stored, which the variant encoding requires to be sorted by field name, so the output is
+/// deterministic. Fields holding [`Variant::Null`] are rendered as `null`, while fields missing
+/// from an object (including shredded fields that are absent in a row) are omitted, so the output
+/// parses back to the same values with [`json_to_variant`].
+///
+/// [`json_to_variant`]: crate::json_to_variant
 pub fn variant_to_json(input: &ArrayRef) -> Result<StringArray, ArrowError> {
-    let struct_array = input
-        .as_any()
-        .downcast_ref::<StructArray>()
-        .ok_or_else(|| ArrowError::CastError("Expected StructArray as input".into()))?;
-
-    // Validate field types
-    let data_type = struct_array.data_type();
-    match data_type {
-        DataType::Struct(inner_fields) => {
-            if inner_fields.len() != 2
-                || inner_fields[0].data_type() != &DataType::Binary
-                || inner_fields[1].data_type() != &DataType::Binary
-            {
-                return Err(ArrowError::CastError(
-                    "Expected struct with two binary fields".into(),
-                ));
+    variants_to_json(input, |variant, buffer| variant.to_json(buffer))
+}
+
+/// Like [`variant_to_json`], but renders each value as multi-line JSON, with nested objects and
+/// lists indented by `indent` spaces per level. Empty objects and lists stay on one line.
+pub fn variant_to_json_pretty(input: &ArrayRef, indent: usize) -> Result<StringArray, ArrowError> {
+    let indent = vec![b' '; indent];
+    variants_to_json(input, |variant, buffer| {
+        let mut formatter = PrettyFormatter::with_indent(&indent);
+        write_pretty(&variant, &mut formatter, buffer)
+    })
+}
+
+/// Writes every row of `array` to `writer` as compact JSON, with each row followed by
+/// `delimiter`, e.g. `b'\n'` for newline-delimited JSON. Null rows are written as `null`, so that
+/// the output always has one entry per row.
+///
+/// Unlike [`variant_to_json`], the output is never materialized in memory: rows are streamed to
+/// `writer`, and shredded input is unshredded a batch of rows at a time. Compact JSON never
+/// contains a raw newline, but other delimiters may also appear inside the JSON values. Consider
+/// wrapping `writer` in a [`std::io::BufWriter`], since each row takes several small writes.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{ArrayRef, StringArray};
+/// # use parquet_variant_compute::{json_to_variant, write_variant_json};
+/// # use std::sync::Arc;
+/// let json: ArrayRef = Arc::new(StringArray::from(vec![Some(r#"{"a": 1}"#), None, Some("[true]")]));
+/// let array = json_to_variant(&json).unwrap();
+/// let mut out = Vec::new();
+/// write_variant_json(&array, &mut out, b'\n').unwrap();
+/// assert_eq!(out, b"{\"a\":1}\nnull\n[true]\n");
+/// ```
+///
+/// # Errors
+/// - If unshredding fails or a value cannot be converted to JSON
+/// - If writing to `writer` fails
+pub fn write_variant_json<W: Write>(
+    array: &VariantArray,
+    writer: &mut W,
+    delimiter: u8,
+) -> Result<(), ArrowError> {
+    const BATCH_SIZE: usize = 1024;
+    for offset in (0..array.len()).step_by(BATCH_SIZE) {
+        let length = BATCH_SIZE.min(array.len() - offset);
+        let batch = unshred_variant(&array.slice(offset, length))?;
+        for i in 0..batch.len() {
+            if batch.is_null(i) {
+                writer.write_all(b"null")?;
+            } else {
+                batch.try_value(i)?.to_json(writer)?;
             }
-        }
-        _ => {
-            return Err(ArrowError::CastError(
-                "Expected StructArray with known fields".into(),
-            ));
+            writer.write_all(&[delimiter])?;
         }
     }
+    Ok(())
+}
 
-    let metadata_array = struct_array
-        .column(0)
-        .as_any()
-        .downcast_ref::<BinaryArray>()
-        .ok_or_else(|| ArrowError::CastError("Expected BinaryArray for 'metadata'".into()))?;
-
-    let value_array = struct_array
-        .column(1)
-        .as_any()
-        .downcast_ref::<BinaryArray>()
-        .ok_or_else(|| ArrowError::CastError("Expected BinaryArray for 'value'".into()))?;
+/// Shared implementation of the `variant_to_json` kernels: `write_json` renders one non-null
+/// variant into the output buffer.
+fn variants_to_json(
+    input: &ArrayRef,
+    mut write_json: impl FnMut(Variant<'_, '_>, &mut Vec<u8>) -> Result<(), ArrowError>,
+) -> Result<StringArray, ArrowError> {
+    let variant_array = unshred_variant(&VariantArray::try_new(input)?)?;
 
     // Zero-copy builder
     // The size per JSON string is assumed to be 128 bytes. If this holds true, resizing could be
     // minimized for performance.
-    let mut json_buffer: Vec<u8> = Vec::with_capacity(struct_array.len() * 128);
-    let mut offsets: Vec<i32> = Vec::with_capacity(struct_array.len() + 1);
-    let mut validity = BooleanBufferBuilder::new(struct_array.len());
-    let mut current_offset: i32 = 0;
-    offsets.push(current_offset);
-
-    for i in 0..struct_array.len() {
-        if struct_array.is_null(i) {
+    let mut json_buffer: Vec<u8> = Vec::with_capacity(variant_array.len() * 128);
+    let mut offsets: Vec<i32> = Vec::with_capacity(variant_array.len() + 1);
+    let mut validity = BooleanBufferBuilder::new(variant_array.len());
+    offsets.push(0);
+
+    for i in 0..variant_array.len() {
+        if variant_array.is_null(i) {
             validity.append(false);
-            offsets.push(current_offset);
         } else {
-            let metadata = metadata_array.value(i);
-            let value = value_array.value(i);
-            let variant = Variant::new(metadata, value);
-            let start_len = json_buffer.len();
-            variant.to_json(&mut json_buffer)?;
-            let written = (json_buffer.len() - start_len) as i32;
-            current_offset += written;
-            offsets.push(current_offset);
+            write_json(variant_array.try_value(i)?, &mut json_buffer)?;
             validity.append(true);
         }
+        let offset = i32::try_from(json_buffer.len()).map_err(|_| {
+            ArrowError::ComputeError("JSON output exceeds the capacity of a StringArray".into())
+        })?;
+        offsets.push(offset);
     }
 
     let offsets_buffer = OffsetBuffer::new(ScalarBuffer::from(offsets));
@@ -98,14 +131,56 @@ pub fn variant_to_json(input: &ArrayRef) -> Result<StringArray, ArrowError> {
     StringArray::try_new(offsets_buffer, value_buffer, Some(null_buffer))
 }
 
+/// Writes `variant` as JSON, laying out objects and lists with `formatter`. Scalars are written
+/// by [`VariantToJson::to_json`], so they are rendered exactly as in the compact output.
+fn write_pretty(
+    variant: &Variant<'_, '_>,
+    formatter: &mut PrettyFormatter<'_>,
+    out: &mut Vec<u8>,
+) -> Result<(), ArrowError> {
+    match variant {
+        Variant::Object(object) => {
+            formatter.begin_object(out)?;
+            for (i, (name, value)) in object.iter().enumerate() {
+                formatter.begin_object_key(out, i == 0)?;
+                // Escapes the name like any other string
+                Variant::from(name).to_json(out)?;
+                formatter.end_object_key(out)?;
+                formatter.begin_object_value(out)?;
+                write_pretty(&value, formatter, out)?;
+                formatter.end_object_value(out)?;
+            }
+            formatter.end_object(out)?;
+        }
+        Variant::List(list) => {
+            formatter.begin_array(out)?;
+            for (i, element) in list.iter().enumerate() {
+                formatter.begin_array_value(out, i == 0)?;
+                write_pretty(&element, formatter, out)?;
+                formatter.end_array_value(out)?;
+            }
+            formatter.end_array(out)?;
+        }
+        _ => variant.to_json(out)?,
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
-    use crate::variant_to_json;
-    use arrow::array::{Array, ArrayRef, BinaryBuilder, BooleanBufferBuilder, StructArray};
+    use crate::{
+        VariantArray, VariantArrayBuilder, json_to_variant, shred_variant, variant_eq,
+        variant_to_json, variant_to_json_pretty, write_variant_json,
+    };
+    use arrow::array::{
+        Array, ArrayRef, BinaryBuilder, BooleanArray, BooleanBufferBuilder, StringArray,
+        StructArray,
+    };
     use arrow::buffer::NullBuffer;
     use arrow::datatypes::DataType;
     use arrow::datatypes::Field;
-    use arrow_schema::Fields;
+    use arrow_schema::{ArrowError, Fields};
+    use parquet_variant::{Variant, VariantBuilderExt};
     use std::sync::Arc;
 
     #[test]
@@ -174,4 +249,175 @@ mod test {
 
         assert_eq!(result_vec, expected);
     }
+
+    #[test]
+    fn test_variant_to_json_nested_object() {
+        let mut builder = VariantArrayBuilder::new(3);
+        {
+            let mut obj = builder.new_object();
+            // Fields are inserted out of order, but always rendered sorted by name
+            obj.insert("zeta", "quote \" and \\ backslash");
+            obj.insert("alpha", true);
+            let mut inner = obj.new_object("mid");
+            inner.insert("n", 1.5f64);
+            let mut list = inner.new_list("list");
+            list.append_value(-7i64);
+            list.append_value(());
+            list.finish();
+            inner.finish();
+            obj.finish();
+        }
+        builder.append_null();
+        builder.append_variant(Variant::from("plain"));
+        let input = ArrayRef::from(builder.build());
+
+        let result = variant_to_json(&input).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result.value(0),
+            r#"{"alpha":true,"mid":{"list":[-7,null],"n":1.5},"zeta":"quote \" and \\ backslash"}"#
+        );
+        assert!(result.is_null(1));
+        assert_eq!(result.value(2), r#""plain""#);
+
+        // Shredding the same data yields the same JSON
+        let shredded = shred_variant(
+            &VariantArray::try_new(&input).unwrap(),
+            &DataType::Struct(vec![Field::new("alpha", DataType::Boolean, true)].into()),
+        )
+        .unwrap();
+        let shredded_result = variant_to_json(&ArrayRef::from(shredded)).unwrap();
+        assert_eq!(shredded_result, result);
+    }
+
+    #[test]
+    fn test_variant_to_json_null_vs_absent_fields() {
+        let json = vec![
+            Some(r#"{"a":null,"c":1}"#),
+            Some(r#"{"c":2}"#),
+            Some(r#"{"a":3,"b":null}"#),
+            Some(r#"{"b":{"x":null}}"#),
+            Some("null"),
+        ];
+        let input: ArrayRef = Arc::new(StringArray::from(json.clone()));
+        let array = json_to_variant(&input).unwrap();
+        let result = variant_to_json(&ArrayRef::from(array.clone())).unwrap();
+        assert_eq!(result, StringArray::from(json.clone()));
+
+        // Shredded fields distinguish explicit nulls (stored in `value`) from absent fields
+        let shredding = DataType::Struct(
+            vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new(
+                    "b",
+                    DataType::Struct(vec![Field::new("x", DataType::Int64, true)].into()),
+                    true,
+                ),
+                Field::new("c", DataType::Int64, true),
+            ]
+            .into(),
+        );
+        let shredded = shred_variant(&array, &shredding).unwrap();
+        let result = variant_to_json(&ArrayRef::from(shredded)).unwrap();
+        assert_eq!(result, StringArray::from(json));
+
+        // The JSON round-trips back to the same variants
+        let roundtrip = json_to_variant(&(Arc::new(result) as ArrayRef)).unwrap();
+        assert_eq!(
+            variant_eq(&roundtrip, &array).unwrap(),
+            BooleanArray::from(vec![true; 5])
+        );
+    }
+
+    #[test]
+    fn test_variant_to_json_pretty() {
+        let mut builder = VariantArrayBuilder::new(3);
+        {
+            let mut obj = builder.new_object();
+            let mut level1 = obj.new_object("level1");
+            let mut level2 = level1.new_object("level2");
+            level2.insert("deep", "a, \"tricky\": [string]");
+            level2.finish();
+            let mut list = level1.new_list("list");
+            list.append_value(1i8);
+            list.new_object().finish();
+            list.new_list().finish();
+            list.finish();
+            level1.finish();
+            obj.insert("top", 1.5f64);
+            obj.finish();
+        }
+        builder.append_null();
+        builder.append_variant(Variant::from(42i32));
+        let input = ArrayRef::from(builder.build());
+
+        let result = variant_to_json_pretty(&input, 2).unwrap();
+        let expected = r#"{
+  "level1": {
+    "level2": {
+      "deep": "a, \"tricky\": [string]"
+    },
+    "list": [
+      1,
+      {},
+      []
+    ]
+  },
+  "top": 1.5
+}"#;
+        assert_eq!(result.value(0), expected);
+        assert!(result.is_null(1));
+        assert_eq!(result.value(2), "42");
+
+        // Pretty output parses to the same JSON as the compact output
+        let compact = variant_to_json(&input).unwrap();
+        let parse = |s: &str| serde_json::from_str::<serde_json::Value>(s).unwrap();
+        assert_eq!(parse(result.value(0)), parse(compact.value(0)));
+    }
+
+    #[test]
+    fn test_write_variant_json() {
+        // Enough rows to span several batches, with some null rows and shredded values
+        let mut builder = VariantArrayBuilder::new(2500);
+        for i in 0..2500 {
+            match i % 4 {
+                0 => builder.append_null(),
+                1 => builder.append_variant(Variant::from(i)),
+                2 => builder.append_variant(Variant::from("line\nbreak")),
+                _ => builder.new_object().with_field("i", i).finish(),
+            }
+        }
+        let array = builder.build();
+        let shredded = shred_variant(&array, &DataType::Int32).unwrap();
+        let expected = variant_to_json(&ArrayRef::from(array.clone())).unwrap();
+
+        for input in [&array, &shredded] {
+            let mut out = Vec::new();
+            write_variant_json(input, &mut out, b'\n').unwrap();
+            let out = String::from_utf8(out).unwrap();
+            let lines: Vec<_> = out.lines().collect();
+            assert_eq!(lines.len(), 2500);
+            for (i, line) in lines.iter().enumerate() {
+                let value = serde_json::from_str::<serde_json::Value>(line).unwrap();
+                if expected.is_null(i) {
+                    assert_eq!(value, serde_json::Value::Null);
+                } else {
+                    assert_eq!(line, &expected.value(i), "row {i}");
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        write_variant_json(&array.slice(1, 3), &mut out, b'|').unwrap();
+        assert_eq!(out, br#"1|"line\nbreak"|{"i":3}|"#);
+
+        let mut out = Vec::new();
+        write_variant_json(&array.slice(0, 0), &mut out, b'\n').unwrap();
+        assert!(out.is_empty());
+
+        // Errors from the writer are returned
+        let mut full = [0u8; 4];
+        let err = write_variant_json(&array, &mut full.as_mut_slice(), b'\n').unwrap_err();
+        assert!(matches!(err, ArrowError::IoError(..)), "{err}");
+    }
 }
@@ -23,7 +23,7 @@ use arrow::datatypes::{
     DecimalType,
 };
 use arrow::error::{ArrowError, Result};
-use chrono::Timelike;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Timelike, Utc};
 use parquet_variant::{Variant, VariantDecimal4, VariantDecimal8, VariantDecimal16};
 
 /// Extension trait for Arrow primitive types that can extract their native value from a Variant
@@ -72,16 +72,39 @@ macro_rules! impl_primitive_from_variant {
     };
 }
 
+/// Timestamp implementations also accept string variants, parsed by `parse_timestamp_string`
+/// (UTC-adjusted) or `parse_timestamp_ntz_string` (NTZ).
 macro_rules! impl_timestamp_from_variant {
-    ($timestamp_type:ty, $variant_method:ident, ntz=$ntz:ident, $cast_fn:expr $(,)?) => {
+    ($timestamp_type:ty, $variant_method:ident, ntz=true, $cast_fn:expr $(,)?) => {
+        impl_timestamp_from_variant!(@impl $timestamp_type, $variant_method, true, parse_timestamp_ntz_string, $cast_fn);
+    };
+    ($timestamp_type:ty, $variant_method:ident, ntz=false, $cast_fn:expr $(,)?) => {
+        impl_timestamp_from_variant!(@impl $timestamp_type, $variant_method, false, parse_timestamp_string, $cast_fn);
+    };
+    (@impl $timestamp_type:ty, $variant_method:ident, $ntz:literal, $parse_fn:ident, $cast_fn:expr) => {
         impl TimestampFromVariant<{ $ntz }> for $timestamp_type {
             fn from_variant(variant: &Variant<'_, '_>) -> Option<Self::Native> {
-                variant.$variant_method().and_then($cast_fn)
+                variant
+                    .$variant_method()
+                    .or_else(|| variant.as_string().and_then($parse_fn))
+                    .and_then($cast_fn)
             }
         }
     };
 }
 
+/// Parses an RFC 3339 timestamp with a UTC offset, e.g. `2024-01-15T10:30:00.123+02:00`
+fn parse_timestamp_string(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|timestamp| timestamp.to_utc())
+}
+
+/// Parses an RFC 3339 timestamp without a UTC offset, e.g. `2024-01-15T10:30:00.123`
+fn parse_timestamp_ntz_string(s: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f").ok()
+}
+
 impl_primitive_from_variant!(datatypes::Int32Type, as_int32);
 impl_primitive_from_variant!(datatypes::Int16Type, as_int16);
 impl_primitive_from_variant!(datatypes::Int8Type, as_int8);
@@ -90,15 +113,76 @@ impl_primitive_from_variant!(datatypes::UInt8Type, as_u8);
 impl_primitive_from_variant!(datatypes::UInt16Type, as_u16);
 impl_primitive_from_variant!(datatypes::UInt32Type, as_u32);
 impl_primitive_from_variant!(datatypes::UInt64Type, as_u64);
-impl_primitive_from_variant!(datatypes::Float16Type, as_f16);
-impl_primitive_from_variant!(datatypes::Float32Type, as_f32);
-impl_primitive_from_variant!(datatypes::Float64Type, as_f64);
-impl_primitive_from_variant!(datatypes::Date32Type, as_naive_date, |v| {
-    Some(datatypes::Date32Type::from_naive_date(v))
-});
-impl_primitive_from_variant!(datatypes::Date64Type, as_naive_date, |v| {
-    Some(datatypes::Date64Type::from_naive_date(v))
-});
+
+impl PrimitiveFromVariant for datatypes::Float16Type {
+    fn from_variant(variant: &Variant<'_, '_>) -> Option<half::f16> {
+        match variant {
+            Variant::Decimal4(_) | Variant::Decimal8(_) | Variant::Decimal16(_) => {
+                datatypes::Float64Type::from_variant(variant).map(half::f16::from_f64)
+            }
+            _ => variant.as_f16(),
+        }
+    }
+}
+
+impl PrimitiveFromVariant for datatypes::Float32Type {
+    fn from_variant(variant: &Variant<'_, '_>) -> Option<f32> {
+        // `as_f32` rounds doubles beyond the `f32` range to infinity, which is an overflow rather
+        // than a loss of precision. Integers and decimals always fit, so they are only rounded.
+        match variant {
+            Variant::Double(d) if d.is_finite() && d.abs() > f32::MAX as f64 => None,
+            Variant::Decimal4(_) | Variant::Decimal8(_) | Variant::Decimal16(_) => {
+                datatypes::Float64Type::from_variant(variant).map(|d| d as f32)
+            }
+            _ => variant.as_f32(),
+        }
+    }
+}
+
+impl PrimitiveFromVariant for datatypes::Float64Type {
+    fn from_variant(variant: &Variant<'_, '_>) -> Option<f64> {
+        // `as_f64` only accepts decimals with scale 0, but any decimal has a (possibly inexact)
+        // floating point representation.
+        match variant {
+            Variant::Decimal4(d) => Some(decimal_to_f64(d.integer() as f64, d.scale())),
+            Variant::Decimal8(d) => Some(decimal_to_f64(d.integer() as f64, d.scale())),
+            Variant::Decimal16(d) => Some(decimal_to_f64(d.integer() as f64, d.scale())),
+            _ => variant.as_f64(),
+        }
+    }
+}
+
+/// Converts an unscaled decimal value to its nearest `f64`, the same way arrow's decimal to float
+/// cast does.
+fn decimal_to_f64(unscaled: f64, scale: u8) -> f64 {
+    unscaled / 10_f64.powi(scale as i32)
+}
+
+impl PrimitiveFromVariant for datatypes::Date32Type {
+    fn from_variant(variant: &Variant<'_, '_>) -> Option<i32> {
+        variant_to_naive_date(variant).map(Self::from_naive_date)
+    }
+}
+
+impl PrimitiveFromVariant for datatypes::Date64Type {
+    fn from_variant(variant: &Variant<'_, '_>) -> Option<i64> {
+        variant_to_naive_date(variant).map(Self::from_naive_date)
+    }
+}
+
+/// Extracts a date from a variant date, a timestamp (truncated to its date), or an ISO 8601
+/// `YYYY-MM-DD` string.
+fn variant_to_naive_date(variant: &Variant<'_, '_>) -> Option<NaiveDate> {
+    match variant {
+        Variant::Date(d) => Some(*d),
+        Variant::TimestampMicros(t) | Variant::TimestampNanos(t) => Some(t.date_naive()),
+        Variant::TimestampNtzMicros(t) | Variant::TimestampNtzNanos(t) => Some(t.date()),
+        _ => variant
+            .as_string()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()),
+    }
+}
+
 impl_primitive_from_variant!(datatypes::Time32SecondType, as_time_utc, |v| {
     // Return None if there are leftover nanoseconds
     if v.nanosecond() != 0 {
@@ -176,15 +260,29 @@ impl_timestamp_from_variant!(
 );
 impl_timestamp_from_variant!(
     datatypes::TimestampMicrosecondType,
-    as_timestamp_ntz_micros,
+    as_timestamp_ntz_nanos,
     ntz = true,
-    |timestamp| Self::from_naive_datetime(timestamp, None),
+    |timestamp| {
+        // Return None if there are leftover nanoseconds
+        if timestamp.nanosecond() % 1_000 != 0 {
+            None
+        } else {
+            Self::from_naive_datetime(timestamp, None)
+        }
+    }
 );
 impl_timestamp_from_variant!(
     datatypes::TimestampMicrosecondType,
-    as_timestamp_micros,
+    as_timestamp_nanos,
     ntz = false,
-    |timestamp| Self::from_naive_datetime(timestamp.naive_utc(), None)
+    |timestamp| {
+        // Return None if there are leftover nanoseconds
+        if timestamp.nanosecond() % 1_000 != 0 {
+            None
+        } else {
+            Self::from_naive_datetime(timestamp.naive_utc(), None)
+        }
+    }
 );
 impl_timestamp_from_variant!(
     datatypes::TimestampNanosecondType,
@@ -46,6 +46,13 @@ use uuid::Uuid;
 /// This function efficiently converts a shredded VariantArray back to an unshredded form where all
 /// data resides in the value column.
 ///
+/// This is the inverse of [`shred_variant`]: typed values are merged back into the residual
+/// objects, with fields ordered by name as the variant encoding requires. Typed columns and a
+/// residual `value` column obtained elsewhere can be combined into a shredded input with
+/// [`VariantArray::from_parts`].
+///
+/// [`shred_variant`]: crate::shred_variant
+///
 /// # Arguments
 /// * `array` - The VariantArray to unshred
 ///
@@ -688,6 +695,56 @@ mod tests {
     };
     use parquet_variant::Variant;
 
+    #[test]
+    fn test_shred_unshred_round_trip() {
+        use crate::{infer_schema, json_to_variant, shred_variant, variant_to_json};
+        use arrow::array::{ArrayRef, StringArray};
+        use arrow::datatypes::{DataType, Field};
+        use std::sync::Arc;
+
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"id": 1, "name": "Alice", "tags": ["a", "b"], "address": {"zip": 12345}}"#),
+            Some(r#"{"id": "two", "extra": true, "tags": [1, null], "address": {"city": "X"}}"#),
+            Some(r#"{"name": null, "address": "unknown"}"#),
+            Some(r#"{}"#),
+            Some(r#"[1, {"id": 3}]"#),
+            Some("null"),
+            None,
+        ]));
+        let input = json_to_variant(&json).unwrap();
+
+        // Shred with both an inferred and an explicit, partially matching schema. The explicit
+        // schema shreds integers as wider types, so only logical (JSON) equality is preserved.
+        let inferred = DataType::Struct(infer_schema(&input, None).unwrap());
+        let explicit = DataType::Struct(
+            vec![
+                Field::new("id", DataType::Int64, true),
+                Field::new_list("tags", Field::new_list_field(DataType::Utf8, true), true),
+                Field::new_struct(
+                    "address",
+                    vec![Field::new("zip", DataType::Int32, true)],
+                    true,
+                ),
+            ]
+            .into(),
+        );
+        for (schema, exact) in [(inferred, true), (explicit, false)] {
+            let shredded = shred_variant(&input, &schema).unwrap();
+            let result = crate::unshred_variant(&shredded).unwrap();
+            assert!(result.typed_value_field().is_none());
+            assert_eq!(result.len(), input.len());
+            assert_eq!(result.nulls(), input.nulls());
+            if exact {
+                let values: Vec<_> = result.iter().collect();
+                assert_eq!(values, input.iter().collect::<Vec<_>>());
+            }
+
+            let result = variant_to_json(&ArrayRef::from(result)).unwrap();
+            let expected = variant_to_json(&ArrayRef::from(input.clone())).unwrap();
+            assert_eq!(result, expected, "round trip through {schema}");
+        }
+    }
+
     #[test]
     fn test_unshred_utf8view_typed_value() {
         let metadata_bytes: &[u8] = &[0x01, 0x00, 0x00];
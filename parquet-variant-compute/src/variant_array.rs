@@ -22,6 +22,7 @@ use crate::type_conversion::{
     generic_conversion_single_value, generic_conversion_single_value_with_result,
     primitive_conversion_single_value,
 };
+use crate::variant_type_of::type_name;
 use arrow::array::{Array, ArrayRef, AsArray, BinaryViewArray, StructArray};
 use arrow::buffer::NullBuffer;
 use arrow::compute::cast;
@@ -36,9 +37,11 @@ use arrow_schema::{ArrowError, DataType, Field, FieldRef, Fields, TimeUnit};
 use chrono::{DateTime, NaiveTime};
 use parquet_variant::{
     Uuid, Variant, VariantDecimal4, VariantDecimal8, VariantDecimal16, VariantDecimalType as _,
+    VariantMetadata,
 };
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 /// Arrow Variant [`ExtensionType`].
@@ -287,6 +290,27 @@ impl VariantArray {
         })
     }
 
+    /// Creates a new `VariantArray` from an array and the [`Field`] that describes it in a
+    /// [`Schema`], e.g. a column read from IPC or Flight.
+    ///
+    /// This is the counterpart of [`Self::field`]: unlike [`Self::try_new`], it also checks that
+    /// `field` is annotated with the [`VariantType`] extension type.
+    ///
+    /// # Errors
+    /// - If `field` does not have the [`VariantType`] extension type
+    /// - If `inner` is not a valid variant array, see [`Self::try_new`]
+    ///
+    /// [`Schema`]: arrow_schema::Schema
+    pub fn try_new_with_field(field: &Field, inner: &dyn Array) -> Result<Self> {
+        field.try_extension_type::<VariantType>().map_err(|e| {
+            ArrowError::InvalidArgumentError(format!(
+                "Field '{}' is not a VariantArray: {e}",
+                field.name()
+            ))
+        })?;
+        Self::try_new(inner)
+    }
+
     pub(crate) fn from_parts(
         metadata: BinaryViewArray,
         value: Option<BinaryViewArray>,
@@ -383,6 +407,54 @@ impl VariantArray {
         }
     }
 
+    /// Return the raw encoded `(metadata, value)` bytes of the given row, without constructing a
+    /// [`Variant`]
+    ///
+    /// Returns `None` if the row is null, or if its value is not (entirely) stored in the `value`
+    /// field because it was shredded into `typed_value`. Use [`unshred_variant`] first to access
+    /// the bytes of every non-null row of a shredded array.
+    ///
+    /// # Panics
+    /// * if the index is out of bounds
+    ///
+    /// [`unshred_variant`]: crate::unshred_variant
+    pub fn value_bytes(&self, index: usize) -> Option<(&[u8], &[u8])> {
+        if self.is_null(index) {
+            return None;
+        }
+        if let Some(typed_value) = self.typed_value_field() {
+            if typed_value.is_valid(index) {
+                return None;
+            }
+        }
+        let value = self.value_field()?;
+        value
+            .is_valid(index)
+            .then(|| (self.metadata.value(index), value.value(index)))
+    }
+
+    /// Checks that the metadata of every non-null row is valid, e.g. that it was written with a
+    /// version of the variant encoding this crate understands.
+    ///
+    /// [`Self::try_new`] does not inspect individual rows, so an array that mixes metadata from
+    /// different producers is only rejected when a row is decoded (and [`Self::value`] panics).
+    /// Call this once on untrusted input to report the first invalid row up front instead.
+    ///
+    /// # Errors
+    /// - If the metadata of any non-null row is invalid, e.g. has an unknown version
+    pub fn validate_metadata(&self) -> Result<()> {
+        for i in 0..self.len() {
+            if self.is_valid(i) {
+                VariantMetadata::try_new(self.metadata.value(i)).map_err(|e| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Invalid VariantArray metadata at row {i}: {e}"
+                    ))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
     /// Return a reference to the metadata field of the [`StructArray`]
     pub fn metadata_field(&self) -> &BinaryViewArray {
         &self.metadata
@@ -414,6 +486,13 @@ impl VariantArray {
         self.inner.data_type()
     }
 
+    /// Returns a zero-copy slice of this array with the indicated offset and length.
+    ///
+    /// The slice shares the metadata, value, typed_value and null buffers of this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + length` is greater than the length of this array.
     pub fn slice(&self, offset: usize, length: usize) -> Self {
         let inner = self.inner.slice(offset, length);
         let metadata = self.metadata.slice(offset, length);
@@ -451,6 +530,59 @@ impl VariantArray {
     pub fn iter(&self) -> VariantArrayIter<'_> {
         VariantArrayIter::new(self)
     }
+
+    /// Computes [`VariantStats`] for this array in a single pass over its rows.
+    ///
+    /// Only the top level of each row is inspected, as in [`variant_type_of`], so this is a cheap
+    /// way to see which type dominates a column before deciding how to shred it.
+    ///
+    /// ```
+    /// # use parquet_variant::Variant;
+    /// # use parquet_variant_compute::VariantArray;
+    /// let array = VariantArray::from(vec![Some(Variant::from(1)), None, Some(Variant::from(2))]);
+    /// let stats = array.statistics().unwrap();
+    /// assert_eq!(stats.null_count, 1);
+    /// assert_eq!(stats.type_counts["int32"], 2);
+    /// ```
+    ///
+    /// # Errors
+    /// - If the array is shredded and a value cannot be read
+    ///
+    /// [`variant_type_of`]: crate::variant_type_of
+    pub fn statistics(&self) -> Result<VariantStats> {
+        // Nested typed_value columns cannot be read row by row, so unshred them first
+        let unshredded;
+        let array = match self.typed_value_field() {
+            Some(typed_value) if typed_value.data_type().is_nested() => {
+                unshredded = crate::unshred_variant(self)?;
+                &unshredded
+            }
+            _ => self,
+        };
+        let mut stats = VariantStats::default();
+        for i in 0..array.len() {
+            if array.is_null(i) {
+                stats.null_count += 1;
+            } else {
+                let type_name = type_name(&array.try_value(i)?);
+                *stats.type_counts.entry(type_name).or_default() += 1;
+            }
+        }
+        Ok(stats)
+    }
+}
+
+/// Statistics about the rows of a [`VariantArray`], see [`VariantArray::statistics`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VariantStats {
+    /// The number of null rows
+    pub null_count: usize,
+    /// The number of non-null rows of each top-level type, keyed by the type names reported by
+    /// [`variant_type_of`]. A [`Variant::Null`] value is counted here as `"null"`, not as a
+    /// null row.
+    ///
+    /// [`variant_type_of`]: crate::variant_type_of
+    pub type_counts: BTreeMap<&'static str, usize>,
 }
 
 impl From<VariantArray> for StructArray {
@@ -465,6 +597,15 @@ impl From<VariantArray> for ArrayRef {
     }
 }
 
+impl TryFrom<&StructArray> for VariantArray {
+    type Error = ArrowError;
+
+    /// Equivalent to [`VariantArray::try_new`]
+    fn try_from(inner: &StructArray) -> Result<Self> {
+        Self::try_new(inner)
+    }
+}
+
 impl<'m, 'v> FromIterator<Option<Variant<'m, 'v>>> for VariantArray {
     fn from_iter<T: IntoIterator<Item = Option<Variant<'m, 'v>>>>(iter: T) -> Self {
         let iter = iter.into_iter();
@@ -481,6 +622,27 @@ impl<'m, 'v> FromIterator<Variant<'m, 'v>> for VariantArray {
     }
 }
 
+/// Builds a `VariantArray` from values that convert into [`Variant`], with `None` producing null
+/// rows.
+///
+/// ```
+/// # use arrow::array::Array;
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::VariantArray;
+/// let array = VariantArray::from(vec![Some(1i64), None, Some(3)]);
+/// assert_eq!(array.value(0), Variant::Int64(1));
+/// assert!(array.is_null(1));
+///
+/// // Mixed types can be converted to `Variant` first
+/// let array = VariantArray::from(vec![Some(Variant::from("a")), Some(Variant::from(true))]);
+/// assert_eq!(array.value(1), Variant::BooleanTrue);
+/// ```
+impl<'m, 'v, V: Into<Variant<'m, 'v>>> From<Vec<Option<V>>> for VariantArray {
+    fn from(values: Vec<Option<V>>) -> Self {
+        Self::from_iter(values.into_iter().map(|value| value.map(Into::into)))
+    }
+}
+
 /// An iterator over [`VariantArray`]
 ///
 /// This iterator returns `Option<Option<Variant<'a, 'a>>>` where:
@@ -564,6 +726,15 @@ impl<'a> DoubleEndedIterator for VariantArrayIter<'a> {
 
 impl<'a> ExactSizeIterator for VariantArrayIter<'a> {}
 
+impl<'a> IntoIterator for &'a VariantArray {
+    type Item = Option<Variant<'a, 'a>>;
+    type IntoIter = VariantArrayIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 /// One shredded field of a partially or perfectly shredded variant. For example, suppose the
 /// shredding schema for variant `v` treats it as an object with a single field `a`, where `a` is
 /// itself a struct with the single field `b` of type INT. Then the physical layout of the column
@@ -1242,8 +1413,9 @@ mod test {
 
     use super::*;
     use arrow::array::{
-        BinaryViewArray, Decimal32Array, Decimal64Array, Decimal128Array, Int32Array, Int64Array,
-        LargeListArray, LargeListViewArray, ListArray, ListViewArray, Time64MicrosecondArray,
+        BinaryArray, BinaryViewArray, Decimal32Array, Decimal64Array, Decimal128Array, Int32Array,
+        Int64Array, LargeListArray, LargeListViewArray, ListArray, ListViewArray,
+        Time64MicrosecondArray,
     };
     use arrow::buffer::{OffsetBuffer, ScalarBuffer};
     use arrow_schema::{Field, Fields};
@@ -1272,6 +1444,39 @@ mod test {
         );
     }
 
+    #[test]
+    fn try_from_binary_struct_array() {
+        let metadata = BinaryArray::from(vec![EMPTY_VARIANT_METADATA_BYTES; 3]);
+        let value = BinaryArray::from(vec![Some(&[12u8, 7][..]), None, Some(&[0u8][..])]);
+        let fields = Fields::from(vec![
+            Field::new("metadata", DataType::Binary, false),
+            Field::new("value", DataType::Binary, true),
+        ]);
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let array = StructArray::new(
+            fields,
+            vec![Arc::new(metadata), Arc::new(value)],
+            Some(nulls),
+        );
+
+        let variant_array = VariantArray::try_from(&array).unwrap();
+        assert_eq!(
+            variant_array.iter().collect::<Vec<_>>(),
+            vec![Some(Variant::Int8(7)), None, Some(Variant::Null)]
+        );
+
+        // Errors are the same as those of `VariantArray::try_new`
+        let array = StructArray::new(
+            Fields::from(vec![Field::new("value", DataType::BinaryView, true)]),
+            vec![make_binary_view_array()],
+            None,
+        );
+        assert_eq!(
+            VariantArray::try_from(&array).unwrap_err().to_string(),
+            "Invalid argument error: Invalid VariantArray: StructArray must contain a 'metadata' field"
+        );
+    }
+
     #[test]
     fn all_null_missing_value_and_typed_value() {
         let fields = Fields::from(vec![Field::new("metadata", DataType::BinaryView, false)]);
@@ -1591,6 +1796,35 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_variant_array_iter_matches_indexed_access() {
+        let mut b = VariantArrayBuilder::new(4);
+        b.append_variant(Variant::from(1_i64));
+        b.append_null();
+        b.append_variant(Variant::Null);
+        b.append_variant(Variant::from("x"));
+        let array = b.build();
+
+        let expected: Vec<_> = (0..array.len())
+            .map(|i| array.is_valid(i).then(|| array.value(i)))
+            .collect();
+        assert_eq!(array.iter().collect::<Vec<_>>(), expected);
+
+        let mut looped = vec![];
+        for value in &array {
+            looped.push(value);
+        }
+        assert_eq!(
+            looped,
+            [
+                Some(Variant::from(1_i64)),
+                None,
+                Some(Variant::Null),
+                Some(Variant::from("x"))
+            ]
+        );
+    }
+
     #[test]
     fn test_variant_array_iter_empty() {
         let v = VariantArrayBuilder::new(0).build();
@@ -1643,6 +1877,191 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_from_vec_of_scalars() {
+        let array = VariantArray::from(vec![Some("a"), None, Some("b")]);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.value(0), Variant::from("a"));
+        assert!(array.is_null(1));
+        assert_eq!(array.value(2), Variant::from("b"));
+
+        let array = VariantArray::from(vec![
+            Some(Variant::from(1i8)),
+            Some(Variant::from(2.5f64)),
+            None,
+            Some(Variant::Null),
+            Some(Variant::from(false)),
+        ]);
+        assert_eq!(array.len(), 5);
+        assert_eq!(array.value(0), Variant::Int8(1));
+        assert_eq!(array.value(1), Variant::Double(2.5));
+        assert!(array.is_null(2));
+        assert_eq!(array.value(3), Variant::Null);
+        assert_eq!(array.value(4), Variant::BooleanFalse);
+
+        let array = VariantArray::from(Vec::<Option<i64>>::new());
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    fn test_statistics() {
+        let json: ArrayRef = Arc::new(arrow::array::StringArray::from(vec![
+            Some(r#"{"a": 1}"#),
+            Some(r#"{"b": "x"}"#),
+            Some("[1, 2]"),
+            Some("1"),
+            Some("2"),
+            Some(r#""s""#),
+            Some("null"),
+            None,
+            None,
+        ]));
+        let array = crate::json_to_variant(&json).unwrap();
+        let expected = VariantStats {
+            null_count: 2,
+            type_counts: BTreeMap::from([
+                ("array", 1),
+                ("int8", 2),
+                ("null", 1),
+                ("object", 2),
+                ("string", 1),
+            ]),
+        };
+        assert_eq!(array.statistics().unwrap(), expected);
+
+        // Shredding does not change the statistics, even with a nested typed_value
+        let schema = DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into());
+        let shredded = crate::shred_variant(&array, &schema).unwrap();
+        assert_eq!(shredded.statistics().unwrap(), expected);
+
+        let sliced = array.slice(3, 4);
+        let stats = sliced.statistics().unwrap();
+        assert_eq!(stats.null_count, 0);
+        assert_eq!(
+            stats.type_counts,
+            BTreeMap::from([("int8", 2), ("null", 1), ("string", 1)])
+        );
+        assert_eq!(
+            VariantArray::from(Vec::<Option<i64>>::new())
+                .statistics()
+                .unwrap(),
+            VariantStats::default()
+        );
+    }
+
+    #[test]
+    fn test_value_bytes() {
+        let mut builder = parquet_variant::VariantBuilder::new();
+        builder.new_object().with_field("a", 1i64).finish();
+        let (metadata, value) = builder.finish();
+
+        let mut array = VariantArrayBuilder::new(3);
+        array.append_variant(Variant::new(&metadata, &value));
+        array.append_null();
+        array.append_variant(Variant::from(5i64));
+        let array = array.build();
+
+        assert_eq!(
+            array.value_bytes(0),
+            Some((metadata.as_slice(), value.as_slice()))
+        );
+        assert_eq!(array.value_bytes(1), None);
+        let (metadata, value) = array.value_bytes(2).unwrap();
+        assert_eq!(Variant::new(metadata, value), Variant::from(5i64));
+
+        // Shredded rows have no bytes until unshredded
+        let shredded = crate::shred_variant(&array, &DataType::Int64).unwrap();
+        assert!(shredded.value_bytes(0).is_some());
+        assert_eq!(shredded.value_bytes(2), None);
+        let unshredded = crate::unshred_variant(&shredded).unwrap();
+        let (metadata, value) = unshredded.value_bytes(2).unwrap();
+        assert_eq!(Variant::new(metadata, value), Variant::from(5i64));
+    }
+
+    #[test]
+    fn test_validate_metadata() {
+        let array = VariantArray::from(vec![Some(1i64), None, Some(2)]);
+        array.validate_metadata().unwrap();
+
+        // Bump the version of the last row's metadata
+        let mut metadata: Vec<Vec<u8>> = array
+            .metadata_field()
+            .iter()
+            .map(|m| m.unwrap().to_vec())
+            .collect();
+        metadata[2][0] = (metadata[2][0] & 0xF0) | 2;
+        let metadata = BinaryViewArray::from_iter_values(metadata);
+        let bumped = VariantArray::from_parts(
+            metadata.clone(),
+            array.value_field().cloned(),
+            None,
+            array.nulls().cloned(),
+        );
+        let err = bumped.validate_metadata().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid VariantArray metadata at row 2: Invalid argument error: The version bytes in the header is not 1, got 10"
+        );
+        // Rows with valid metadata can still be read
+        assert_eq!(bumped.value(0), Variant::from(1i64));
+
+        // Null rows are not checked
+        let nulls = NullBuffer::from(vec![true, false, false]);
+        let masked =
+            VariantArray::from_parts(metadata, array.value_field().cloned(), None, Some(nulls));
+        masked.validate_metadata().unwrap();
+    }
+
+    #[test]
+    fn test_variant_array_field_ipc_round_trip() {
+        use arrow::ipc::reader::StreamReader;
+        use arrow::ipc::writer::StreamWriter;
+        use arrow::record_batch::RecordBatch;
+        use arrow_schema::Schema;
+
+        let array = VariantArray::from(vec![Some("a"), None, Some("c")]);
+        let field = array.field("v");
+        assert_eq!(
+            field.metadata(),
+            &std::collections::HashMap::from([
+                (
+                    "ARROW:extension:name".to_string(),
+                    "arrow.parquet.variant".to_string()
+                ),
+                ("ARROW:extension:metadata".to_string(), String::new()),
+            ])
+        );
+
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![ArrayRef::from(array.clone())]).unwrap();
+        let mut buffer = vec![];
+        let mut writer = StreamWriter::try_new(&mut buffer, &schema).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let batch = StreamReader::try_new(buffer.as_slice(), None)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let read =
+            VariantArray::try_new_with_field(batch.schema().field(0), batch.column(0)).unwrap();
+        assert_eq!(
+            read.iter().collect::<Vec<_>>(),
+            array.iter().collect::<Vec<_>>()
+        );
+
+        // Fields without the extension type are rejected
+        let plain = Field::new("v", array.data_type().clone(), true);
+        let err = VariantArray::try_new_with_field(&plain, array.inner()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field 'v' is not a VariantArray: Invalid argument error: Extension type name missing"
+        );
+    }
+
     #[test]
     fn test_variant_equality() {
         let v_iter = [None, Some(Variant::BooleanFalse), Some(Variant::Null), None];
@@ -1666,6 +2085,41 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_variant_array_slice() {
+        let array: VariantArray = (0..10_i32)
+            .map(|i| (i % 4 != 1).then(|| Variant::from(i)))
+            .collect();
+
+        let sliced = array.slice(3, 4);
+        assert_eq!(sliced.len(), 4);
+        for i in 0..sliced.len() {
+            assert_eq!(sliced.is_null(i), array.is_null(i + 3));
+            if sliced.is_valid(i) {
+                assert_eq!(sliced.value(i), array.value(i + 3));
+            }
+        }
+        assert_eq!(sliced.value(0), array.value(3));
+        assert!(sliced.is_null(2)); // row 5 of the original array
+
+        // The slice shares its buffers with the original array
+        let views_ptr = |array: &BinaryViewArray| array.views().as_ptr();
+        assert_eq!(
+            views_ptr(sliced.metadata_field()),
+            array.metadata_field().views()[3..].as_ptr()
+        );
+        assert_eq!(
+            views_ptr(sliced.value_field().unwrap()),
+            array.value_field().unwrap().views()[3..].as_ptr()
+        );
+        let nulls = sliced.nulls().unwrap();
+        assert_eq!(nulls.offset(), 3);
+        assert_eq!(
+            nulls.buffer().as_ptr(),
+            array.nulls().unwrap().buffer().as_ptr()
+        );
+    }
+
     macro_rules! invalid_variant_array_test {
         ($fn_name: ident, $invalid_typed_value: expr, $error_msg: literal) => {
             #[test]
@@ -18,15 +18,22 @@
 //! [`VariantArrayBuilder`] implementation
 
 use crate::VariantArray;
-use arrow::array::{ArrayRef, BinaryViewArray, BinaryViewBuilder, NullBufferBuilder, StructArray};
+use crate::arrow_to_variant::make_arrow_to_variant_row_builder;
+use arrow::array::{
+    Array, ArrayRef, ArrowPrimitiveType, BinaryViewArray, BinaryViewBuilder, NullBufferBuilder,
+    PrimitiveArray, StructArray,
+};
+use arrow::compute::CastOptions;
 use arrow_schema::{ArrowError, DataType, Field, Fields};
 use parquet_variant::{
     BuilderSpecificState, ListBuilder, MetadataBuilder, ObjectBuilder, Variant, VariantBuilderExt,
-    VariantMetadata,
+    VariantList, VariantMetadata, VariantObject,
 };
 use parquet_variant::{
     ParentState, ReadOnlyMetadataBuilder, ValueBuilder, WritableMetadataBuilder,
 };
+use parquet_variant_json::append_json;
+use serde_json::Value;
 use std::sync::Arc;
 
 /// A builder for [`VariantArray`]
@@ -161,6 +168,109 @@ impl VariantArrayBuilder {
         ValueBuilder::append_variant(self.parent_state(), variant);
     }
 
+    /// Appends an already serialized variant as the next row, copying `metadata` and `value`
+    /// directly into the builder's buffers.
+    ///
+    /// This avoids decoding and re-encoding variants that were produced elsewhere. If `validate`
+    /// is true, the bytes are first fully validated (see [`Variant::try_new`]); otherwise the
+    /// caller must ensure that they are a valid variant, or later accesses may fail or panic.
+    ///
+    /// # Errors
+    /// - If `validate` is true and the bytes are not a valid variant
+    pub fn append_raw(
+        &mut self,
+        metadata: &[u8],
+        value: &[u8],
+        validate: bool,
+    ) -> Result<(), ArrowError> {
+        if validate {
+            Variant::try_new(metadata, value)?;
+        }
+        self.append_encoded(metadata, value);
+        Ok(())
+    }
+
+    /// Appends a borrowed [`Variant`] as the next row.
+    ///
+    /// Objects and lists are copied byte for byte, together with their metadata dictionary,
+    /// without being decoded or re-encoded (see [`Self::append_raw`]). Other values hold no
+    /// metadata and are small, so they are encoded as by [`Self::append_variant`].
+    ///
+    /// Note that the whole metadata dictionary is copied, including any field names that the
+    /// value does not use, e.g. when `variant` is a nested field of a larger object.
+    pub fn append_variant_ref(&mut self, variant: &Variant<'_, '_>) {
+        match variant {
+            Variant::Object(VariantObject {
+                metadata, value, ..
+            })
+            | Variant::List(VariantList {
+                metadata, value, ..
+            }) => self.append_encoded(metadata.as_bytes(), value),
+            _ => self.append_variant(variant.clone()),
+        }
+    }
+
+    /// Appends each JSON value as the next row, encoded as by [`json_to_variant`].
+    ///
+    /// A top-level [`Value::Null`] produces a null row if `null_as_array_null` is true, and a
+    /// [`Variant::Null`] row otherwise. Nested `null`s are always [`Variant::Null`].
+    ///
+    /// # Errors
+    /// - If a number cannot be represented as a variant. Rows appended before the failing value
+    ///   are kept.
+    ///
+    /// [`json_to_variant`]: crate::json_to_variant
+    pub fn extend_json<I: IntoIterator<Item = Value>>(
+        &mut self,
+        values: I,
+        null_as_array_null: bool,
+    ) -> Result<(), ArrowError> {
+        for value in values {
+            match value {
+                Value::Null if null_as_array_null => self.append_null(),
+                value => append_json(&value, self)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends each element of `array` as the next row, encoded as by [`cast_to_variant`]. Null
+    /// elements produce null rows.
+    ///
+    /// This is the inverse of extracting a primitive type with [`variant_get`].
+    ///
+    /// # Errors
+    /// - If `T` has no variant equivalent (e.g. intervals), in which case no rows are appended
+    /// - If an element cannot be converted (e.g. an out of range timestamp). Rows appended before
+    ///   the failing element are kept.
+    ///
+    /// [`cast_to_variant`]: crate::cast_to_variant
+    /// [`variant_get`]: crate::variant_get
+    pub fn append_primitive_array<T: ArrowPrimitiveType>(
+        &mut self,
+        array: &PrimitiveArray<T>,
+    ) -> Result<(), ArrowError> {
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let mut row_builder =
+            make_arrow_to_variant_row_builder(array.data_type(), array, &options)?;
+        for i in 0..array.len() {
+            row_builder.append_row(self, i)?;
+        }
+        Ok(())
+    }
+
+    /// Copies already serialized metadata and value bytes into the next row
+    fn append_encoded(&mut self, metadata: &[u8], value: &[u8]) {
+        self.metadata_offsets
+            .push(self.metadata_builder.append_encoded(metadata));
+        self.value_builder.append_encoded(value);
+        self.value_offsets.push(self.value_builder.offset());
+        self.nulls.append_non_null();
+    }
+
     /// Creates a builder-specific parent state
     fn parent_state(&mut self) -> ParentState<'_, ArrayBuilderState<'_>> {
         let state = ArrayBuilderState {
@@ -459,8 +569,10 @@ fn binary_view_array_from_buffers(buffer: Vec<u8>, offsets: Vec<usize>) -> Binar
 #[cfg(test)]
 mod test {
     use super::*;
-    use arrow::array::Array;
-    use parquet_variant::{ShortString, Variant};
+    use crate::{GetOptions, variant_get};
+    use arrow::array::{Array, AsArray, Date32Array, Int64Array, IntervalYearMonthArray};
+    use parquet_variant::{EMPTY_VARIANT_METADATA_BYTES, ShortString, Variant, VariantPath};
+    use serde_json::json;
 
     /// Test that both the metadata and value buffers are non nullable
     #[test]
@@ -526,6 +638,151 @@ mod test {
         assert_eq!(list.len(), 2);
     }
 
+    /// Test that null rows only touch the null buffer
+    #[test]
+    fn test_variant_array_builder_append_null() {
+        let mut builder = VariantArrayBuilder::new(3);
+        builder.append_variant(Variant::from(1i32));
+        builder.append_null();
+        builder.append_variant(Variant::from("two"));
+        let variant_array = builder.build();
+
+        assert_eq!(variant_array.len(), 3);
+        let nulls = variant_array.nulls().expect("array should have nulls");
+        assert_eq!(nulls.iter().collect::<Vec<_>>(), vec![true, false, true]);
+        assert!(variant_array.is_null(1));
+        assert_eq!(variant_array.value(0), Variant::from(1i32));
+        assert_eq!(variant_array.value(2), Variant::from("two"));
+
+        // No metadata or value bytes are written for the null row
+        assert!(variant_array.metadata_field().value(1).is_empty());
+        assert!(variant_array.value_field().unwrap().value(1).is_empty());
+    }
+
+    #[test]
+    fn test_variant_array_builder_append_raw() {
+        let mut variant = parquet_variant::VariantBuilder::new();
+        variant.new_object().with_field("a", 1i32).finish();
+        let (metadata, value) = variant.finish();
+
+        let mut builder = VariantArrayBuilder::new(4);
+        builder.append_raw(&metadata, &value, true).unwrap();
+        builder.append_variant(Variant::from("built"));
+        builder.append_null();
+        builder.append_raw(&metadata, &value, false).unwrap();
+
+        // Invalid bytes are rejected when validating, without adding a row
+        let err = builder
+            .append_raw(&metadata, &value[..1], true)
+            .unwrap_err();
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)), "{err}");
+
+        let variant_array = builder.build();
+        assert_eq!(variant_array.len(), 4);
+        assert_eq!(variant_array.metadata_field().value(0), metadata.as_slice());
+        assert_eq!(
+            variant_array.value_field().unwrap().value(0),
+            value.as_slice()
+        );
+        for i in [0, 3] {
+            let row = variant_array.value(i);
+            assert_eq!(row.get_object_field("a"), Some(Variant::Int32(1)));
+        }
+        assert_eq!(variant_array.value(1), Variant::from("built"));
+        assert!(variant_array.is_null(2));
+    }
+
+    #[test]
+    fn test_variant_array_builder_append_variant_ref() {
+        let mut variant = parquet_variant::VariantBuilder::new();
+        let mut object = variant.new_object();
+        object.insert("a", 1i32);
+        object
+            .new_list("b")
+            .with_value("x")
+            .with_value(2i8)
+            .finish();
+        object.finish();
+        let (metadata, value) = variant.finish();
+        let source = Variant::new(&metadata, &value);
+
+        let mut builder = VariantArrayBuilder::new(4);
+        builder.append_variant_ref(&source);
+        builder.append_variant_ref(&source.get_object_field("b").unwrap());
+        builder.append_variant_ref(&Variant::from("short"));
+        builder.append_variant_ref(&Variant::from(3i64));
+        let variant_array = builder.build();
+
+        // Objects and lists are copied as-is, keeping the full metadata dictionary
+        let metadata_field = variant_array.metadata_field();
+        let value_field = variant_array.value_field().unwrap();
+        assert_eq!(metadata_field.value(0), metadata.as_slice());
+        assert_eq!(value_field.value(0), value.as_slice());
+        assert_eq!(metadata_field.value(1), metadata.as_slice());
+        let Variant::List(list) = source.get_object_field("b").unwrap() else {
+            panic!("expected list");
+        };
+        assert_eq!(value_field.value(1), list.value);
+
+        assert_eq!(variant_array.value(0), source);
+        assert_eq!(
+            variant_array.value(1).get_list_element(0),
+            Some(Variant::from("x"))
+        );
+        assert_eq!(variant_array.value(2), Variant::from("short"));
+        assert_eq!(variant_array.value(3), Variant::from(3i64));
+        assert_eq!(metadata_field.value(3), EMPTY_VARIANT_METADATA_BYTES);
+    }
+
+    #[test]
+    fn test_variant_array_builder_extend_json() {
+        let values = vec![json!({"a": 1}), json!(null), json!({"a": [2]}), json!("x")];
+        for null_as_array_null in [true, false] {
+            let mut builder = VariantArrayBuilder::new(values.len());
+            builder
+                .extend_json(values.clone(), null_as_array_null)
+                .unwrap();
+            let array = builder.build();
+            assert_eq!(array.is_null(1), null_as_array_null);
+            if !null_as_array_null {
+                assert_eq!(array.value(1), Variant::Null);
+            }
+            assert_eq!(array.value(3), Variant::from("x"));
+
+            let options = GetOptions::new_with_path(VariantPath::try_from("a").unwrap())
+                .with_as_data_type(DataType::Int64);
+            let a = variant_get(&ArrayRef::from(array), options).unwrap();
+            assert_eq!(
+                a.as_primitive(),
+                &Int64Array::from(vec![Some(1), None, None, None])
+            );
+        }
+    }
+
+    #[test]
+    fn test_variant_array_builder_append_primitive_array() {
+        let ints = Int64Array::from(vec![Some(1), None, Some(i64::MAX)]);
+        let dates = Date32Array::from(vec![Some(0), None]);
+        let mut builder = VariantArrayBuilder::new(5);
+        builder.append_primitive_array(&ints).unwrap();
+        builder.append_primitive_array(&dates).unwrap();
+        let err = builder
+            .append_primitive_array(&IntervalYearMonthArray::from(vec![1]))
+            .unwrap_err();
+        assert!(err.to_string().contains("interval"), "{err}");
+        let array = builder.build();
+
+        assert_eq!(array.len(), 5);
+        assert!(array.is_null(1) && array.is_null(4));
+        assert_eq!(array.value(3), Variant::Date(chrono::NaiveDate::default()));
+
+        // Round trip the integers back through variant_get
+        let ints_variant = array.slice(0, 3);
+        let options = GetOptions::new().with_as_data_type(DataType::Int64);
+        let result = variant_get(&ArrayRef::from(ints_variant), options).unwrap();
+        assert_eq!(result.as_primitive(), &ints);
+    }
+
     #[test]
     fn test_extend_variant_array_builder() {
         let mut b = VariantArrayBuilder::new(3);
@@ -15,17 +15,28 @@
 // specific language governing permissions and limitations
 // under the License.
 use arrow::{
-    array::{self, Array, ArrayRef, BinaryViewArray, StructArray},
+    array::{
+        self, Array, ArrayRef, BinaryViewArray, ListArray, NullBufferBuilder, OffsetBufferBuilder,
+        Scalar, StructArray,
+    },
+    buffer::NullBuffer,
     compute::CastOptions,
     datatypes::Field,
     error::Result,
 };
 use arrow_schema::{ArrowError, DataType, FieldRef};
-use parquet_variant::{VariantPath, VariantPathElement};
+use parquet_variant::{
+    EMPTY_VARIANT_METADATA, ObjectFieldBuilder, Variant, VariantBuilder, VariantBuilderExt,
+    VariantPath, VariantPathElement,
+};
+use parquet_variant_json::append_json;
 
-use crate::VariantArray;
+use crate::type_conversion::variant_cast_with_options;
 use crate::variant_array::BorrowedShreddingState;
-use crate::variant_to_arrow::make_variant_to_arrow_row_builder;
+use crate::variant_to_arrow::{
+    FieldIdCache, make_primitive_variant_to_arrow_row_builder, make_variant_to_arrow_row_builder,
+};
+use crate::{VariantArray, VariantArrayBuilder, VariantType, unshred_variant};
 
 use arrow::array::AsArray;
 use std::sync::Arc;
@@ -42,11 +53,28 @@ pub(crate) enum ShreddedPathStep<'a> {
     NotShredded,
 }
 
+/// Returns a null buffer that is NULL for the rows where a shredded object field is missing, i.e.
+/// both its `value` and `typed_value` are NULL, or `None` if the field is present in every row.
+fn missing_field_nulls(shredding_state: &BorrowedShreddingState<'_>) -> Option<NullBuffer> {
+    let value = shredding_state.value_field().map(|v| v.logical_nulls());
+    let typed_value = shredding_state
+        .typed_value_field()
+        .map(|v| v.logical_nulls());
+    let validity = match (value, typed_value) {
+        // A column that contains no NULLs means the field is present in every row
+        (Some(None), _) | (_, Some(None)) | (None, None) => return None,
+        (Some(Some(value)), Some(Some(typed_value))) => value.inner() | typed_value.inner(),
+        (Some(Some(nulls)), None) | (None, Some(Some(nulls))) => nulls.into_inner(),
+    };
+    Some(NullBuffer::new(validity)).filter(|nulls| nulls.null_count() > 0)
+}
+
 /// Given a shredded variant field -- a `(value?, typed_value?)` pair -- try to take one path step
 /// deeper. For a `VariantPathElement::Field`, the step fails if there is no `typed_value` at this
 /// level, or if `typed_value` is not a struct, or if the requested field name does not exist.
 ///
-/// TODO: Support `VariantPathElement::Index`? It wouldn't be easy, and maybe not even possible.
+/// Indexes are never followed here: [`shredded_get_path`] unshreds the column at the first index
+/// into a shredded `typed_value` and follows the rest of the path row by row.
 pub(crate) fn follow_shredded_path_element<'a>(
     shredding_state: &BorrowedShreddingState<'a>,
     path_element: &VariantPathElement<'_>,
@@ -99,7 +127,11 @@ pub(crate) fn follow_shredded_path_element<'a>(
             let state = BorrowedShreddingState::try_from(struct_array)?;
             Ok(ShreddedPathStep::Success(state))
         }
-        VariantPathElement::Index { .. } => {
+        VariantPathElement::FieldId { .. } => Err(ArrowError::NotYetImplemented(
+            "Pathing into shredded variant field by id".into(),
+        )),
+        _ => {
+            // Callers follow indexes row by row, and expand wildcards before reaching here.
             // TODO: Support array indexing. Among other things, it will require slicing not
             // only the array we have here, but also the corresponding metadata and null masks.
             Err(ArrowError::NotYetImplemented(
@@ -112,12 +144,27 @@ pub(crate) fn follow_shredded_path_element<'a>(
 /// Follows the given path as far as possible through shredded variant fields. If the path ends on a
 /// shredded field, return it directly. Otherwise, use a row shredder to follow the rest of the path
 /// and extract the requested value on a per-row basis.
-fn shredded_get_path(
+///
+/// `context` locates `input` within the original array, and is only used for error messages.
+fn shredded_get_path<'p>(
     input: &VariantArray,
-    path: &[VariantPathElement<'_>],
+    context: ErrorContext<'_, 'p>,
+    path: &[VariantPathElement<'p>],
     as_field: Option<&Field>,
     cast_options: &CastOptions,
+    strategy: GetStrategy,
 ) -> Result<ArrayRef> {
+    // Shredded fields are found by name, which a field id does not carry, so follow such paths
+    // through the unshredded values instead
+    if input.typed_value_field().is_some()
+        && path
+            .iter()
+            .any(|element| matches!(element, VariantPathElement::FieldId { .. }))
+    {
+        let input = unshred_variant(input)?;
+        return shredded_get_path(&input, context, path, as_field, cast_options, strategy);
+    }
+
     // Helper that creates a new VariantArray from the given nested value and typed_value columns,
     // properly accounting for accumulated nulls from path traversal
     let make_target_variant =
@@ -130,11 +177,11 @@ fn shredded_get_path(
 
     // Helper that shreds a VariantArray to a specific type.
     let shred_basic_variant =
-        |target: VariantArray, path: VariantPath<'_>, as_field: Option<&Field>| {
+        |target: VariantArray, row_path: VariantPath<'_>, as_field: Option<&Field>| {
             let as_type = as_field.map(|f| f.data_type());
             let mut builder = make_variant_to_arrow_row_builder(
                 target.metadata_field(),
-                path,
+                row_path,
                 as_type,
                 cast_options,
                 target.len(),
@@ -144,7 +191,9 @@ fn shredded_get_path(
                     builder.append_null()?;
                 } else if !cast_options.safe {
                     let value = target.try_value(i)?;
-                    builder.append_value(value)?;
+                    builder
+                        .append_value(value)
+                        .map_err(|e| context.wrap(e, i, path))?;
                 } else {
                     let _ = match target.try_value(i) {
                         Ok(v) => builder.append_value(v)?,
@@ -164,6 +213,21 @@ fn shredded_get_path(
     let mut accumulated_nulls = input.inner().nulls().cloned();
     let mut path_index = 0;
     for path_element in path {
+        // Shredded lists cannot be indexed column-wise yet, so follow the rest of the path row by
+        // row through the unshredded values
+        if matches!(
+            path_element,
+            VariantPathElement::Index { .. } | VariantPathElement::IndexFromEnd { .. }
+        ) && shredding_state.typed_value_field().is_some()
+        {
+            let target = make_target_variant(
+                shredding_state.value_field().cloned(),
+                shredding_state.typed_value_field().cloned(),
+                accumulated_nulls,
+            );
+            let row_path = &path[path_index..];
+            return shred_basic_variant(unshred_variant(&target)?, row_path.into(), as_field);
+        }
         match follow_shredded_path_element(&shredding_state, path_element, cast_options)? {
             ShreddedPathStep::Success(state) => {
                 // Union nulls from the typed_value we just accessed
@@ -181,7 +245,10 @@ fn shredded_get_path(
                 let num_rows = input.len();
                 let arr = match as_field.map(|f| f.data_type()) {
                     Some(data_type) => array::new_null_array(data_type, num_rows),
-                    None => Arc::new(array::NullArray::new(num_rows)) as _,
+                    None => ArrayRef::from(VariantArray::from_iter(std::iter::repeat_n(
+                        None::<Variant>,
+                        num_rows,
+                    ))),
                 };
                 return Ok(arr);
             }
@@ -191,7 +258,21 @@ fn shredded_get_path(
                     None,
                     accumulated_nulls,
                 );
-                return shred_basic_variant(target, path[path_index..].into(), as_field);
+                let row_path = &path[path_index..];
+                let data_type = as_field.map(|f| f.data_type());
+                if let Some(data_type) = data_type
+                    .filter(|t| strategy == GetStrategy::Columnar && is_primitive_get_type(t))
+                {
+                    return columnar_get_primitive(
+                        &target,
+                        data_type,
+                        cast_options,
+                        context,
+                        path,
+                        row_path,
+                    );
+                }
+                return shred_basic_variant(target, row_path.into(), as_field);
             }
         };
     }
@@ -203,9 +284,22 @@ fn shredded_get_path(
         accumulated_nulls,
     );
 
-    // If our caller did not request any specific type, we can just return whatever we landed on.
+    // If our caller did not request any specific type, we can just return whatever we landed on,
+    // except that rows where the field we landed on is missing must be NULL rather than
+    // `Variant::Null`.
     let Some(as_field) = as_field else {
-        return Ok(ArrayRef::from(target));
+        if path.is_empty() {
+            return Ok(ArrayRef::from(target));
+        }
+        let nulls = NullBuffer::union(
+            target.nulls(),
+            missing_field_nulls(&shredding_state).as_ref(),
+        );
+        return Ok(ArrayRef::from(make_target_variant(
+            shredding_state.value_field().cloned(),
+            shredding_state.typed_value_field().cloned(),
+            nulls,
+        )));
     };
 
     // Try to return the typed value directly when we have a perfect shredding match.
@@ -227,19 +321,25 @@ fn shredded_get_path(
             return shred_basic_variant(target, VariantPath::default(), Some(as_field));
         }
 
+        let struct_path: Vec<_> = context.path.iter().chain(path).cloned().collect();
         let children = fields
             .iter()
             .map(|field| {
                 shredded_get_path(
                     &target,
+                    ErrorContext {
+                        path: &struct_path,
+                        ..context
+                    },
                     &[VariantPathElement::from(field.name().as_str())],
                     Some(field),
                     cast_options,
+                    strategy,
                 )
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let struct_nulls = target.nulls().cloned();
+        let struct_nulls = shredded_struct_nulls(&target, context, path, cast_options)?;
 
         return Ok(Arc::new(StructArray::try_new(
             fields.clone(),
@@ -248,10 +348,163 @@ fn shredded_get_path(
         )?));
     }
 
-    // Not a struct, so directly shred the variant as the requested type
+    // Unshredded primitives can be decoded directly from the `value` column
+    if strategy != GetStrategy::Rowwise
+        && target.typed_value_field().is_none()
+        && is_primitive_get_type(as_field.data_type())
+    {
+        let data_type = as_field.data_type();
+        return columnar_get_primitive(&target, data_type, cast_options, context, path, &[]);
+    }
+
+    // Not a struct, so directly shred the variant as the requested type. Row-by-row access cannot
+    // read nested `typed_value` columns (e.g. a shredded list), so unshred those first.
+    let target = match target.typed_value_field() {
+        Some(typed_value) if typed_value.data_type().is_nested() => unshred_variant(&target)?,
+        _ => target,
+    };
     shred_basic_variant(target, VariantPath::default(), Some(as_field))
 }
 
+/// Whether `data_type` is extracted by a [`PrimitiveVariantToArrowRowBuilder`]
+///
+/// [`PrimitiveVariantToArrowRowBuilder`]: crate::variant_to_arrow::PrimitiveVariantToArrowRowBuilder
+fn is_primitive_get_type(data_type: &DataType) -> bool {
+    data_type.is_primitive()
+        || matches!(
+            data_type,
+            DataType::Boolean
+                | DataType::Utf8
+                | DataType::LargeUtf8
+                | DataType::Utf8View
+                | DataType::Binary
+                | DataType::LargeBinary
+                | DataType::BinaryView
+        )
+}
+
+/// Extracts a primitive type from an unshredded `VariantArray`, decoding the `value` column in a
+/// single pass.
+///
+/// Primitive variant values never reference the metadata dictionary, so they are decoded without
+/// decoding each row's metadata. Objects and lists (which can't be converted to a primitive type,
+/// but may appear in error messages) still get their metadata. If `row_path` is not empty, it is
+/// followed within each row first.
+fn columnar_get_primitive<'p>(
+    target: &VariantArray,
+    data_type: &DataType,
+    cast_options: &CastOptions,
+    context: ErrorContext<'_, 'p>,
+    path: &[VariantPathElement<'p>],
+    row_path: &[VariantPathElement<'p>],
+) -> Result<ArrayRef> {
+    let mut builder =
+        make_primitive_variant_to_arrow_row_builder(data_type, cast_options, target.len())?;
+    let Some(values) = target.value_field() else {
+        // Neither `value` nor `typed_value`: every row is `Variant::Null`
+        (0..target.len()).try_for_each(|_| builder.append_null())?;
+        return builder.finish();
+    };
+
+    let metadata = target.metadata_field();
+    let nulls = NullBuffer::union(target.nulls(), values.nulls());
+    let mut field_ids = FieldIdCache::default();
+    for (i, bytes) in values.iter().enumerate() {
+        let bytes = match bytes {
+            Some(bytes) if nulls.as_ref().is_none_or(|n| n.is_valid(i)) => bytes,
+            _ => {
+                builder.append_null()?;
+                continue;
+            }
+        };
+        // The two low bits of the value header are the basic type; 2 and 3 are object and array
+        let variant = match bytes.first().map(|header| header & 0x03) {
+            Some(2 | 3) => Variant::new(metadata.value(i), bytes),
+            _ => Variant::new_with_metadata(EMPTY_VARIANT_METADATA, bytes),
+        };
+        let variant = match row_path {
+            [] => Some(variant),
+            _ => field_ids
+                .follow_path(variant, row_path, cast_options)
+                .map_err(|e| context.wrap(e, i, path))?,
+        };
+        match variant {
+            Some(variant) => builder
+                .append_value(&variant)
+                .map_err(|e| context.wrap(e, i, path))
+                .map(|_| ())?,
+            None => builder.append_null()?,
+        }
+    }
+    builder.finish()
+}
+
+/// Locates a [`VariantArray`] being extracted from within the array originally passed to
+/// [`variant_get`], so that errors can point at the offending input row and path.
+#[derive(Clone, Copy, Default)]
+struct ErrorContext<'a, 'p> {
+    /// The path that led to the array
+    path: &'a [VariantPathElement<'p>],
+    /// The input row of each row in the array, if they differ (e.g. for wildcard elements)
+    rows: Option<&'a [usize]>,
+}
+
+impl<'p> ErrorContext<'_, 'p> {
+    /// The input row that row `row` of the array came from
+    fn input_row(&self, row: usize) -> usize {
+        self.rows.map_or(row, |rows| rows[row])
+    }
+
+    /// Adds the input row and the full requested path to a cast error raised while extracting
+    /// `path` from row `row` of the array
+    fn wrap(&self, err: ArrowError, row: usize, path: &[VariantPathElement<'p>]) -> ArrowError {
+        match err {
+            ArrowError::CastError(msg) => {
+                let row = self.input_row(row);
+                let path: VariantPath = self.path.iter().chain(path).cloned().collect();
+                ArrowError::CastError(format!("row {row}: {msg} at path {path}"))
+            }
+            e => e,
+        }
+    }
+}
+
+/// Computes the nulls of a struct extracted from a shredded `target`. Rows that are not shredded
+/// as an object fall back to `value`, which must then hold an object: other values produce NULL
+/// structs, or a cast error in strict mode.
+fn shredded_struct_nulls<'p>(
+    target: &VariantArray,
+    context: ErrorContext<'_, 'p>,
+    path: &[VariantPathElement<'p>],
+    cast_options: &CastOptions,
+) -> Result<Option<NullBuffer>> {
+    let Some(value) = target.value_field() else {
+        return Ok(target.nulls().cloned());
+    };
+    let typed_value = target.typed_value_field();
+    let mut nulls = NullBufferBuilder::new(target.len());
+    for i in 0..target.len() {
+        let falls_back = value.is_valid(i) && typed_value.is_none_or(|t| t.is_null(i));
+        if target.is_null(i) {
+            nulls.append_null();
+        } else if falls_back {
+            let variant = Variant::try_new(target.metadata_field().value(i), value.value(i))?;
+            match variant_cast_with_options(&variant, cast_options, Variant::as_object) {
+                Ok(object) => nulls.append(object.is_some()),
+                Err(_) => {
+                    let err = ArrowError::CastError(format!(
+                        "Failed to extract struct from variant {variant:?}"
+                    ));
+                    return Err(context.wrap(err, i, path));
+                }
+            }
+        } else {
+            nulls.append_non_null();
+        }
+    }
+    Ok(nulls.finish())
+}
+
 fn try_perfect_shredding(variant_array: &VariantArray, as_field: &Field) -> Option<ArrayRef> {
     // Try to return the typed value directly when we have a perfect shredding match.
     if matches!(as_field.data_type(), DataType::Struct(_)) {
@@ -276,29 +529,787 @@ fn try_perfect_shredding(variant_array: &VariantArray, as_field: &Field) -> Opti
 
 /// Returns an array with the specified path extracted from the variant values.
 ///
+/// `input` can be any struct that [`VariantArray::try_new`] accepts, such as a plain struct of
+/// `metadata` and `value` binary columns read from parquet, with or without the variant extension
+/// type. An error is only returned if it is not shaped like a (possibly shredded) variant.
+///
 /// The return array type depends on the `as_type` field of the options parameter
 /// 1. `as_type: None`: a VariantArray is returned. The values in this new VariantArray will point
 ///    to the specified path.
-/// 2. `as_type: Some(<specific field>)`: an array of the specified type is returned.
+/// 2. `as_type: Some(<specific field>)`: an array of the specified type is returned. Its data type
+///    is exactly that of the field, including the names and metadata (e.g. extension types) of
+///    any nested struct fields or list items. An [`ArrayRef`] carries no field of its own, so use
+///    `as_type` itself to describe the result in a schema.
+///
+/// When `as_type` is a string type (`Utf8`, `LargeUtf8` or `Utf8View`), only variant string values
+/// are extracted. Other values (numbers, booleans, objects, lists, ...) are not stringified: they
+/// become NULL when `cast_options.safe` is true and produce an error otherwise. Use
+/// [`variant_to_json`](crate::variant_to_json) to render arbitrary variant values as text.
+///
+/// When `as_type` is an integer type, variant integers of any width are converted as long as the
+/// value fits: narrower values are always widened, and values that overflow the requested type
+/// follow the same safe/strict rules as other failed conversions.
+///
+/// When `as_type` is a floating point type, variant integers, decimals and floating point values
+/// are rounded to the nearest representable value. A `Float32` result cannot hold doubles beyond
+/// the `f32` range, which follow the same safe/strict rules as other failed conversions.
+///
+/// When `as_type` is a timestamp type, variant timestamps of any precision are converted to the
+/// requested unit as long as no precision is lost. Variant strings are parsed as RFC 3339
+/// timestamps: with a UTC offset for timezone-aware types, and without one for timezone-naive
+/// types. Unparseable strings follow the same safe/strict rules as other failed conversions.
+/// Date types additionally accept variant timestamps, truncated to their date, and `YYYY-MM-DD`
+/// strings.
+///
+/// If [`GetOptions::default_value`] is set, it replaces every NULL in the result. Otherwise, if
+/// `as_type` is not nullable, the result must not contain any NULLs (from missing paths, NULL
+/// values, or failed conversions in safe mode); an error is returned otherwise.
+///
+/// When the path contains a [`VariantPathElement::Wildcard`] (e.g. `$.tags[*]`), every element of
+/// the list at that position is gathered into a `ListArray`, whose items are the remainder of the
+/// path extracted as described above. Rows where the path does not lead to a list produce a NULL
+/// list entry; empty lists produce empty (non-NULL) entries.
 ///
 /// TODO: How would a caller request a struct or list type where the fields/elements can be any
 /// variant? Caller can pass None as the requested type to fetch a specific path, but it would
 /// quickly become annoying (and inefficient) to call `variant_get` for each leaf value in a struct or
 /// list and then try to assemble the results.
 pub fn variant_get(input: &ArrayRef, options: GetOptions) -> Result<ArrayRef> {
+    compile_variant_get(options)?.execute(input)
+}
+
+/// Validates `options` once, returning a [`VariantGetPlan`] that extracts them from any number of
+/// batches with the same results as [`variant_get`].
+///
+/// This is useful for query engines that extract the same path from every batch of a stream: an
+/// invalid `default_value` or a `default_value` combined with a wildcard path is reported here,
+/// rather than for each batch.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{ArrayRef, AsArray, StringArray};
+/// # use arrow::datatypes::{DataType, Int64Type};
+/// # use parquet_variant::{Variant, VariantPath};
+/// # use parquet_variant_compute::{GetOptions, compile_variant_get, json_to_variant};
+/// # use std::sync::Arc;
+/// let options = GetOptions::new_with_path(VariantPath::try_from("a").unwrap())
+///     .with_as_data_type(DataType::Int64)
+///     .with_default_value(Some(Variant::from(0)));
+/// let plan = compile_variant_get(options).unwrap();
+///
+/// for (batch, expected) in [(r#"{"a": 1}"#, 1), (r#"{"b": 2}"#, 0)] {
+///     let json: ArrayRef = Arc::new(StringArray::from(vec![batch]));
+///     let input = ArrayRef::from(json_to_variant(&json).unwrap());
+///     let result = plan.execute(&input).unwrap();
+///     assert_eq!(result.as_primitive::<Int64Type>().value(0), expected);
+/// }
+/// ```
+pub fn compile_variant_get(options: GetOptions<'_>) -> Result<VariantGetPlan<'_>> {
+    let has_wildcard = options
+        .path
+        .iter()
+        .any(|element| matches!(element, VariantPathElement::Wildcard));
+    if options.default_value.is_some() && has_wildcard {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "A default value cannot be used with the wildcard path {}",
+            options.path
+        )));
+    }
+    if options.parse_json_strings
+        && options
+            .path
+            .iter()
+            .any(|element| matches!(element, VariantPathElement::FieldId { .. }))
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "JSON strings cannot be parsed with the field id path {}",
+            options.path
+        )));
+    }
+
+    let typed_default = match (&options.default_value, &options.as_type) {
+        (Some(default), Some(field)) => {
+            let strict = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
+            let default_options = GetOptions::new()
+                .with_as_type(Some(field.clone()))
+                .with_cast_options(strict);
+            let typed_default = variant_get_scalar(default, default_options).map_err(|_| {
+                ArrowError::CastError(format!(
+                    "Default value {default:?} cannot be converted to {}",
+                    field.data_type()
+                ))
+            })?;
+            Some(typed_default)
+        }
+        _ => None,
+    };
+
+    Ok(VariantGetPlan {
+        options,
+        has_wildcard,
+        typed_default,
+    })
+}
+
+/// Like [`variant_get`], but also counts how the path resolved in the rows of `input`.
+///
+/// This tells, for example, whether a path is present in every row, and so whether it is worth
+/// shredding. The counts describe the extraction itself, i.e. rows filled in by
+/// [`GetOptions::default_value`] are still counted as NULL, missing or mismatched.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{ArrayRef, StringArray};
+/// # use arrow::datatypes::DataType;
+/// # use parquet_variant::VariantPath;
+/// # use parquet_variant_compute::{GetOptions, json_to_variant, variant_get_with_stats};
+/// # use std::sync::Arc;
+/// let json: ArrayRef = Arc::new(StringArray::from(vec![r#"{"a": 1}"#, r#"{"a": "x"}"#, "{}"]));
+/// let input = ArrayRef::from(json_to_variant(&json).unwrap());
+/// let options = GetOptions::new_with_path(VariantPath::try_from("a").unwrap())
+///     .with_as_data_type(DataType::Int64);
+/// let (_, stats) = variant_get_with_stats(&input, options).unwrap();
+/// assert_eq!(stats.resolved_count, 1);
+/// assert_eq!(stats.type_mismatch_count, 1);
+/// assert_eq!(stats.missing_count, 1);
+/// ```
+pub fn variant_get_with_stats(
+    input: &ArrayRef,
+    options: GetOptions,
+) -> Result<(ArrayRef, ResolutionStats)> {
+    compile_variant_get(options)?.execute_with_stats(input)
+}
+
+/// How the path of a [`variant_get`] resolved in the rows of its input, see
+/// [`variant_get_with_stats`]. Every row is counted exactly once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResolutionStats {
+    /// The number of rows where the path resolved to a value that was extracted
+    pub resolved_count: usize,
+    /// The number of null rows, and of rows where the path resolved to [`Variant::Null`]
+    pub null_count: usize,
+    /// The number of rows where the path does not exist, as defined by [`MissingBehavior`]
+    pub missing_count: usize,
+    /// The number of rows where the path resolved to a value that could not be converted to
+    /// `as_type`, or stepped into a value of the wrong kind (e.g. a field of a list)
+    pub type_mismatch_count: usize,
+}
+
+/// A [`variant_get`] extraction compiled by [`compile_variant_get`], which can be executed on
+/// many batches.
+#[derive(Debug, Clone)]
+pub struct VariantGetPlan<'a> {
+    options: GetOptions<'a>,
+    /// Whether the path contains a [`VariantPathElement::Wildcard`]
+    has_wildcard: bool,
+    /// The default value converted to `as_type`, if both are set
+    typed_default: Option<Scalar<ArrayRef>>,
+}
+
+impl<'a> VariantGetPlan<'a> {
+    /// The options this plan was compiled from
+    pub fn options(&self) -> &GetOptions<'a> {
+        &self.options
+    }
+
+    /// Extracts the compiled options from `input`, exactly like [`variant_get`].
+    pub fn execute(&self, input: &ArrayRef) -> Result<ArrayRef> {
+        let variant_array = self.prepare(input)?;
+        self.finish(self.extract(&variant_array)?)
+    }
+
+    /// Like [`Self::execute`], but also reports how the path resolved in each row, see
+    /// [`variant_get_with_stats`].
+    pub fn execute_with_stats(&self, input: &ArrayRef) -> Result<(ArrayRef, ResolutionStats)> {
+        let variant_array = self.prepare(input)?;
+        let result = self.extract(&variant_array)?;
+        let stats = self.resolution_stats(&variant_array, &result)?;
+        Ok((self.finish(result)?, stats))
+    }
+
+    /// Converts `input` to a `VariantArray`, parsing the JSON strings on the path if requested
+    fn prepare(&self, input: &ArrayRef) -> Result<VariantArray> {
+        let variant_array = VariantArray::try_new(input)?;
+        match self.options.parse_json_strings && !self.options.path.is_empty() {
+            true => parse_json_strings_on_path(&variant_array, &self.options.path),
+            false => Ok(variant_array),
+        }
+    }
+
+    /// Extracts the compiled options from `variant_array`, before applying the default and the
+    /// nullability of `as_type`
+    fn extract(&self, variant_array: &VariantArray) -> Result<ArrayRef> {
+        let GetOptions {
+            as_type,
+            path,
+            cast_options,
+            strategy,
+            missing_as,
+            default_value: _,
+            parse_json_strings: _,
+            output_name: _,
+        } = &self.options;
+
+        if *missing_as == MissingBehavior::Error {
+            check_path_exists(variant_array, path)?;
+        }
+
+        // An empty batch has nothing to extract, but must still produce the requested type
+        if let Some(field) = as_type.as_ref().filter(|_| variant_array.is_empty()) {
+            let data_type = match self.has_wildcard {
+                true => path
+                    .iter()
+                    .filter(|element| matches!(element, VariantPathElement::Wildcard))
+                    .fold(field.data_type().clone(), |data_type, _| {
+                        DataType::new_list(data_type, true)
+                    }),
+                false => field.data_type().clone(),
+            };
+            return Ok(array::new_empty_array(&data_type));
+        }
+
+        get_path(
+            variant_array,
+            ErrorContext::default(),
+            path,
+            as_type.as_deref(),
+            cast_options,
+            *strategy,
+        )
+    }
+
+    /// Classifies each row of `variant_array` by how the path resolved, given the `result` of
+    /// [`Self::extract`]
+    fn resolution_stats(
+        &self,
+        variant_array: &VariantArray,
+        result: &ArrayRef,
+    ) -> Result<ResolutionStats> {
+        let path = &self.options.path;
+        // Wildcard paths produce a list for each row where the path up to the wildcard is a list
+        let prefix: VariantPath = path
+            .iter()
+            .take_while(|element| !matches!(element, VariantPathElement::Wildcard))
+            .cloned()
+            .collect();
+        let input = unshred_variant(variant_array)?;
+        let result_nulls = result.logical_nulls();
+
+        let mut stats = ResolutionStats::default();
+        for row in 0..input.len() {
+            if input.is_null(row) {
+                stats.null_count += 1;
+                continue;
+            }
+            let value = input.try_value(row)?;
+            if find_missing_element(&value, path).is_some() {
+                stats.missing_count += 1;
+            } else if value.get_path(&prefix) == Some(Variant::Null) {
+                stats.null_count += 1;
+            } else if result_nulls
+                .as_ref()
+                .is_none_or(|nulls| nulls.is_valid(row))
+            {
+                stats.resolved_count += 1;
+            } else {
+                stats.type_mismatch_count += 1;
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Applies the default and the nullability of `as_type` to the result of a get
+    fn finish(&self, result: ArrayRef) -> Result<ArrayRef> {
+        let result = match &self.options.default_value {
+            Some(default) if result.logical_null_count() > 0 => self.fill_nulls(result, default)?,
+            _ => result,
+        };
+        match &self.options.as_type {
+            Some(field) if !field.is_nullable() => into_non_nullable(result, field),
+            _ => Ok(result),
+        }
+    }
+
+    /// Replaces every NULL in `result` with `default`
+    fn fill_nulls(&self, result: ArrayRef, default: &Variant<'_, '_>) -> Result<ArrayRef> {
+        let Some(typed_default) = &self.typed_default else {
+            let result = unshred_variant(&VariantArray::try_new(&result)?)?;
+            let mut builder = VariantArrayBuilder::new(result.len());
+            for value in result.iter() {
+                builder.append_variant_ref(value.as_ref().unwrap_or(default));
+            }
+            return Ok(ArrayRef::from(builder.build()));
+        };
+
+        let mask = arrow::compute::is_not_null(&result)?;
+        arrow::compute::kernels::zip::zip(&mask, &result, typed_default)
+    }
+}
+
+/// Like [`variant_get`], but extracts several paths from the same input, returning one array per
+/// request in the same order.
+///
+/// The results are the same as calling [`variant_get`] for each request, but requests that
+/// decode the input row by row share a single pass over it, so that each row is only decoded
+/// once. This is the case for unshredded input, unless the request has an empty path, a
+/// [`VariantPathElement::Wildcard`], or [`GetStrategy::Columnar`]. Other requests are extracted
+/// individually.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{Array, ArrayRef, AsArray, StringArray};
+/// # use arrow::datatypes::{DataType, Field, Int64Type};
+/// # use parquet_variant::VariantPath;
+/// # use parquet_variant_compute::{GetOptions, json_to_variant, variant_get_many};
+/// # use std::sync::Arc;
+/// let json: ArrayRef = Arc::new(StringArray::from(vec![r#"{"a": 1, "b": "x"}"#, r#"{"a": 2}"#]));
+/// let input = ArrayRef::from(json_to_variant(&json).unwrap());
+/// let get = |path, data_type| {
+///     GetOptions::new_with_path(VariantPath::try_from(path).unwrap())
+///         .with_as_type(Some(Arc::new(Field::new(path, data_type, true))))
+/// };
+/// let results =
+///     variant_get_many(&input, &[get("a", DataType::Int64), get("b", DataType::Utf8)]).unwrap();
+/// assert_eq!(results[0].as_primitive::<Int64Type>().values(), &[1, 2]);
+/// assert_eq!(results[1].as_string::<i32>().value(0), "x");
+/// assert!(results[1].is_null(1));
+/// ```
+pub fn variant_get_many(input: &ArrayRef, requests: &[GetOptions]) -> Result<Vec<ArrayRef>> {
     let variant_array = VariantArray::try_new(input)?;
+    let plans = requests
+        .iter()
+        .cloned()
+        .map(compile_variant_get)
+        .collect::<Result<Vec<_>>>()?;
+    let shared_pass = |plan: &VariantGetPlan| {
+        variant_array.typed_value_field().is_none()
+            && !plan.options.path.is_empty()
+            && plan.options.strategy != GetStrategy::Columnar
+            && !plan.has_wildcard
+            && !plan.options.parse_json_strings
+    };
+
+    // One row builder for each request that takes part in the shared pass
+    let mut builders = plans
+        .iter()
+        .map(|plan| {
+            if !shared_pass(plan) {
+                return Ok(None);
+            }
+            let options = &plan.options;
+            if options.missing_as == MissingBehavior::Error {
+                check_path_exists(&variant_array, &options.path)?;
+            }
+            let builder = make_variant_to_arrow_row_builder(
+                variant_array.metadata_field(),
+                options.path.clone(),
+                options.as_type.as_ref().map(|field| field.data_type()),
+                &options.cast_options,
+                variant_array.len(),
+            )?;
+            Ok(Some(builder))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let context = ErrorContext::default();
+    // Skip the pass entirely if no request takes part in it
+    let num_rows = if builders.iter().any(Option::is_some) {
+        variant_array.len()
+    } else {
+        0
+    };
+    for i in 0..num_rows {
+        if variant_array.is_null(i) {
+            for builder in builders.iter_mut().flatten() {
+                builder.append_null()?;
+            }
+            continue;
+        }
+        let value = variant_array.try_value(i)?;
+        for (builder, options) in builders.iter_mut().zip(requests) {
+            if let Some(builder) = builder {
+                builder
+                    .append_value(value.clone())
+                    .map_err(|e| context.wrap(e, i, &options.path))?;
+            }
+        }
+    }
+
+    builders
+        .into_iter()
+        .zip(&plans)
+        .map(|(builder, plan)| match builder {
+            Some(builder) => plan.finish(builder.finish()?),
+            None => plan.execute(input),
+        })
+        .collect()
+}
+
+/// Returns an error for the first valid row of `input` where `path` does not exist, i.e. steps
+/// into a field missing from an object or past the end of a list.
+///
+/// Stepping into [`Variant::Null`] or a value of the wrong kind is not a missing path: those are
+/// governed by the cast options instead.
+fn check_path_exists(input: &VariantArray, path: &[VariantPathElement<'_>]) -> Result<()> {
+    if path.is_empty() {
+        return Ok(());
+    }
+    let input = unshred_variant(input)?;
+    for row in (0..input.len()).filter(|&row| input.is_valid(row)) {
+        if let Some(missing) = find_missing_element(&input.try_value(row)?, path) {
+            let path = VariantPath::from_iter(path.iter().cloned());
+            return Err(ArrowError::CastError(format!(
+                "row {row}: {} does not exist at path {path}",
+                path[missing]
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the index of the first element of `path` that does not exist in `value`, if any.
+fn find_missing_element(value: &Variant<'_, '_>, path: &[VariantPathElement<'_>]) -> Option<usize> {
+    let (element, rest) = path.split_first()?;
+    let next = match (element, value) {
+        (VariantPathElement::Field { name }, Variant::Object(object)) => object.get(name),
+        (VariantPathElement::FieldId { id }, Variant::Object(object)) => {
+            object.get_by_field_id(*id)
+        }
+        (VariantPathElement::Index { index }, Variant::List(list)) => list.get(*index),
+        (VariantPathElement::IndexFromEnd { offset }, Variant::List(list)) => {
+            list.len().checked_sub(*offset).and_then(|i| list.get(i))
+        }
+        (VariantPathElement::Wildcard, Variant::List(list)) => {
+            let missing = list
+                .iter()
+                .find_map(|element| find_missing_element(&element, rest));
+            return missing.map(|i| i + 1);
+        }
+        // Null values and values of the wrong kind are not missing
+        _ => return None,
+    };
+    match next {
+        Some(next) => find_missing_element(&next, rest).map(|i| i + 1),
+        None => Some(0),
+    }
+}
+
+/// Like [`variant_get`], but extracts from a single [`Variant`] rather than an array of them.
+///
+/// The result is a single-element array wrapped in a [`Scalar`], following the same rules as
+/// [`variant_get`]: a `VariantArray` if `options.as_type` is `None`, and an array of the requested
+/// type otherwise. The element is null if the path does not resolve, or (in safe mode) if the value
+/// cannot be converted. To just follow a path without converting, see [`Variant::get_path`].
+///
+/// # Example
+/// ```
+/// # use arrow::array::AsArray;
+/// # use arrow::datatypes::{DataType, Field, Int32Type};
+/// # use parquet_variant::{Variant, VariantBuilder, VariantPath};
+/// # use parquet_variant_compute::{GetOptions, variant_get_scalar};
+/// # use std::sync::Arc;
+/// let mut builder = VariantBuilder::new();
+/// let mut object = builder.new_object();
+/// object.new_object("a").with_field("b", 42i64).finish();
+/// object.finish();
+/// let (metadata, value) = builder.finish();
+/// let variant = Variant::new(&metadata, &value);
+///
+/// let field = Field::new("b", DataType::Int32, true);
+/// let options = GetOptions::new_with_path(VariantPath::try_from("a.b").unwrap())
+///     .with_as_type(Some(Arc::new(field)));
+/// let array = variant_get_scalar(&variant, options).unwrap().into_inner();
+/// assert_eq!(array.as_primitive::<Int32Type>().value(0), 42);
+/// ```
+pub fn variant_get_scalar(variant: &Variant, options: GetOptions) -> Result<Scalar<ArrayRef>> {
+    let input = ArrayRef::from(VariantArray::from_iter([variant.clone()]));
+    variant_get(&input, options).map(Scalar::new)
+}
+
+/// Casts every row of `array` to `to_type`, the reverse of [`cast_to_variant`].
+///
+/// This is [`variant_get`] with an empty path, so values are converted with the same rules:
+/// values that cannot be converted to `to_type` become NULL when `options.safe` is true and
+/// produce an error otherwise.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{Array, AsArray};
+/// # use arrow::compute::CastOptions;
+/// # use arrow::datatypes::{DataType, Int64Type};
+/// # use parquet_variant_compute::{VariantArray, cast_variant};
+/// let array = VariantArray::from(vec![Some(1i8), None, Some(3)]);
+/// let result = cast_variant(&array, &DataType::Int64, &CastOptions::default()).unwrap();
+/// let result = result.as_primitive::<Int64Type>();
+/// assert_eq!(result.value(2), 3);
+/// assert!(result.is_null(1));
+/// ```
+///
+/// [`cast_to_variant`]: crate::cast_to_variant
+pub fn cast_variant(
+    array: &VariantArray,
+    to_type: &DataType,
+    options: &CastOptions,
+) -> Result<ArrayRef> {
+    let options = GetOptions::new()
+        .with_as_data_type(to_type.clone())
+        .with_cast_options(options.clone());
+    variant_get(&ArrayRef::from(array.clone()), options)
+}
+
+/// Extracts `path` from `input`, expanding any [`VariantPathElement::Wildcard`] into lists.
+///
+/// `context` locates `input` within the original array, and is only used for error messages.
+fn get_path<'p>(
+    input: &VariantArray,
+    context: ErrorContext<'_, 'p>,
+    path: &[VariantPathElement<'p>],
+    as_field: Option<&Field>,
+    cast_options: &CastOptions,
+    strategy: GetStrategy,
+) -> Result<ArrayRef> {
+    match path
+        .iter()
+        .position(|element| matches!(element, VariantPathElement::Wildcard))
+    {
+        Some(pos) => wildcard_get_path(
+            input,
+            context,
+            &path[..pos],
+            &path[pos + 1..],
+            as_field,
+            cast_options,
+            strategy,
+        ),
+        None => shredded_get_path(input, context, path, as_field, cast_options, strategy),
+    }
+}
+
+/// Checks that `array` has no NULLs, as required by the non-nullable `field`, and removes its
+/// (all-valid) null buffer if it has one.
+fn into_non_nullable(array: ArrayRef, field: &Field) -> Result<ArrayRef> {
+    match array.logical_null_count() {
+        0 if array.nulls().is_none() => Ok(array),
+        0 => {
+            let data = array.into_data().into_builder().nulls(None).build()?;
+            Ok(array::make_array(data))
+        }
+        null_count => Err(ArrowError::CastError(format!(
+            "Cannot extract variant into non-nullable field '{}': {null_count} of {} rows are NULL",
+            field.name(),
+            array.len(),
+        ))),
+    }
+}
+
+/// Rewrites the rows of `input` where `path` steps into strings that hold JSON (see
+/// [`GetOptions::parse_json_strings`]), replacing those strings with their parsed values. Other
+/// rows are copied as is.
+fn parse_json_strings_on_path(
+    input: &VariantArray,
+    path: &[VariantPathElement<'_>],
+) -> Result<VariantArray> {
+    let input = unshred_variant(input)?;
+    let mut builder = VariantArrayBuilder::new(input.len());
+    for row in 0..input.len() {
+        if input.is_null(row) {
+            builder.append_null();
+            continue;
+        }
+        let value = input.try_value(row)?;
+        match path_steps_into_json(&value, path) {
+            true => append_parsing_json_strings(&mut builder, value, path)?,
+            false => builder.append_variant_ref(&value),
+        }
+    }
+    Ok(builder.build())
+}
+
+/// Whether `value` is a string that appears to hold a JSON object or list
+fn looks_like_json(value: &Variant<'_, '_>) -> bool {
+    value
+        .as_string()
+        .is_some_and(|text| text.trim_start().starts_with(['{', '[']))
+}
+
+/// Whether following `path` into `value` steps into a string that appears to hold JSON
+fn path_steps_into_json(value: &Variant<'_, '_>, path: &[VariantPathElement<'_>]) -> bool {
+    let Some((element, rest)) = path.split_first() else {
+        return false;
+    };
+    if looks_like_json(value) {
+        return true;
+    }
+    let next = match (element, value) {
+        (VariantPathElement::Field { name }, Variant::Object(object)) => object.get(name),
+        (VariantPathElement::Index { index }, Variant::List(list)) => list.get(*index),
+        (VariantPathElement::IndexFromEnd { offset }, Variant::List(list)) => {
+            list.len().checked_sub(*offset).and_then(|i| list.get(i))
+        }
+        (VariantPathElement::Wildcard, Variant::List(list)) => {
+            return list
+                .iter()
+                .any(|element| path_steps_into_json(&element, rest));
+        }
+        _ => None,
+    };
+    next.is_some_and(|next| path_steps_into_json(&next, rest))
+}
+
+/// Appends `value` to `builder`, replacing each string holding JSON that `path` steps into with
+/// its parsed value. Strings that do not parse are kept as they are.
+fn append_parsing_json_strings(
+    builder: &mut impl VariantBuilderExt,
+    value: Variant<'_, '_>,
+    path: &[VariantPathElement<'_>],
+) -> Result<()> {
+    let Some((element, rest)) = path.split_first() else {
+        builder.append_value(value);
+        return Ok(());
+    };
+    if let Some(json) = value
+        .as_string()
+        .filter(|_| looks_like_json(&value))
+        .and_then(|text| serde_json::from_str::<serde_json::Value>(text).ok())
+    {
+        let mut parsed = VariantBuilder::new();
+        append_json(&json, &mut parsed)?;
+        let (metadata, value) = parsed.finish();
+        return append_parsing_json_strings(builder, Variant::try_new(&metadata, &value)?, path);
+    }
+
+    match (element, &value) {
+        (VariantPathElement::Field { name }, Variant::Object(object)) => {
+            let mut object_builder = builder.try_new_object()?;
+            for (key, field) in object.iter() {
+                let mut field_builder = ObjectFieldBuilder::new(key, &mut object_builder);
+                match key == name {
+                    true => append_parsing_json_strings(&mut field_builder, field, rest)?,
+                    false => field_builder.append_value(field),
+                }
+            }
+            object_builder.finish();
+        }
+        (
+            VariantPathElement::Index { .. }
+            | VariantPathElement::IndexFromEnd { .. }
+            | VariantPathElement::Wildcard,
+            Variant::List(list),
+        ) => {
+            let on_path = match element {
+                VariantPathElement::Index { index } => Some(*index),
+                VariantPathElement::IndexFromEnd { offset } => list.len().checked_sub(*offset),
+                _ => None,
+            };
+            let mut list_builder = builder.try_new_list()?;
+            for (i, item) in list.iter().enumerate() {
+                match matches!(element, VariantPathElement::Wildcard) || on_path == Some(i) {
+                    true => append_parsing_json_strings(&mut list_builder, item, rest)?,
+                    false => list_builder.append_value(item),
+                }
+            }
+            list_builder.finish();
+        }
+        _ => builder.append_value(value),
+    }
+    Ok(())
+}
+
+/// Extracts a path containing a [`VariantPathElement::Wildcard`], gathering every element of the
+/// list found at `prefix` into a `ListArray`. The remaining `suffix` (which may contain further
+/// wildcards) is applied to each list element, and the results are extracted as `as_type`.
+///
+/// Rows where `prefix` does not resolve to a list produce a NULL list entry.
+fn wildcard_get_path<'p>(
+    input: &VariantArray,
+    context: ErrorContext<'_, 'p>,
+    prefix: &[VariantPathElement<'p>],
+    suffix: &[VariantPathElement<'p>],
+    as_field: Option<&Field>,
+    cast_options: &CastOptions,
+    strategy: GetStrategy,
+) -> Result<ArrayRef> {
+    let lists = shredded_get_path(input, context, prefix, None, cast_options, strategy)?;
+
+    let mut offsets = OffsetBufferBuilder::<i32>::new(input.len());
+    let mut nulls = NullBufferBuilder::new(input.len());
+    let mut elements = VariantArrayBuilder::new(input.len());
+    // Only strict mode reports errors, so only it needs to know where each element came from
+    let mut element_rows = Vec::new();
+
+    let mut lists = VariantArray::try_new(&lists)?;
+    // Row-by-row access cannot read nested `typed_value` columns (e.g. shredded lists)
+    if lists
+        .typed_value_field()
+        .is_some_and(|typed_value| typed_value.data_type().is_nested())
+    {
+        lists = unshred_variant(&lists)?;
+    }
+    for i in 0..lists.len() {
+        let value = match lists.is_valid(i).then(|| lists.try_value(i)).transpose() {
+            Ok(value) => value,
+            Err(_) if cast_options.safe => None,
+            Err(e) => return Err(e),
+        };
+        match value {
+            Some(Variant::List(list)) => {
+                offsets.push_length(list.len());
+                list.iter()
+                    .for_each(|element| elements.append_variant(element));
+                if !cast_options.safe {
+                    let row = context.input_row(i);
+                    element_rows.extend(std::iter::repeat_n(row, list.len()));
+                }
+                nulls.append_non_null();
+            }
+            _ => {
+                offsets.push_length(0);
+                nulls.append_null();
+            }
+        }
+    }
 
-    let GetOptions {
-        as_type,
-        path,
+    let element_path: Vec<_> = context
+        .path
+        .iter()
+        .chain(prefix)
+        .cloned()
+        .chain([VariantPathElement::Wildcard])
+        .collect();
+    let element_context = ErrorContext {
+        path: &element_path,
+        rows: (!cast_options.safe).then_some(element_rows.as_slice()),
+    };
+    let values = get_path(
+        &elements.build(),
+        element_context,
+        suffix,
+        as_field,
         cast_options,
-    } = options;
+        strategy,
+    )?;
+
+    let mut item_field = Field::new_list_field(values.data_type().clone(), true);
+    if as_field.is_none() && matches!(values.data_type(), DataType::Struct(_)) {
+        item_field = item_field.with_extension_type(VariantType);
+    }
 
-    shredded_get_path(&variant_array, &path, as_type.as_deref(), &cast_options)
+    Ok(Arc::new(ListArray::try_new(
+        Arc::new(item_field),
+        offsets.finish(),
+        values,
+        nulls.finish(),
+    )?))
 }
 
 /// Controls the action of the variant_get kernel.
+///
+/// Construct with [`GetOptions::new`] or [`GetOptions::new_with_path`] and the `with_*` methods.
 #[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct GetOptions<'a> {
     /// What path to extract
     pub path: VariantPath<'a>,
@@ -308,6 +1319,52 @@ pub struct GetOptions<'a> {
     pub as_type: Option<FieldRef>,
     /// Controls the casting behavior (e.g. error vs substituting null on cast error).
     pub cast_options: CastOptions<'a>,
+    /// How to decode values that are not shredded as `as_type`. All strategies produce the same
+    /// result.
+    pub strategy: GetStrategy,
+    /// What to do with rows where the path does not exist.
+    pub missing_as: MissingBehavior,
+    /// If set, substituted for every NULL in the result, like a SQL `COALESCE`: for null rows,
+    /// missing paths and (in safe mode) values that cannot be converted to `as_type`. It must be
+    /// convertible to `as_type`, and cannot be used with [`VariantPathElement::Wildcard`] paths.
+    pub default_value: Option<Variant<'a, 'a>>,
+    /// If true, strings that hold a JSON object or list are parsed when the path steps into them,
+    /// and the rest of the path is followed within the parsed value. Otherwise strings are opaque
+    /// values like any other. Cannot be used with [`VariantPathElement::FieldId`] paths, whose
+    /// ids refer to the original metadata.
+    pub parse_json_strings: bool,
+    /// The name of the extracted column, see [`Self::output_field`]. If `None`, the name is
+    /// derived from the path.
+    pub output_name: Option<String>,
+}
+
+/// What [`variant_get`] does with rows where the path does not exist, i.e. steps into a field that
+/// is missing from an object, or past the end of a list.
+///
+/// Null rows are not missing, and neither are paths that step into [`Variant::Null`] or into a
+/// value of the wrong kind (e.g. a field of a list): the latter follow [`GetOptions::cast_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingBehavior {
+    /// Produce NULL, the same as for a path that resolves to [`Variant::Null`]
+    #[default]
+    Null,
+    /// Return an error
+    Error,
+}
+
+/// How [`variant_get`] decodes values that are not shredded as the requested type.
+///
+/// Perfectly shredded values are always returned directly, whatever the strategy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GetStrategy {
+    /// Decodes primitive `as_type`s columnar once the path is exhausted, and rowwise otherwise
+    #[default]
+    Auto,
+    /// Converts each row to a [`Variant`] and appends it to a builder for `as_type`
+    Rowwise,
+    /// Decodes primitive `as_type`s directly from the `value` column, also following any part of
+    /// the path that is not shredded within each row. Other types are decoded rowwise.
+    Columnar,
 }
 
 impl<'a> GetOptions<'a> {
@@ -320,8 +1377,7 @@ impl<'a> GetOptions<'a> {
     pub fn new_with_path(path: VariantPath<'a>) -> Self {
         Self {
             path,
-            as_type: None,
-            cast_options: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -331,30 +1387,112 @@ impl<'a> GetOptions<'a> {
         self
     }
 
+    /// Specify the type to return as a bare [`DataType`], i.e. a nullable field without a name
+    /// or metadata. Use [`Self::with_as_type`] if those matter.
+    pub fn with_as_data_type(self, data_type: DataType) -> Self {
+        self.with_as_type(Some(Arc::new(Field::new("", data_type, true))))
+    }
+
     /// Specify the cast options to use when casting to the specified type.
     pub fn with_cast_options(mut self, cast_options: CastOptions<'a>) -> Self {
         self.cast_options = cast_options;
         self
     }
-}
 
-#[cfg(test)]
-mod test {
-    use std::str::FromStr;
-    use std::sync::Arc;
+    /// Specify the strategy to decode values with.
+    pub fn with_strategy(mut self, strategy: GetStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
 
-    use super::{GetOptions, variant_get};
-    use crate::variant_array::{ShreddedVariantFieldArray, StructArrayBuilder};
-    use crate::{
-        VariantArray, VariantArrayBuilder, cast_to_variant, json_to_variant, shred_variant,
-    };
-    use arrow::array::{
-        Array, ArrayRef, AsArray, BinaryArray, BinaryViewArray, BooleanArray, Date32Array,
-        Date64Array, Decimal32Array, Decimal64Array, Decimal128Array, Decimal256Array,
-        Float32Array, Float64Array, Int8Array, Int16Array, Int32Array, Int64Array,
-        LargeBinaryArray, LargeListArray, LargeListViewArray, LargeStringArray, ListArray,
-        ListViewArray, NullBuilder, StringArray, StringViewArray, StructArray,
-        Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray,
+    /// Specify what to do with rows where the path does not exist.
+    pub fn with_missing_as(mut self, missing_as: MissingBehavior) -> Self {
+        self.missing_as = missing_as;
+        self
+    }
+
+    /// Specify a value to substitute for NULLs in the result.
+    pub fn with_default_value(mut self, default_value: Option<Variant<'a, 'a>>) -> Self {
+        self.default_value = default_value;
+        self
+    }
+
+    /// Specify whether to follow the path into strings that hold JSON.
+    pub fn with_parse_json_strings(mut self, parse_json_strings: bool) -> Self {
+        self.parse_json_strings = parse_json_strings;
+        self
+    }
+
+    /// Specify the name of the extracted column.
+    pub fn with_output_name(mut self, output_name: Option<String>) -> Self {
+        self.output_name = output_name;
+        self
+    }
+
+    /// The name of the extracted column: [`Self::output_name`] if set, otherwise the name of the
+    /// last [`VariantPathElement::Field`] in the path (e.g. `email` for `$.user.email`, and `tags`
+    /// for `$.tags[0]`), or the empty string if the path has no named fields.
+    pub fn output_name(&self) -> String {
+        if let Some(name) = &self.output_name {
+            return name.clone();
+        }
+        self.path
+            .iter()
+            .rev()
+            .find_map(|element| match element {
+                VariantPathElement::Field { name } => Some(name.to_string()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns a field named [`Self::output_name`] that describes `result`, the array returned by
+    /// [`variant_get`] for these options, e.g. to assemble a [`RecordBatch`] from several
+    /// extractions.
+    ///
+    /// Variant results are marked with the [`VariantType`] extension type, and typed results keep
+    /// the metadata of [`Self::as_type`].
+    ///
+    /// [`RecordBatch`]: arrow::array::RecordBatch
+    pub fn output_field(&self, result: &dyn Array) -> Field {
+        let field = Field::new(self.output_name(), result.data_type().clone(), true);
+        match &self.as_type {
+            None if matches!(result.data_type(), DataType::Struct(_)) => {
+                field.with_extension_type(VariantType)
+            }
+            Some(as_type) if as_type.data_type() == result.data_type() => {
+                field.with_metadata(as_type.metadata().clone())
+            }
+            _ => field,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use super::{
+        ErrorContext, GetOptions, GetStrategy, MissingBehavior, ResolutionStats, cast_variant,
+        columnar_get_primitive, compile_variant_get, variant_get, variant_get_many,
+        variant_get_scalar, variant_get_with_stats,
+    };
+    use crate::variant_array::{ShreddedVariantFieldArray, StructArrayBuilder};
+    use crate::variant_to_arrow::make_primitive_variant_to_arrow_row_builder;
+    use crate::{
+        VariantArray, VariantArrayBuilder, VariantType, cast_to_variant, json_to_variant,
+        shred_variant, unshred_variant,
+    };
+    use arrow::array::{
+        Array, ArrayRef, AsArray, BinaryArray, BinaryViewArray, BooleanArray, Date32Array,
+        Date64Array, Decimal32Array, Decimal64Array, Decimal128Array, Decimal256Array,
+        Float16Array, Float32Array, Float64Array, Int8Array, Int16Array, Int32Array, Int64Array,
+        LargeBinaryArray, LargeListArray, LargeListViewArray, LargeStringArray, ListArray,
+        ListViewArray, NullBuilder, RecordBatch, StringArray, StringViewArray, StructArray,
+        Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray,
+        UInt8Array, UInt64Array,
     };
     use arrow::buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
     use arrow::compute::CastOptions;
@@ -365,9 +1503,30 @@ mod test {
     use arrow_schema::{DataType, Field, FieldRef, Fields, IntervalUnit, TimeUnit};
     use chrono::DateTime;
     use parquet_variant::{
-        EMPTY_VARIANT_METADATA_BYTES, Variant, VariantDecimal4, VariantDecimal8, VariantDecimal16,
-        VariantDecimalType, VariantPath,
+        EMPTY_VARIANT_METADATA_BYTES, Variant, VariantBuilderExt, VariantDecimal4, VariantDecimal8,
+        VariantDecimal16, VariantDecimalType, VariantPath, VariantPathElement,
     };
+    use parquet_variant_json::JsonToVariant;
+
+    /// Options that extract `path` as `as_type`, or as variant if `None`
+    fn get_options(path: &str, as_type: Option<DataType>, safe: bool) -> GetOptions<'_> {
+        GetOptions::new_with_path(VariantPath::try_from(path).unwrap())
+            .with_as_type(as_type.map(|t| FieldRef::from(Field::new("result", t, true))))
+            .with_cast_options(CastOptions {
+                safe,
+                ..Default::default()
+            })
+    }
+
+    /// Extracts `path` from every row of `input` with [`get_options`]
+    fn get(
+        input: &ArrayRef,
+        path: &str,
+        as_type: Option<DataType>,
+        safe: bool,
+    ) -> arrow::error::Result<ArrayRef> {
+        variant_get(input, get_options(path, as_type, safe))
+    }
 
     fn single_variant_get_test(input_json: &str, path: VariantPath, expected_json: &str) {
         // Create input array from JSON string
@@ -443,428 +1602,981 @@ mod test {
     }
 
     #[test]
-    fn get_complex_variant() {
-        single_variant_get_test(
-            r#"{"top_level_field": {"inner_field": 1234}}"#,
-            VariantPath::try_from("top_level_field").unwrap(),
-            r#"{"inner_field": 1234}"#,
-        );
-    }
-
-    /// Partial Shredding: extract a value as a VariantArray
-    macro_rules! numeric_partially_shredded_test {
-        ($primitive_type:ty, $data_fn:ident) => {
-            let array = $data_fn();
-            let options = GetOptions::new();
-            let result = variant_get(&array, options).unwrap();
-
-            // expect the result is a VariantArray
-            let result = VariantArray::try_new(&result).unwrap();
-            assert_eq!(result.len(), 4);
-
-            // Expect the values are the same as the original values
-            assert_eq!(
-                result.value(0),
-                Variant::from(<$primitive_type>::try_from(34u8).unwrap())
-            );
-            assert!(!result.is_valid(1));
-            assert_eq!(result.value(2), Variant::from("n/a"));
-            assert_eq!(
-                result.value(3),
-                Variant::from(<$primitive_type>::try_from(100u8).unwrap())
-            );
+    fn get_scalar_from_nested_variant() {
+        let mut builder = parquet_variant::VariantBuilder::new();
+        let mut object = builder.new_object();
+        object
+            .new_object("a")
+            .with_field("b", 42i64)
+            .with_field("c", "text")
+            .finish();
+        object.finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::new(&metadata, &value);
+
+        let get_scalar = |path, as_type| {
+            let options = get_options(path, as_type, true);
+            let array = variant_get_scalar(&variant, options).unwrap().into_inner();
+            assert_eq!(array.len(), 1);
+            array
         };
-    }
 
-    /// Build a mixed input [typed, null, fallback, typed] and let shred_variant
-    /// generate the shredded fixture for the requested type.
-    macro_rules! partially_shredded_variant_array_gen {
-        ($func_name:ident,  $typed_value_array_gen: expr) => {
-            partially_shredded_variant_array_gen!(
-                $func_name,
-                $typed_value_array_gen,
-                Variant::from("n/a")
-            );
-        };
-        ($func_name:ident,  $typed_value_array_gen: expr, $fallback_variant:expr) => {
-            fn $func_name() -> ArrayRef {
-                let typed_value: ArrayRef = Arc::new($typed_value_array_gen());
-                let typed_as_variant = cast_to_variant(typed_value.as_ref())
-                    .expect("should cast typed array to variant");
-                let mut input_builder = VariantArrayBuilder::new(typed_as_variant.len());
-                input_builder.append_variant(typed_as_variant.value(0));
-                input_builder.append_null();
-                input_builder.append_variant($fallback_variant);
-                input_builder.append_variant(typed_as_variant.value(3));
+        let result = get_scalar("a.b", Some(Int64));
+        assert_eq!(
+            result
+                .as_primitive::<arrow::datatypes::Int64Type>()
+                .value(0),
+            42
+        );
 
-                let variant_array = shred_variant(&input_builder.build(), typed_value.data_type())
-                    .expect("should shred variant array");
-                ArrayRef::from(variant_array)
-            }
-        };
-    }
+        // Without a type, the result is a single-element `VariantArray`
+        let result = VariantArray::try_new(&get_scalar("a", None)).unwrap();
+        let a = result.value(0);
+        assert_eq!(a.get_object_field("c"), Some(Variant::from("text")));
 
-    // Fixture definitions grouped with the partially-shredded tests.
-    macro_rules! numeric_partially_shredded_variant_array_fn {
-        ($func:ident, $array_type:ident, $primitive_type:ty) => {
-            partially_shredded_variant_array_gen!($func, || $array_type::from(vec![
-                Some(<$primitive_type>::try_from(34u8).unwrap()),
-                None,
-                None,
-                Some(<$primitive_type>::try_from(100u8).unwrap()),
-            ]));
-        };
+        // Missing paths and failed conversions are null
+        assert!(get_scalar("a.missing", Some(Int64)).is_null(0));
+        assert!(get_scalar("a.c", Some(Int64)).is_null(0));
     }
 
-    numeric_partially_shredded_variant_array_fn!(
-        partially_shredded_int8_variant_array,
-        Int8Array,
-        i8
-    );
-    numeric_partially_shredded_variant_array_fn!(
-        partially_shredded_int16_variant_array,
-        Int16Array,
-        i16
-    );
-    numeric_partially_shredded_variant_array_fn!(
-        partially_shredded_int32_variant_array,
-        Int32Array,
-        i32
-    );
-    numeric_partially_shredded_variant_array_fn!(
-        partially_shredded_int64_variant_array,
-        Int64Array,
-        i64
-    );
-    numeric_partially_shredded_variant_array_fn!(
-        partially_shredded_float32_variant_array,
-        Float32Array,
-        f32
-    );
-    numeric_partially_shredded_variant_array_fn!(
-        partially_shredded_float64_variant_array,
-        Float64Array,
-        f64
-    );
+    #[test]
+    fn get_from_bare_binary_struct() {
+        // A plain struct of binary columns, e.g. as read from parquet, without the extension type
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            r#"{"a": 1}"#,
+            "null",
+            r#"{"a": "x"}"#,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let binary =
+            |array: &BinaryViewArray| arrow::compute::cast(array, &DataType::Binary).unwrap();
+        let fields = Fields::from(vec![
+            Field::new("metadata", DataType::Binary, false),
+            Field::new("value", DataType::Binary, false),
+        ]);
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let input: ArrayRef = Arc::new(StructArray::new(
+            fields,
+            vec![
+                binary(array.metadata_field()),
+                binary(array.value_field().unwrap()),
+            ],
+            Some(nulls),
+        ));
 
-    partially_shredded_variant_array_gen!(partially_shredded_bool_variant_array, || {
-        arrow::array::BooleanArray::from(vec![Some(true), None, None, Some(false)])
-    });
+        let field = Field::new("a", DataType::Int64, true);
+        let options = GetOptions::new_with_path(VariantPath::try_from("a").unwrap())
+            .with_as_type(Some(Arc::new(field)));
+        let result = variant_get(&input, options).unwrap();
+        let expected: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, None]));
+        assert_eq!(&result, &expected);
 
-    partially_shredded_variant_array_gen!(
-        partially_shredded_utf8_variant_array,
-        || { StringArray::from(vec![Some("hello"), None, None, Some("world")]) },
-        Variant::from(42i32)
-    );
+        let result = variant_get(&input, GetOptions::default()).unwrap();
+        let result = VariantArray::try_new(&result).unwrap();
+        assert!(result.is_null(1));
+        assert_eq!(
+            result.value(2).get_object_field("a"),
+            Some(Variant::from("x"))
+        );
 
-    partially_shredded_variant_array_gen!(partially_shredded_date32_variant_array, || {
-        Date32Array::from(vec![
-            Some(20348), // 2025-09-17
+        // Structs that are not variant-shaped are still rejected
+        let input: ArrayRef = Arc::new(StructArray::new(
+            Fields::from(vec![Field::new("value", DataType::Binary, false)]),
+            vec![binary(array.value_field().unwrap())],
             None,
-            None,
-            Some(20340), // 2025-09-09
-        ])
-    });
-
-    #[test]
-    fn get_variant_partially_shredded_int8_as_variant() {
-        numeric_partially_shredded_test!(i8, partially_shredded_int8_variant_array);
+        ));
+        let err = variant_get(&input, GetOptions::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid VariantArray: StructArray must contain a 'metadata' field"
+        );
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![1]));
+        assert!(variant_get(&input, GetOptions::default()).is_err());
     }
 
     #[test]
-    fn get_variant_partially_shredded_int16_as_variant() {
-        numeric_partially_shredded_test!(i16, partially_shredded_int16_variant_array);
-    }
+    fn get_field_by_id_matches_name() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"b": 1, "c": "x"}, "d": 2}"#),
+            Some(r#"{"a": {"b": "two"}}"#),
+            Some(r#"{"a": [1]}"#),
+            Some(r#"{"a": null}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let schema = DataType::Struct(
+            vec![Field::new_struct(
+                "a",
+                vec![Field::new("b", DataType::Int64, true)],
+                true,
+            )]
+            .into(),
+        );
+        let shredded = shred_variant(&array, &schema).unwrap();
 
-    #[test]
-    fn get_variant_partially_shredded_int32_as_variant() {
-        numeric_partially_shredded_test!(i32, partially_shredded_int32_variant_array);
-    }
+        // json_to_variant gives every row its own dictionary, but all of them start with "a" and
+        // then "b" (if present), so the ids resolved against the first row are valid in every row
+        let path = VariantPath::try_from("a.b").unwrap();
+        let by_id = path.resolve_field_ids(array.value(0).metadata());
+        assert_eq!(
+            by_id[..],
+            [
+                VariantPathElement::field_id(0),
+                VariantPathElement::field_id(1)
+            ]
+        );
 
-    #[test]
-    fn get_variant_partially_shredded_int64_as_variant() {
-        numeric_partially_shredded_test!(i64, partially_shredded_int64_variant_array);
+        for input in [&array, &shredded] {
+            let input = ArrayRef::from(input.clone());
+            for as_type in [None, Some(DataType::Int64), Some(DataType::Utf8)] {
+                for strategy in [GetStrategy::Rowwise, GetStrategy::Columnar] {
+                    let options = |path: &VariantPath<'static>| {
+                        GetOptions::new_with_path(path.clone())
+                            .with_as_type(
+                                as_type.clone().map(|t| Arc::new(Field::new("r", t, true))),
+                            )
+                            .with_strategy(strategy)
+                    };
+                    let mut expected = variant_get(&input, options(&path)).unwrap();
+                    let mut actual = variant_get(&input, options(&by_id)).unwrap();
+                    if as_type.is_none() {
+                        // Field ids are followed through unshredded values
+                        let unshred = |array| {
+                            let array = VariantArray::try_new(&array).unwrap();
+                            ArrayRef::from(unshred_variant(&array).unwrap())
+                        };
+                        (expected, actual) = (unshred(expected), unshred(actual));
+                    }
+                    assert_eq!(&actual, &expected, "{as_type:?} {strategy:?}");
+                }
+            }
+        }
     }
 
     #[test]
-    fn get_variant_partially_shredded_float32_as_variant() {
-        numeric_partially_shredded_test!(f32, partially_shredded_float32_variant_array);
-    }
+    fn get_preserves_field_metadata() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"x": 1, "y": "s"}, "l": [1, 2]}"#),
+            Some(r#"{"a": {"x": 2}, "l": []}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let metadata = |value: &str| {
+            std::collections::HashMap::from([(
+                "ARROW:extension:name".to_string(),
+                value.to_string(),
+            )])
+        };
+        let struct_type = DataType::Struct(
+            vec![
+                Field::new("x", DataType::Int64, true).with_metadata(metadata("x_ext")),
+                Field::new("y", DataType::Utf8, true),
+            ]
+            .into(),
+        );
+        let list_type = DataType::List(Arc::new(
+            Field::new("element", DataType::Int64, true).with_metadata(metadata("item_ext")),
+        ));
+        let shredded = shred_variant(
+            &array,
+            &DataType::Struct(vec![Field::new("a", struct_type.clone(), true)].into()),
+        )
+        .unwrap();
 
-    #[test]
-    fn get_variant_partially_shredded_float64_as_variant() {
-        numeric_partially_shredded_test!(f64, partially_shredded_float64_variant_array);
+        for input in [&array, &shredded] {
+            let input = ArrayRef::from(input.clone());
+            for (path, data_type) in [("a", &struct_type), ("l", &list_type)] {
+                let field = Field::new("result", data_type.clone(), true)
+                    .with_metadata(metadata("result_ext"));
+                let options = GetOptions::new_with_path(VariantPath::try_from(path).unwrap())
+                    .with_as_type(Some(Arc::new(field)));
+                let result = variant_get(&input, options).unwrap();
+                assert_eq!(result.data_type(), data_type, "{path}");
+            }
+        }
     }
 
     #[test]
-    fn get_variant_partially_shredded_bool_as_variant() {
-        let array = partially_shredded_bool_variant_array();
-        let options = GetOptions::new();
-        let result = variant_get(&array, options).unwrap();
+    fn get_many_matches_individual_gets() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": 1, "b": {"c": "x"}, "l": [1, 2]}"#),
+            Some(r#"{"a": "two", "b": {"c": 3}, "l": []}"#),
+            Some(r#"{"b": null}"#),
+            Some("[1, 2]"),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let shredded = shred_variant(
+            &array,
+            &DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into()),
+        )
+        .unwrap();
 
-        // expect the result is a VariantArray
-        let result = VariantArray::try_new(&result).unwrap();
-        assert_eq!(result.len(), 4);
+        let struct_type = DataType::Struct(vec![Field::new("c", DataType::Utf8, true)].into());
+        let requests = [
+            get_options("a", Some(DataType::Int64), true),
+            get_options("a", Some(DataType::Utf8), true),
+            get_options("a", None, true),
+            get_options("b", Some(struct_type), true),
+            get_options("b.c", None, true),
+            get_options("l[1]", Some(DataType::Int32), true),
+            get_options("l[*]", Some(DataType::Int64), true),
+            get_options("", None, true),
+            get_options("a", Some(DataType::Int64), true).with_strategy(GetStrategy::Columnar),
+        ];
+        for input in [&array, &shredded] {
+            let input = ArrayRef::from(input.clone());
+            let results = variant_get_many(&input, &requests).unwrap();
+            assert_eq!(results.len(), requests.len());
+            for (result, options) in results.iter().zip(&requests) {
+                let expected = variant_get(&input, options.clone()).unwrap();
+                assert_eq!(result, &expected, "{}", options.path);
+            }
+        }
 
-        // Expect the values are the same as the original values
-        assert_eq!(result.value(0), Variant::from(true));
-        assert!(!result.is_valid(1));
-        assert_eq!(result.value(2), Variant::from("n/a"));
-        assert_eq!(result.value(3), Variant::from(false));
+        // Errors match too, in strict mode or when a path must exist
+        let input = ArrayRef::from(array);
+        let strict = get_options("a", Some(DataType::Int64), false);
+        let must_exist = get_options("a", None, true).with_missing_as(MissingBehavior::Error);
+        for options in [strict, must_exist] {
+            let err = variant_get_many(&input, &[get_options("b", None, true), options.clone()])
+                .unwrap_err();
+            let expected = variant_get(&input, options).unwrap_err();
+            assert_eq!(err.to_string(), expected.to_string());
+        }
+        let field = Field::new("result", DataType::Int64, false);
+        let non_nullable = get_options("a", None, true).with_as_type(Some(Arc::new(field)));
+        assert!(variant_get_many(&input, &[non_nullable]).is_err());
+        assert!(variant_get_many(&input, &[]).unwrap().is_empty());
     }
 
     #[test]
-    fn get_variant_partially_shredded_utf8_as_variant() {
-        let array = partially_shredded_utf8_variant_array();
-        let options = GetOptions::new();
-        let result = variant_get(&array, options).unwrap();
-
-        // expect the result is a VariantArray
-        let result = VariantArray::try_new(&result).unwrap();
-        assert_eq!(result.len(), 4);
-
-        // Expect the values are the same as the original values
-        assert_eq!(result.value(0), Variant::from("hello"));
-        assert!(!result.is_valid(1));
-        assert_eq!(result.value(2), Variant::from(42i32));
-        assert_eq!(result.value(3), Variant::from("world"));
-    }
+    fn get_applies_last_path_element() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"value": 5, "a": {"b": [7, {"c": 9}]}}"#),
+            Some(r#"{"value": {"value": 6}}"#),
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let shredded = shred_variant(
+            &array,
+            &DataType::Struct(vec![Field::new("value", DataType::Int64, true)].into()),
+        )
+        .unwrap();
 
-    partially_shredded_variant_array_gen!(partially_shredded_binary_view_variant_array, || {
-        BinaryViewArray::from(vec![
-            Some(&[1u8, 2u8, 3u8][..]), // row 0 is shredded
-            None,                       // row 1 is null
-            None,                       // row 2 is a string
-            Some(&[4u8, 5u8, 6u8][..]), // row 3 is shredded
-        ])
-    });
+        for input in [&array, &shredded] {
+            let input = ArrayRef::from(input.clone());
+            let expected: ArrayRef = Arc::new(UInt64Array::from(vec![Some(5), None]));
+            assert_eq!(
+                &get(&input, "$.value", Some(DataType::UInt64), true).unwrap(),
+                &expected
+            );
+            let expected: ArrayRef = Arc::new(UInt64Array::from(vec![None, Some(6)]));
+            assert_eq!(
+                &get(&input, "$.value.value", Some(DataType::UInt64), true).unwrap(),
+                &expected
+            );
+            let expected: ArrayRef = Arc::new(UInt64Array::from(vec![Some(7), None]));
+            assert_eq!(
+                &get(&input, "$.a.b[0]", Some(DataType::UInt64), true).unwrap(),
+                &expected
+            );
+            let expected: ArrayRef = Arc::new(UInt64Array::from(vec![Some(9), None]));
+            assert_eq!(
+                &get(&input, "$.a.b[1].c", Some(DataType::UInt64), true).unwrap(),
+                &expected
+            );
+        }
+    }
 
     #[test]
-    fn get_variant_partially_shredded_date32_as_variant() {
-        let array = partially_shredded_date32_variant_array();
-        let options = GetOptions::new();
-        let result = variant_get(&array, options).unwrap();
+    fn get_mixes_index_and_field_segments() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(
+                r#"{"items": [{"name": "a"}, {"name": "b", "x": 1}], "matrix": [[1, 2, 3], [4, 5, 6]]}"#,
+            ),
+            Some(r#"{"items": [{"other": 1}, 2], "matrix": [[1], [2]]}"#),
+            Some(r#"{"items": "x", "matrix": null}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let items = Field::new_list(
+            "items",
+            Field::new_list_field(
+                DataType::Struct(vec![Field::new("name", DataType::Utf8, true)].into()),
+                true,
+            ),
+            true,
+        );
+        let matrix = Field::new_list(
+            "matrix",
+            Field::new_list_field(DataType::new_list(DataType::Int64, true), true),
+            true,
+        );
+        let shredded = shred_variant(
+            &array,
+            &DataType::Struct(vec![items.clone(), matrix].into()),
+        )
+        .unwrap();
+        let partially_shredded =
+            shred_variant(&array, &DataType::Struct(vec![items].into())).unwrap();
 
-        // expect the result is a VariantArray
-        let result = VariantArray::try_new(&result).unwrap();
-        assert_eq!(result.len(), 4);
+        for input in [&array, &shredded, &partially_shredded] {
+            let input = ArrayRef::from(input.clone());
+            let expected: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), None, None, None]));
+            assert_eq!(
+                &get(&input, "$.items[0].name", Some(DataType::Utf8), true).unwrap(),
+                &expected
+            );
+            let expected: ArrayRef = Arc::new(StringArray::from(vec![Some("b"), None, None, None]));
+            assert_eq!(
+                &get(&input, "$.items[1].name", Some(DataType::Utf8), true).unwrap(),
+                &expected
+            );
+            assert_eq!(
+                &get(&input, "$.items[-1].name", Some(DataType::Utf8), true).unwrap(),
+                &expected
+            );
+            let expected: ArrayRef = Arc::new(Int64Array::from(vec![Some(6), None, None, None]));
+            assert_eq!(
+                &get(&input, "$.matrix[1][2]", Some(Int64), true).unwrap(),
+                &expected
+            );
+            let expected: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), Some(1), None, None]));
+            assert_eq!(
+                &get(&input, "$.matrix[0][0]", Some(Int64), true).unwrap(),
+                &expected
+            );
 
-        // Expect the values are the same as the original values
-        use chrono::NaiveDate;
-        let date1 = NaiveDate::from_ymd_opt(2025, 9, 17).unwrap();
-        let date2 = NaiveDate::from_ymd_opt(2025, 9, 9).unwrap();
-        assert_eq!(result.value(0), Variant::from(date1));
-        assert!(!result.is_valid(1));
-        assert_eq!(result.value(2), Variant::from("n/a"));
-        assert_eq!(result.value(3), Variant::from(date2));
+            let result =
+                VariantArray::try_new(&get(&input, "$.items[0]", None, true).unwrap()).unwrap();
+            assert_eq!(
+                result.value(1).as_object().unwrap().get("other"),
+                Some(Variant::Int8(1))
+            );
+            let result =
+                VariantArray::try_new(&get(&input, "$.matrix[1][2]", None, true).unwrap()).unwrap();
+            let values: Vec<_> = result
+                .iter()
+                .map(|v| v.and_then(|v| v.as_int64()))
+                .collect();
+            assert_eq!(values, [Some(6), None, None, None]);
+        }
     }
 
     #[test]
-    fn get_variant_partially_shredded_binary_view_as_variant() {
-        let array = partially_shredded_binary_view_variant_array();
-        let options = GetOptions::new();
-        let result = variant_get(&array, options).unwrap();
+    fn get_composes_with_variant_output() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"b": 1, "c": "x"}}"#),
+            Some(r#"{"a": {"b": "two"}}"#),
+            Some(r#"{"a": {"c": 3}}"#),
+            Some(r#"{"a": 4}"#),
+            Some(r#"{"z": {"b": 5}}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let a_type = |b_type| {
+            DataType::Struct(
+                vec![Field::new_struct(
+                    "a",
+                    vec![Field::new("b", b_type, true)],
+                    true,
+                )]
+                .into(),
+            )
+        };
+        let inputs = [
+            array.clone(),
+            shred_variant(&array, &a_type(DataType::Int64)).unwrap(),
+            shred_variant(&array, &a_type(DataType::Utf8)).unwrap(),
+        ];
 
-        // expect the result is a VariantArray
-        let result = VariantArray::try_new(&result).unwrap();
-        assert_eq!(result.len(), 4);
+        for input in inputs {
+            let input = ArrayRef::from(input);
+            let a = get(&input, "$.a", None, true).unwrap();
+            assert!(VariantArray::try_new(&a).is_ok());
+            let chained = get(&a, "$.b", Some(DataType::Int64), true).unwrap();
+            let direct = get(&input, "$.a.b", Some(DataType::Int64), true).unwrap();
+            assert_eq!(&chained, &direct);
+            assert_eq!(
+                direct.as_primitive(),
+                &Int64Array::from(vec![Some(1), None, None, None, None, None])
+            );
 
-        // Expect the values are the same as the original values
-        assert_eq!(result.value(0), Variant::from(&[1u8, 2u8, 3u8][..]));
-        assert!(!result.is_valid(1));
-        assert_eq!(result.value(2), Variant::from("n/a"));
-        assert_eq!(result.value(3), Variant::from(&[4u8, 5u8, 6u8][..]));
+            let chained = VariantArray::try_new(&get(&a, "$.b", None, true).unwrap()).unwrap();
+            let direct = VariantArray::try_new(&get(&input, "$.a.b", None, true).unwrap()).unwrap();
+            assert_eq!(
+                chained.iter().collect::<Vec<_>>(),
+                direct.iter().collect::<Vec<_>>()
+            );
+        }
     }
 
-    // Timestamp partially-shredded tests grouped with the other partially-shredded cases.
-    macro_rules! assert_variant_get_as_variant_array_with_default_option {
-        ($variant_array: expr, $array_expected: expr) => {{
-            let options = GetOptions::new();
-            let array = $variant_array;
-            let result = variant_get(&array, options).unwrap();
-            let result = VariantArray::try_new(&result).unwrap();
-
-            assert_eq!(result.len(), $array_expected.len());
+    #[test]
+    fn get_from_empty_array() {
+        let empty = VariantArray::from(Vec::<Option<i64>>::new());
+        let shredding = DataType::Struct(
+            vec![
+                Field::new("a", Int64, true),
+                Field::new_list("l", Field::new_list_field(DataType::Utf8, true), true),
+            ]
+            .into(),
+        );
+        let inputs = [
+            empty.clone(),
+            shred_variant(&empty, &shredding).unwrap(),
+            shred_variant(&empty, &Int64).unwrap(),
+        ];
+        let as_types = [
+            Int64,
+            Float32,
+            DataType::Utf8View,
+            DataType::Date32,
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            DataType::Decimal128(10, 2),
+            DataType::Struct(vec![Field::new("a", Int64, false)].into()),
+            DataType::List(Arc::new(Field::new_list_field(Int64, true))),
+        ];
+        let paths = ["$", "$.a", "$.l", "$.l[*]", "$.a.b[-1]"];
+
+        for input in inputs {
+            let input = ArrayRef::from(input);
+            for path in paths {
+                let path = VariantPath::try_from(path).unwrap();
+                let options = GetOptions::new_with_path(path.clone());
+                let result = variant_get(&input, options).unwrap();
+                assert_eq!(result.len(), 0, "{path}");
+                assert!(result.nulls().is_none(), "{path}");
+
+                for as_type in &as_types {
+                    for nullable in [true, false] {
+                        let field = Arc::new(Field::new("x", as_type.clone(), nullable));
+                        let expected_type = match path.contains(&VariantPathElement::Wildcard) {
+                            true => DataType::new_list(as_type.clone(), true),
+                            false => as_type.clone(),
+                        };
+                        let options =
+                            GetOptions::new_with_path(path.clone()).with_as_type(Some(field));
+                        let result = variant_get(&input, options).unwrap();
+                        assert_eq!(result.len(), 0, "{path} as {as_type}");
+                        assert_eq!(result.data_type(), &expected_type, "{path} as {as_type}");
+                        assert!(result.nulls().is_none(), "{path} as {as_type}");
+                    }
+                }
 
-            for (idx, item) in $array_expected.into_iter().enumerate() {
-                match item {
-                    Some(item) => assert_eq!(result.value(idx), item),
-                    None => assert!(result.is_null(idx)),
+                let requests: Vec<_> = as_types
+                    .iter()
+                    .map(|as_type| {
+                        GetOptions::new_with_path(path.clone()).with_as_data_type(as_type.clone())
+                    })
+                    .collect();
+                for result in variant_get_many(&input, &requests).unwrap() {
+                    assert_eq!(result.len(), 0, "{path}");
+                    assert!(result.nulls().is_none(), "{path}");
                 }
             }
-        }};
-    }
-
-    partially_shredded_variant_array_gen!(
-        partially_shredded_timestamp_micro_ntz_variant_array,
-        || {
-            arrow::array::TimestampMicrosecondArray::from(vec![
-                Some(-456000),
-                None,
-                None,
-                Some(1758602096000000),
-            ])
         }
-    );
+    }
 
     #[test]
-    fn get_variant_partial_shredded_timestamp_micro_ntz_as_variant() {
-        let array = partially_shredded_timestamp_micro_ntz_variant_array();
-        assert_variant_get_as_variant_array_with_default_option!(
-            array,
-            vec![
-                Some(Variant::from(
-                    DateTime::from_timestamp_micros(-456000i64)
-                        .unwrap()
-                        .naive_utc(),
-                )),
-                None,
-                Some(Variant::from("n/a")),
-                Some(Variant::from(
-                    DateTime::parse_from_rfc3339("2025-09-23T12:34:56+08:00")
-                        .unwrap()
-                        .naive_utc(),
-                )),
-            ]
+    fn get_with_default() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"maybe": 5}"#),
+            Some(r#"{"other": 1}"#),
+            Some(r#"{"maybe": null}"#),
+            Some(r#"{"maybe": "five"}"#),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let shredded = shred_variant(
+            &array,
+            &DataType::Struct(vec![Field::new("maybe", DataType::Int64, true)].into()),
         )
+        .unwrap();
+        let path = || VariantPath::try_from("$.maybe").unwrap();
+
+        for input in [&array, &shredded] {
+            let input = ArrayRef::from(input.clone());
+            for nullable in [true, false] {
+                let field = Field::new("maybe", DataType::Int64, nullable);
+                let options = GetOptions::new_with_path(path())
+                    .with_as_type(Some(Arc::new(field)))
+                    .with_default_value(Some(Variant::from(0)));
+                let result = variant_get(&input, options).unwrap();
+                let expected: ArrayRef = Arc::new(Int64Array::from(vec![5, 0, 0, 0, 0]));
+                assert_eq!(&result, &expected);
+                assert_eq!(result.null_count(), 0);
+            }
+
+            // Without as_type, the default is substituted as a variant
+            let options =
+                GetOptions::new_with_path(path()).with_default_value(Some(Variant::from("n/a")));
+            let result = variant_get(&input, options).unwrap();
+            let result = VariantArray::try_new(&result).unwrap();
+            assert_eq!(result.value(0).as_int64(), Some(5));
+            assert_eq!(result.value(1), Variant::from("n/a"));
+            assert_eq!(result.value(2), Variant::Null);
+            assert_eq!(result.value(3), Variant::from("five"));
+            assert_eq!(result.value(4), Variant::from("n/a"));
+        }
+
+        let input = ArrayRef::from(array);
+        let field = Field::new("maybe", DataType::Int64, true);
+        let options = GetOptions::new_with_path(path())
+            .with_as_type(Some(Arc::new(field)))
+            .with_default_value(Some(Variant::from("zero")));
+        let err = variant_get(&input, options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Default value ShortString(ShortString(\"zero\")) cannot be converted to Int64"
+        );
+
+        let options = GetOptions::new_with_path(VariantPath::try_from("l[*]").unwrap())
+            .with_default_value(Some(Variant::from(0)));
+        assert!(variant_get(&input, options).is_err());
     }
 
-    partially_shredded_variant_array_gen!(partially_shredded_timestamp_micro_variant_array, || {
-        arrow::array::TimestampMicrosecondArray::from(vec![
-            Some(-456000),
+    #[test]
+    fn get_missing_field_of_perfectly_shredded_object_with_default() {
+        // Without a `value` column, a field that is not shredded is missing from every row
+        let metadata =
+            BinaryViewArray::from_iter_values(std::iter::repeat_n(EMPTY_VARIANT_METADATA_BYTES, 3));
+        let a = ShreddedVariantFieldArray::from_parts(
             None,
+            Some(Arc::new(Int64Array::from(vec![1, 2, 3]))),
             None,
-            Some(1758602096000000),
-        ])
-        .with_timezone("+00:00")
-    });
+        );
+        let typed_value = StructArray::try_new(
+            Fields::from(vec![Field::new("a", a.data_type().clone(), true)]),
+            vec![ArrayRef::from(a)],
+            None,
+        )
+        .unwrap();
+        let input: ArrayRef =
+            VariantArray::from_parts(metadata, None, Some(Arc::new(typed_value)), None).into();
+        let path = || VariantPath::try_from("$.b").unwrap();
+
+        let options =
+            GetOptions::new_with_path(path()).with_default_value(Some(Variant::from("n/a")));
+        let result = variant_get(&input, options).unwrap();
+        let result = VariantArray::try_new(&result).unwrap();
+        assert_eq!(result.len(), 3);
+        assert!(result.iter().all(|v| v == Some(Variant::from("n/a"))));
+
+        let options = GetOptions::new_with_path(path())
+            .with_as_data_type(DataType::Int64)
+            .with_default_value(Some(Variant::from(0)));
+        let result = variant_get(&input, options).unwrap();
+        let expected: ArrayRef = Arc::new(Int64Array::from(vec![0, 0, 0]));
+        assert_eq!(&result, &expected);
+    }
 
     #[test]
-    fn get_variant_partial_shredded_timestamp_micro_as_variant() {
-        let array = partially_shredded_timestamp_micro_variant_array();
-        assert_variant_get_as_variant_array_with_default_option!(
-            array,
+    fn compiled_plan_matches_variant_get() {
+        let batches = [
+            vec![Some(r#"{"a": {"b": 1}, "l": [1, 2]}"#), None],
             vec![
-                Some(Variant::from(
-                    DateTime::from_timestamp_micros(-456000i64)
-                        .unwrap()
-                        .to_utc(),
-                )),
-                None,
-                Some(Variant::from("n/a")),
-                Some(Variant::from(
-                    DateTime::parse_from_rfc3339("2025-09-23T12:34:56+08:00")
-                        .unwrap()
-                        .to_utc(),
-                )),
-            ]
-        )
-    }
+                Some(r#"{"a": {"b": "x"}, "l": "y"}"#),
+                Some(r#"{"a": {"b": 300}, "l": []}"#),
+                Some(r#"{"c": 1}"#),
+            ],
+        ];
+        let batches = batches.map(|json| {
+            let json: ArrayRef = Arc::new(StringArray::from(json));
+            ArrayRef::from(json_to_variant(&json).unwrap())
+        });
+        let path = |path| VariantPath::try_from(path).unwrap();
+        let field = |data_type| Some(Arc::new(Field::new("result", data_type, true)));
+        let requests = [
+            GetOptions::new_with_path(path("$.a.b")),
+            GetOptions::new_with_path(path("$.a.b")).with_as_type(field(Int64)),
+            GetOptions::new_with_path(path("$.a.b"))
+                .with_as_type(field(Int8))
+                .with_default_value(Some(Variant::from(-1))),
+            GetOptions::new_with_path(path("$.a.b")).with_default_value(Some(Variant::from("n/a"))),
+            GetOptions::new_with_path(path("$.l[*]")).with_as_type(field(Int64)),
+        ];
 
-    partially_shredded_variant_array_gen!(
-        partially_shredded_timestamp_nano_ntz_variant_array,
-        || {
-            arrow::array::TimestampNanosecondArray::from(vec![
-                Some(-4999999561),
-                None,
-                None,
-                Some(1758602096000000000),
-            ])
+        for options in requests {
+            let plan = compile_variant_get(options.clone()).unwrap();
+            for batch in &batches {
+                let expected = variant_get(batch, options.clone()).unwrap();
+                assert_eq!(&plan.execute(batch).unwrap(), &expected);
+            }
         }
-    );
 
-    #[test]
-    fn get_variant_partial_shredded_timestamp_nano_ntz_as_variant() {
-        let array = partially_shredded_timestamp_nano_ntz_variant_array();
-        assert_variant_get_as_variant_array_with_default_option!(
-            array,
-            vec![
-                Some(Variant::from(
-                    DateTime::from_timestamp(-5, 439).unwrap().naive_utc()
-                )),
-                None,
-                Some(Variant::from("n/a")),
-                Some(Variant::from(
-                    DateTime::parse_from_rfc3339("2025-09-23T12:34:56+08:00")
-                        .unwrap()
-                        .naive_utc()
-                )),
-            ]
-        )
+        // Invalid options are rejected when compiling, before seeing any batch
+        let options = GetOptions::new_with_path(path("$.a.b"))
+            .with_as_type(field(Int64))
+            .with_default_value(Some(Variant::from("zero")));
+        let err = compile_variant_get(options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Default value ShortString(ShortString(\"zero\")) cannot be converted to Int64"
+        );
+        let options =
+            GetOptions::new_with_path(path("$.l[*]")).with_default_value(Some(Variant::from(0)));
+        assert!(compile_variant_get(options).is_err());
     }
 
-    partially_shredded_variant_array_gen!(partially_shredded_timestamp_nano_variant_array, || {
-        arrow::array::TimestampNanosecondArray::from(vec![
-            Some(-4999999561),
-            None,
-            None,
-            Some(1758602096000000000),
-        ])
-        .with_timezone("+00:00")
-    });
-
     #[test]
-    fn get_variant_partial_shredded_timestamp_nano_as_variant() {
-        let array = partially_shredded_timestamp_nano_variant_array();
-        assert_variant_get_as_variant_array_with_default_option!(
-            array,
-            vec![
-                Some(Variant::from(
-                    DateTime::from_timestamp(-5, 439).unwrap().to_utc()
-                )),
-                None,
-                Some(Variant::from("n/a")),
-                Some(Variant::from(
-                    DateTime::parse_from_rfc3339("2025-09-23T12:34:56+08:00")
-                        .unwrap()
-                        .to_utc()
-                )),
-            ]
+    fn get_with_resolution_stats() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": 1, "l": [1]}"#),
+            Some(r#"{"a": 2, "l": "x"}"#),
+            Some(r#"{"a": "three"}"#),
+            Some(r#"{"a": null}"#),
+            Some(r#"{"b": 1, "l": []}"#),
+            Some("[1]"),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let shredded = shred_variant(
+            &array,
+            &DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into()),
         )
+        .unwrap();
+        let stats =
+            |resolved_count, null_count, missing_count, type_mismatch_count| ResolutionStats {
+                resolved_count,
+                null_count,
+                missing_count,
+                type_mismatch_count,
+            };
+        let path = |path| VariantPath::try_from(path).unwrap();
+
+        for input in [&array, &shredded] {
+            let input = ArrayRef::from(input.clone());
+
+            let options = GetOptions::new_with_path(path("a")).with_as_data_type(Int64);
+            let (result, resolution) = variant_get_with_stats(&input, options.clone()).unwrap();
+            assert_eq!(&result, &variant_get(&input, options).unwrap());
+            assert_eq!(resolution, stats(2, 2, 1, 2));
+
+            // The default does not change how the path resolved
+            let options = GetOptions::new_with_path(path("a"))
+                .with_as_data_type(Int64)
+                .with_default_value(Some(Variant::from(0)));
+            let (result, resolution) = variant_get_with_stats(&input, options).unwrap();
+            assert_eq!(result.null_count(), 0);
+            assert_eq!(resolution, stats(2, 2, 1, 2));
+
+            let options = GetOptions::new_with_path(path("a"));
+            let (_, resolution) = variant_get_with_stats(&input, options).unwrap();
+            assert_eq!(resolution, stats(3, 2, 1, 1));
+
+            let options = GetOptions::new_with_path(path("l[*]")).with_as_data_type(Int64);
+            let (_, resolution) = variant_get_with_stats(&input, options).unwrap();
+            assert_eq!(resolution, stats(2, 1, 2, 2));
+        }
     }
 
-    /// Shredding: extract a value as an Int32Array
     #[test]
-    fn get_variant_shredded_int32_as_int32_safe_cast() {
-        // Extract the typed value as Int32Array
-        let array = partially_shredded_int32_variant_array();
-        // specify we want the typed value as Int32
-        let field = Field::new("typed_value", DataType::Int32, true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&array, options).unwrap();
-        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
-            Some(34),
+    fn get_through_json_strings() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"payload": "{\"id\": 1, \"tags\": [\"a\", \"[\\\"b\\\"]\"]}"}"#),
+            Some(r#"{"payload": {"id": 2}}"#),
+            Some(r#"{"payload": "not json"}"#),
+            Some(r#"{"payload": "{\"id\": "}"#),
+            Some(r#"{"payload": " [{\"id\": 5}]"}"#),
             None,
-            None, // "n/a" is not an Int32 so converted to null
-            Some(100),
         ]));
-        assert_eq!(&result, &expected)
+        let array = json_to_variant(&json).unwrap();
+        let shredded = shred_variant(
+            &array,
+            &DataType::Struct(vec![Field::new("payload", DataType::Utf8, true)].into()),
+        )
+        .unwrap();
+
+        for input in [&array, &shredded] {
+            let input = ArrayRef::from(input.clone());
+            let get = |path, parse_json_strings| {
+                let options = get_options(path, Some(Int64), true)
+                    .with_parse_json_strings(parse_json_strings);
+                variant_get(&input, options).unwrap()
+            };
+
+            // Strings are opaque by default
+            let expected: ArrayRef = Arc::new(Int64Array::from(vec![
+                None,
+                Some(2),
+                None,
+                None,
+                None,
+                None,
+            ]));
+            assert_eq!(&get("$.payload.id", false), &expected);
+
+            let expected: ArrayRef = Arc::new(Int64Array::from(vec![
+                Some(1),
+                Some(2),
+                None,
+                None,
+                None,
+                None,
+            ]));
+            assert_eq!(&get("$.payload.id", true), &expected);
+            let expected: ArrayRef = Arc::new(Int64Array::from(vec![
+                None,
+                None,
+                None,
+                None,
+                Some(5),
+                None,
+            ]));
+            assert_eq!(&get("$.payload[0].id", true), &expected);
+
+            // Nested JSON strings are parsed too, but only where the path steps into them
+            let options =
+                GetOptions::new_with_path(VariantPath::try_from("$.payload.tags[1][0]").unwrap())
+                    .with_as_data_type(DataType::Utf8)
+                    .with_parse_json_strings(true);
+            let result = variant_get(&input, options).unwrap();
+            assert_eq!(result.as_string::<i32>().value(0), "b");
+            let options = GetOptions::new_with_path(VariantPath::try_from("$.payload").unwrap())
+                .with_parse_json_strings(true);
+            let result = VariantArray::try_new(&variant_get(&input, options).unwrap()).unwrap();
+            assert!(result.value(0).as_string().unwrap().starts_with('{'));
+        }
+
+        let options =
+            GetOptions::new_with_path(VariantPath::from_iter([VariantPathElement::field_id(0)]))
+                .with_parse_json_strings(true);
+        assert!(variant_get(&ArrayRef::from(array), options).is_err());
     }
 
-    /// Shredding: extract a value as an Int32Array, unsafe cast (should error on "n/a")
     #[test]
-    fn get_variant_shredded_int32_as_int32_unsafe_cast() {
-        // Extract the typed value as Int32Array
-        let array = partially_shredded_int32_variant_array();
-        let field = Field::new("typed_value", DataType::Int32, true);
-        let cast_options = CastOptions {
-            safe: false, // unsafe cast
-            ..Default::default()
-        };
-        let options = GetOptions::new()
-            .with_as_type(Some(FieldRef::from(field)))
-            .with_cast_options(cast_options);
+    fn get_from_appended_json_values() {
+        let rows = [
+            serde_json::json!({"user": {"id": 1, "tags": ["a", "b"]}, "score": 0.5}),
+            serde_json::json!({"user": {"id": 5000000000i64, "tags": []}, "score": null}),
+            serde_json::json!(["not", "an", "object"]),
+        ];
+        let mut builder = VariantArrayBuilder::new(rows.len());
+        for row in &rows {
+            builder.append_json_value(row).unwrap();
+        }
+        let input = ArrayRef::from(builder.build());
 
-        let err = variant_get(&array, options).unwrap_err();
-        // TODO make this error message nicer (not Debug format)
+        let expected: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), Some(5000000000), None]));
         assert_eq!(
-            err.to_string(),
-            "Cast error: Failed to extract primitive of type Int32 from variant ShortString(ShortString(\"n/a\")) at path VariantPath([])"
+            &get(&input, "$.user.id", Some(Int64), true).unwrap(),
+            &expected
+        );
+        let expected: ArrayRef = Arc::new(StringArray::from(vec![Some("b"), None, None]));
+        assert_eq!(
+            &get(&input, "$.user.tags[-1]", Some(DataType::Utf8), true).unwrap(),
+            &expected
+        );
+        let expected: ArrayRef = Arc::new(Float64Array::from(vec![Some(0.5), None, None]));
+        assert_eq!(
+            &get(&input, "$.score", Some(Float64), true).unwrap(),
+            &expected
+        );
+        let expected: ArrayRef = Arc::new(StringArray::from(vec![None, None, Some("an")]));
+        assert_eq!(
+            &get(&input, "[1]", Some(DataType::Utf8), true).unwrap(),
+            &expected
         );
+
+        // Integers keep the narrowest type that fits
+        let options = GetOptions::new_with_path(VariantPath::try_from("$.user.id").unwrap());
+        let ids = VariantArray::try_new(&variant_get(&input, options).unwrap()).unwrap();
+        assert_eq!(ids.value(0), Variant::Int8(1));
+        assert_eq!(ids.value(1), Variant::Int64(5000000000));
     }
 
-    /// Perfect Shredding: extract the typed value as a VariantArray
-    macro_rules! numeric_perfectly_shredded_test {
+    #[test]
+    fn get_output_field_names() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            r#"{"user": {"email": "a@x", "tags": ["t"]}}"#,
+            r#"{"user": {"email": "b@x"}}"#,
+        ]));
+        let input = ArrayRef::from(json_to_variant(&json).unwrap());
+        let path = |path| VariantPath::try_from(path).unwrap();
+
+        let email =
+            GetOptions::new_with_path(path("$.user.email")).with_as_data_type(DataType::Utf8);
+        assert_eq!(email.output_name(), "email");
+        assert_eq!(
+            GetOptions::new_with_path(path("$.user.tags[0]")).output_name(),
+            "tags"
+        );
+        assert_eq!(GetOptions::new_with_path(path("$[0]")).output_name(), "");
+        let renamed = GetOptions::new_with_path(path("$.user.email"))
+            .with_output_name(Some("contact".to_string()));
+        assert_eq!(renamed.output_name(), "contact");
+
+        // Fields describe the results, so they can be assembled into a RecordBatch
+        let user = GetOptions::new_with_path(path("user"));
+        let results = [&email, &renamed, &user].map(|options| {
+            let result = variant_get(&input, options.clone()).unwrap();
+            (options.output_field(&result), result)
+        });
+        let (fields, columns): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+        assert_eq!(fields[0], Field::new("email", DataType::Utf8, true));
+        assert!(fields[1].try_extension_type::<VariantType>().is_ok());
+        assert_eq!(fields[2].name(), "user");
+        assert!(fields[2].try_extension_type::<VariantType>().is_ok());
+
+        let schema = Arc::new(arrow_schema::Schema::new(fields));
+        let batch = RecordBatch::try_new(schema, columns).unwrap();
+        assert_eq!(batch.column_by_name("email").unwrap().len(), 2);
+        assert_eq!(batch.num_columns(), 3);
+
+        // Typed results keep the metadata of `as_type`
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let as_type = Field::new("ignored", DataType::Utf8, false).with_metadata(metadata.clone());
+        let options =
+            GetOptions::new_with_path(path("$.user.email")).with_as_type(Some(Arc::new(as_type)));
+        let result = variant_get(&input, options.clone()).unwrap();
+        let field = options.output_field(&result);
+        assert_eq!(field.name(), "email");
+        assert_eq!(field.metadata(), &metadata);
+    }
+
+    #[test]
+    fn get_missing_path_as_error() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"b": 1}, "l": [{"b": 2}]}"#),
+            Some(r#"{"a": null, "l": []}"#),
+            Some(r#"{"a": "not an object", "l": [{"b": 3}]}"#),
+            None,
+            Some(r#"{"x": {"b": 4}, "l": [{"b": 5}, {"c": 6}]}"#),
+        ]));
+        let unshredded = json_to_variant(&json).unwrap();
+        let schema = DataType::Struct(
+            vec![Field::new_struct(
+                "a",
+                vec![Field::new("b", Int64, true)],
+                true,
+            )]
+            .into(),
+        );
+        let shredded = shred_variant(&unshredded, &schema).unwrap();
+
+        for input in [unshredded, shredded] {
+            let input = ArrayRef::from(input);
+            let get = |path, missing_as| {
+                let options = get_options(path, Some(Int64), true).with_missing_as(missing_as);
+                variant_get(&input, options)
+            };
+
+            // By default, the missing intermediate field in row 4 is NULL like the others
+            let result = get("a.b", MissingBehavior::Null).unwrap();
+            let expected: ArrayRef =
+                Arc::new(Int64Array::from(vec![Some(1), None, None, None, None]));
+            assert_eq!(&result, &expected);
+
+            let err = get("a.b", MissingBehavior::Error).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Cast error: row 4: .a does not exist at path $.a.b"
+            );
+
+            let err = get("l[*].b", MissingBehavior::Error).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Cast error: row 4: .b does not exist at path $.l[*].b"
+            );
+            let err = get("l[0].b", MissingBehavior::Error).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Cast error: row 1: [0] does not exist at path $.l[0].b"
+            );
+        }
+
+        // Paths through null and non-object values are not missing
+        let rows = Arc::new(json.slice(0, 4));
+        let input = ArrayRef::from(json_to_variant(&(rows as ArrayRef)).unwrap());
+        let options = GetOptions::new_with_path(VariantPath::try_from("a.b").unwrap())
+            .with_missing_as(MissingBehavior::Error);
+        let result = variant_get(&input, options).unwrap();
+        assert_eq!(result.logical_null_count(), 3);
+    }
+
+    #[test]
+    fn get_field_with_changing_metadata() {
+        // Field ids of "a" differ between dictionaries, and identical dictionaries repeat
+        let input: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"o": {"a": 1, "b": 2}}"#),
+            Some(r#"{"o": {"b": 3, "a": 4}}"#),
+            Some(r#"{"o": {"b": 5}}"#),
+            Some(r#"{"o": {"b": 6, "a": 7}}"#),
+            Some(r#"{"o": {"b": 8, "a": 9}}"#),
+            Some(r#"{"a": {"o": 10}}"#),
+            Some(r#"{"o": {"a": 11}}"#),
+        ]));
+        let input = ArrayRef::from(json_to_variant(&input).unwrap());
+        let options = GetOptions::new_with_path(VariantPath::try_from("o.a").unwrap())
+            .with_as_data_type(Int64);
+        let result = variant_get(&input, options).unwrap();
+        let expected: ArrayRef = Arc::new(Int64Array::from(vec![
+            Some(1),
+            Some(4),
+            None,
+            Some(7),
+            Some(9),
+            None,
+            Some(11),
+        ]));
+        assert_eq!(&result, &expected);
+    }
+
+    #[test]
+    fn get_object_variant_is_self_contained() {
+        // Rows have different metadata dictionaries, and "a" is not the first field name in them
+        let input: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"z": 0, "a": {"x": 1, "nested": {"y": "deep"}}}"#),
+            Some(r#"{"a": {"other": true, "x": 2}, "b": [1]}"#),
+            Some(r#"{"a": 3}"#),
+            Some(r#"{"b": {"x": 4}}"#),
+        ]));
+        let input = json_to_variant(&input).unwrap();
+        let schema = DataType::Struct(
+            vec![Field::new_struct(
+                "a",
+                vec![Field::new("x", Int64, true)],
+                true,
+            )]
+            .into(),
+        );
+        let shredded = shred_variant(&input, &schema).unwrap();
+
+        for input in [input, shredded] {
+            let options = GetOptions::new_with_path(VariantPath::try_from("a").unwrap());
+            let result = variant_get(&ArrayRef::from(input), options).unwrap();
+            let result = crate::unshred_variant(&VariantArray::try_new(&result).unwrap()).unwrap();
+
+            // Every row is readable from its own metadata and value bytes alone
+            let row = |i: usize| {
+                let metadata = result.metadata_field().value(i);
+                let value = result.value_field().unwrap().value(i);
+                Variant::try_new(metadata, value).unwrap()
+            };
+            // Shredding widens "x" to Int64, so compare values rather than encodings
+            let x = |v: &Variant| v.get_object_field("x").and_then(|x| x.as_int64());
+            let first = row(0);
+            assert_eq!(x(&first), Some(1));
+            let nested = first.get_object_field("nested").unwrap();
+            assert_eq!(nested.get_object_field("y"), Some(Variant::from("deep")));
+            assert_eq!(x(&row(1)), Some(2));
+            assert_eq!(row(1).get_object_field("other"), Some(Variant::BooleanTrue));
+            assert_eq!(row(2), Variant::from(3i8));
+            assert!(result.is_null(3));
+        }
+    }
+
+    #[test]
+    fn get_complex_variant() {
+        single_variant_get_test(
+            r#"{"top_level_field": {"inner_field": 1234}}"#,
+            VariantPath::try_from("top_level_field").unwrap(),
+            r#"{"inner_field": 1234}"#,
+        );
+    }
+
+    /// Partial Shredding: extract a value as a VariantArray
+    macro_rules! numeric_partially_shredded_test {
         ($primitive_type:ty, $data_fn:ident) => {
             let array = $data_fn();
             let options = GetOptions::new();
@@ -872,1302 +2584,1387 @@ mod test {
 
             // expect the result is a VariantArray
             let result = VariantArray::try_new(&result).unwrap();
-            assert_eq!(result.len(), 3);
+            assert_eq!(result.len(), 4);
 
             // Expect the values are the same as the original values
             assert_eq!(
                 result.value(0),
-                Variant::from(<$primitive_type>::try_from(1u8).unwrap())
+                Variant::from(<$primitive_type>::try_from(34u8).unwrap())
             );
+            assert!(!result.is_valid(1));
+            assert_eq!(result.value(2), Variant::from("n/a"));
             assert_eq!(
-                result.value(1),
-                Variant::from(<$primitive_type>::try_from(2u8).unwrap())
+                result.value(3),
+                Variant::from(<$primitive_type>::try_from(100u8).unwrap())
             );
-            assert_eq!(
-                result.value(2),
-                Variant::from(<$primitive_type>::try_from(3u8).unwrap())
+        };
+    }
+
+    /// Build a mixed input [typed, null, fallback, typed] and let shred_variant
+    /// generate the shredded fixture for the requested type.
+    macro_rules! partially_shredded_variant_array_gen {
+        ($func_name:ident,  $typed_value_array_gen: expr) => {
+            partially_shredded_variant_array_gen!(
+                $func_name,
+                $typed_value_array_gen,
+                Variant::from("n/a")
             );
         };
+        ($func_name:ident,  $typed_value_array_gen: expr, $fallback_variant:expr) => {
+            fn $func_name() -> ArrayRef {
+                let typed_value: ArrayRef = Arc::new($typed_value_array_gen());
+                let typed_as_variant = cast_to_variant(typed_value.as_ref())
+                    .expect("should cast typed array to variant");
+                let mut input_builder = VariantArrayBuilder::new(typed_as_variant.len());
+                input_builder.append_variant(typed_as_variant.value(0));
+                input_builder.append_null();
+                input_builder.append_variant($fallback_variant);
+                input_builder.append_variant(typed_as_variant.value(3));
+
+                let variant_array = shred_variant(&input_builder.build(), typed_value.data_type())
+                    .expect("should shred variant array");
+                ArrayRef::from(variant_array)
+            }
+        };
     }
 
+    // Fixture definitions grouped with the partially-shredded tests.
+    macro_rules! numeric_partially_shredded_variant_array_fn {
+        ($func:ident, $array_type:ident, $primitive_type:ty) => {
+            partially_shredded_variant_array_gen!($func, || $array_type::from(vec![
+                Some(<$primitive_type>::try_from(34u8).unwrap()),
+                None,
+                None,
+                Some(<$primitive_type>::try_from(100u8).unwrap()),
+            ]));
+        };
+    }
+
+    numeric_partially_shredded_variant_array_fn!(
+        partially_shredded_int8_variant_array,
+        Int8Array,
+        i8
+    );
+    numeric_partially_shredded_variant_array_fn!(
+        partially_shredded_int16_variant_array,
+        Int16Array,
+        i16
+    );
+    numeric_partially_shredded_variant_array_fn!(
+        partially_shredded_int32_variant_array,
+        Int32Array,
+        i32
+    );
+    numeric_partially_shredded_variant_array_fn!(
+        partially_shredded_int64_variant_array,
+        Int64Array,
+        i64
+    );
+    numeric_partially_shredded_variant_array_fn!(
+        partially_shredded_float32_variant_array,
+        Float32Array,
+        f32
+    );
+    numeric_partially_shredded_variant_array_fn!(
+        partially_shredded_float64_variant_array,
+        Float64Array,
+        f64
+    );
+
+    partially_shredded_variant_array_gen!(partially_shredded_bool_variant_array, || {
+        arrow::array::BooleanArray::from(vec![Some(true), None, None, Some(false)])
+    });
+
+    partially_shredded_variant_array_gen!(
+        partially_shredded_utf8_variant_array,
+        || { StringArray::from(vec![Some("hello"), None, None, Some("world")]) },
+        Variant::from(42i32)
+    );
+
+    partially_shredded_variant_array_gen!(partially_shredded_date32_variant_array, || {
+        Date32Array::from(vec![
+            Some(20348), // 2025-09-17
+            None,
+            None,
+            Some(20340), // 2025-09-09
+        ])
+    });
+
     #[test]
-    fn get_variant_perfectly_shredded_int8_as_variant() {
-        numeric_perfectly_shredded_test!(i8, perfectly_shredded_int8_variant_array);
+    fn get_variant_partially_shredded_int8_as_variant() {
+        numeric_partially_shredded_test!(i8, partially_shredded_int8_variant_array);
     }
 
     #[test]
-    fn get_variant_perfectly_shredded_int16_as_variant() {
-        numeric_perfectly_shredded_test!(i16, perfectly_shredded_int16_variant_array);
+    fn get_variant_partially_shredded_int16_as_variant() {
+        numeric_partially_shredded_test!(i16, partially_shredded_int16_variant_array);
     }
 
     #[test]
-    fn get_variant_perfectly_shredded_int32_as_variant() {
-        numeric_perfectly_shredded_test!(i32, perfectly_shredded_int32_variant_array);
+    fn get_variant_partially_shredded_int32_as_variant() {
+        numeric_partially_shredded_test!(i32, partially_shredded_int32_variant_array);
     }
 
     #[test]
-    fn get_variant_perfectly_shredded_int64_as_variant() {
-        numeric_perfectly_shredded_test!(i64, perfectly_shredded_int64_variant_array);
+    fn get_variant_partially_shredded_int64_as_variant() {
+        numeric_partially_shredded_test!(i64, partially_shredded_int64_variant_array);
     }
 
     #[test]
-    fn get_variant_perfectly_shredded_float32_as_variant() {
-        numeric_perfectly_shredded_test!(f32, perfectly_shredded_float32_variant_array);
+    fn get_variant_partially_shredded_float32_as_variant() {
+        numeric_partially_shredded_test!(f32, partially_shredded_float32_variant_array);
     }
 
     #[test]
-    fn get_variant_perfectly_shredded_float64_as_variant() {
-        numeric_perfectly_shredded_test!(f64, perfectly_shredded_float64_variant_array);
+    fn get_variant_partially_shredded_float64_as_variant() {
+        numeric_partially_shredded_test!(f64, partially_shredded_float64_variant_array);
     }
 
-    /// AllNull: extract a value as a VariantArray
     #[test]
-    fn get_variant_all_null_as_variant() {
-        let array = all_null_variant_array();
+    fn get_variant_partially_shredded_bool_as_variant() {
+        let array = partially_shredded_bool_variant_array();
         let options = GetOptions::new();
         let result = variant_get(&array, options).unwrap();
 
         // expect the result is a VariantArray
         let result = VariantArray::try_new(&result).unwrap();
-        assert_eq!(result.len(), 3);
+        assert_eq!(result.len(), 4);
 
-        // All values should be null
-        assert!(!result.is_valid(0));
-        assert!(!result.is_valid(1));
-        assert!(!result.is_valid(2));
+        // Expect the values are the same as the original values
+        assert_eq!(result.value(0), Variant::from(true));
+        assert!(!result.is_valid(1));
+        assert_eq!(result.value(2), Variant::from("n/a"));
+        assert_eq!(result.value(3), Variant::from(false));
     }
 
-    /// AllNull: extract a value as an Int32Array
     #[test]
-    fn get_variant_all_null_as_int32() {
-        let array = all_null_variant_array();
-        // specify we want the typed value as Int32
-        let field = Field::new("typed_value", DataType::Int32, true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+    fn get_variant_partially_shredded_utf8_as_variant() {
+        let array = partially_shredded_utf8_variant_array();
+        let options = GetOptions::new();
         let result = variant_get(&array, options).unwrap();
 
-        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
-            Option::<i32>::None,
-            Option::<i32>::None,
-            Option::<i32>::None,
-        ]));
-        assert_eq!(&result, &expected)
-    }
+        // expect the result is a VariantArray
+        let result = VariantArray::try_new(&result).unwrap();
+        assert_eq!(result.len(), 4);
 
-    macro_rules! perfectly_shredded_to_arrow_primitive_test {
-        ($name:ident, $primitive_type:expr, $perfectly_shredded_array_gen_fun:ident, $expected_array:expr) => {
-            #[test]
-            fn $name() {
-                let array = $perfectly_shredded_array_gen_fun();
-                let field = Field::new("typed_value", $primitive_type, true);
-                let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
-                let result = variant_get(&array, options).unwrap();
-                let expected_array: ArrayRef = Arc::new($expected_array);
-                assert_eq!(&result, &expected_array);
-            }
-        };
+        // Expect the values are the same as the original values
+        assert_eq!(result.value(0), Variant::from("hello"));
+        assert!(!result.is_valid(1));
+        assert_eq!(result.value(2), Variant::from(42i32));
+        assert_eq!(result.value(3), Variant::from("world"));
     }
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_int18_as_int8,
-        Int8,
-        perfectly_shredded_int8_variant_array,
-        Int8Array::from(vec![Some(1), Some(2), Some(3)])
-    );
-
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_int16_as_int16,
-        Int16,
-        perfectly_shredded_int16_variant_array,
-        Int16Array::from(vec![Some(1), Some(2), Some(3)])
-    );
+    partially_shredded_variant_array_gen!(partially_shredded_binary_view_variant_array, || {
+        BinaryViewArray::from(vec![
+            Some(&[1u8, 2u8, 3u8][..]), // row 0 is shredded
+            None,                       // row 1 is null
+            None,                       // row 2 is a string
+            Some(&[4u8, 5u8, 6u8][..]), // row 3 is shredded
+        ])
+    });
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_int32_as_int32,
-        Int32,
-        perfectly_shredded_int32_variant_array,
-        Int32Array::from(vec![Some(1), Some(2), Some(3)])
-    );
+    #[test]
+    fn get_variant_partially_shredded_date32_as_variant() {
+        let array = partially_shredded_date32_variant_array();
+        let options = GetOptions::new();
+        let result = variant_get(&array, options).unwrap();
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_int64_as_int64,
-        Int64,
-        perfectly_shredded_int64_variant_array,
-        Int64Array::from(vec![Some(1), Some(2), Some(3)])
-    );
+        // expect the result is a VariantArray
+        let result = VariantArray::try_new(&result).unwrap();
+        assert_eq!(result.len(), 4);
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_float32_as_float32,
-        Float32,
-        perfectly_shredded_float32_variant_array,
-        Float32Array::from(vec![Some(1.0), Some(2.0), Some(3.0)])
-    );
+        // Expect the values are the same as the original values
+        use chrono::NaiveDate;
+        let date1 = NaiveDate::from_ymd_opt(2025, 9, 17).unwrap();
+        let date2 = NaiveDate::from_ymd_opt(2025, 9, 9).unwrap();
+        assert_eq!(result.value(0), Variant::from(date1));
+        assert!(!result.is_valid(1));
+        assert_eq!(result.value(2), Variant::from("n/a"));
+        assert_eq!(result.value(3), Variant::from(date2));
+    }
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_float64_as_float64,
-        Float64,
-        perfectly_shredded_float64_variant_array,
-        Float64Array::from(vec![Some(1.0), Some(2.0), Some(3.0)])
-    );
+    #[test]
+    fn get_variant_partially_shredded_binary_view_as_variant() {
+        let array = partially_shredded_binary_view_variant_array();
+        let options = GetOptions::new();
+        let result = variant_get(&array, options).unwrap();
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_boolean_as_boolean,
-        Boolean,
-        perfectly_shredded_bool_variant_array,
-        BooleanArray::from(vec![Some(true), Some(false), Some(true)])
-    );
+        // expect the result is a VariantArray
+        let result = VariantArray::try_new(&result).unwrap();
+        assert_eq!(result.len(), 4);
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_utf8_as_utf8,
-        DataType::Utf8,
-        perfectly_shredded_utf8_variant_array,
-        StringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
-    );
+        // Expect the values are the same as the original values
+        assert_eq!(result.value(0), Variant::from(&[1u8, 2u8, 3u8][..]));
+        assert!(!result.is_valid(1));
+        assert_eq!(result.value(2), Variant::from("n/a"));
+        assert_eq!(result.value(3), Variant::from(&[4u8, 5u8, 6u8][..]));
+    }
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_large_utf8_as_utf8,
-        DataType::Utf8,
-        perfectly_shredded_large_utf8_variant_array,
-        StringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
-    );
+    // Timestamp partially-shredded tests grouped with the other partially-shredded cases.
+    macro_rules! assert_variant_get_as_variant_array_with_default_option {
+        ($variant_array: expr, $array_expected: expr) => {{
+            let options = GetOptions::new();
+            let array = $variant_array;
+            let result = variant_get(&array, options).unwrap();
+            let result = VariantArray::try_new(&result).unwrap();
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_utf8_view_as_utf8,
-        DataType::Utf8,
-        perfectly_shredded_utf8_view_variant_array,
-        StringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
-    );
+            assert_eq!(result.len(), $array_expected.len());
 
-    macro_rules! perfectly_shredded_variant_array_fn {
-        ($func:ident, $typed_value_gen:expr) => {
-            fn $func() -> ArrayRef {
-                // Prefer producing fixtures with shred_variant from unshredded input.
-                // Fall back for remaining non-shreddable test-only Arrow types (currently Null).
-                let typed_value: ArrayRef = Arc::new($typed_value_gen());
-                if let Some(shredded) = cast_to_variant(typed_value.as_ref())
-                    .ok()
-                    .and_then(|unshredded| shred_variant(&unshredded, typed_value.data_type()).ok())
-                {
-                    return shredded.into();
+            for (idx, item) in $array_expected.into_iter().enumerate() {
+                match item {
+                    Some(item) => assert_eq!(result.value(idx), item),
+                    None => assert!(result.is_null(idx)),
                 }
-
-                let metadata = BinaryViewArray::from_iter_values(std::iter::repeat_n(
-                    EMPTY_VARIANT_METADATA_BYTES,
-                    typed_value.len(),
-                ));
-                VariantArray::from_parts(metadata, None, Some(typed_value), None).into()
             }
-        };
-    }
-
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_utf8_variant_array, || {
-        StringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
-    });
-
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_large_utf8_variant_array, || {
-        LargeStringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
-    });
-
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_utf8_view_variant_array, || {
-        StringViewArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
-    });
-
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_bool_variant_array, || {
-        BooleanArray::from(vec![Some(true), Some(false), Some(true)])
-    });
-
-    /// Return a VariantArray that represents a perfectly "shredded" variant
-    /// for the given typed value.
-    ///
-    /// The schema of the corresponding `StructArray` would look like this:
-    ///
-    /// ```text
-    /// StructArray {
-    ///   metadata: BinaryViewArray,
-    ///   typed_value: Int32Array,
-    /// }
-    /// ```
-    macro_rules! numeric_perfectly_shredded_variant_array_fn {
-        ($func:ident, $array_type:ident, $primitive_type:ty) => {
-            perfectly_shredded_variant_array_fn!($func, || {
-                $array_type::from(vec![
-                    Some(<$primitive_type>::try_from(1u8).unwrap()),
-                    Some(<$primitive_type>::try_from(2u8).unwrap()),
-                    Some(<$primitive_type>::try_from(3u8).unwrap()),
-                ])
-            });
-        };
+        }};
     }
 
-    numeric_perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_int8_variant_array,
-        Int8Array,
-        i8
-    );
-    numeric_perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_int16_variant_array,
-        Int16Array,
-        i16
-    );
-    numeric_perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_int32_variant_array,
-        Int32Array,
-        i32
-    );
-    numeric_perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_int64_variant_array,
-        Int64Array,
-        i64
-    );
-    numeric_perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_float32_variant_array,
-        Float32Array,
-        f32
-    );
-    numeric_perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_float64_variant_array,
-        Float64Array,
-        f64
-    );
-
-    perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_timestamp_micro_ntz_variant_array,
+    partially_shredded_variant_array_gen!(
+        partially_shredded_timestamp_micro_ntz_variant_array,
         || {
             arrow::array::TimestampMicrosecondArray::from(vec![
                 Some(-456000),
-                Some(1758602096000001),
-                Some(1758602096000002),
+                None,
+                None,
+                Some(1758602096000000),
             ])
         }
     );
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_micro_ntz_as_timestamp_micro_ntz,
-        DataType::Timestamp(TimeUnit::Microsecond, None),
-        perfectly_shredded_timestamp_micro_ntz_variant_array,
-        arrow::array::TimestampMicrosecondArray::from(vec![
-            Some(-456000),
-            Some(1758602096000001),
-            Some(1758602096000002),
-        ])
-    );
-
-    // test converting micro to nano
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_micro_ntz_as_nano_ntz,
-        DataType::Timestamp(TimeUnit::Nanosecond, None),
-        perfectly_shredded_timestamp_micro_ntz_variant_array,
-        arrow::array::TimestampNanosecondArray::from(vec![
-            Some(-456000000),
-            Some(1758602096000001000),
-            Some(1758602096000002000)
-        ])
-    );
+    #[test]
+    fn get_variant_partial_shredded_timestamp_micro_ntz_as_variant() {
+        let array = partially_shredded_timestamp_micro_ntz_variant_array();
+        assert_variant_get_as_variant_array_with_default_option!(
+            array,
+            vec![
+                Some(Variant::from(
+                    DateTime::from_timestamp_micros(-456000i64)
+                        .unwrap()
+                        .naive_utc(),
+                )),
+                None,
+                Some(Variant::from("n/a")),
+                Some(Variant::from(
+                    DateTime::parse_from_rfc3339("2025-09-23T12:34:56+08:00")
+                        .unwrap()
+                        .naive_utc(),
+                )),
+            ]
+        )
+    }
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_timestamp_micro_variant_array, || {
+    partially_shredded_variant_array_gen!(partially_shredded_timestamp_micro_variant_array, || {
         arrow::array::TimestampMicrosecondArray::from(vec![
             Some(-456000),
-            Some(1758602096000001),
-            Some(1758602096000002),
+            None,
+            None,
+            Some(1758602096000000),
         ])
         .with_timezone("+00:00")
     });
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_micro_as_timestamp_micro,
-        DataType::Timestamp(TimeUnit::Microsecond, Some(Arc::from("+00:00"))),
-        perfectly_shredded_timestamp_micro_variant_array,
-        arrow::array::TimestampMicrosecondArray::from(vec![
-            Some(-456000),
-            Some(1758602096000001),
-            Some(1758602096000002),
-        ])
-        .with_timezone("+00:00")
-    );
-
-    // test converting micro to nano
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_micro_as_nano,
-        DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("+00:00"))),
-        perfectly_shredded_timestamp_micro_variant_array,
-        arrow::array::TimestampNanosecondArray::from(vec![
-            Some(-456000000),
-            Some(1758602096000001000),
-            Some(1758602096000002000)
-        ])
-        .with_timezone("+00:00")
-    );
+    #[test]
+    fn get_variant_partial_shredded_timestamp_micro_as_variant() {
+        let array = partially_shredded_timestamp_micro_variant_array();
+        assert_variant_get_as_variant_array_with_default_option!(
+            array,
+            vec![
+                Some(Variant::from(
+                    DateTime::from_timestamp_micros(-456000i64)
+                        .unwrap()
+                        .to_utc(),
+                )),
+                None,
+                Some(Variant::from("n/a")),
+                Some(Variant::from(
+                    DateTime::parse_from_rfc3339("2025-09-23T12:34:56+08:00")
+                        .unwrap()
+                        .to_utc(),
+                )),
+            ]
+        )
+    }
 
-    perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_timestamp_nano_ntz_variant_array,
+    partially_shredded_variant_array_gen!(
+        partially_shredded_timestamp_nano_ntz_variant_array,
         || {
             arrow::array::TimestampNanosecondArray::from(vec![
                 Some(-4999999561),
-                Some(1758602096000000001),
-                Some(1758602096000000002),
+                None,
+                None,
+                Some(1758602096000000000),
             ])
         }
     );
 
-    perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_timestamp_micro_variant_array_for_second_and_milli_second,
-        || {
-            arrow::array::TimestampMicrosecondArray::from(vec![
-                Some(1234),       // can't be cast to second & millisecond
-                Some(1234000),    // can be cast to millisecond, but not second
-                Some(1234000000), // can be cast to second & millisecond
-            ])
-            .with_timezone("+00:00")
-        }
-    );
+    #[test]
+    fn get_variant_partial_shredded_timestamp_nano_ntz_as_variant() {
+        let array = partially_shredded_timestamp_nano_ntz_variant_array();
+        assert_variant_get_as_variant_array_with_default_option!(
+            array,
+            vec![
+                Some(Variant::from(
+                    DateTime::from_timestamp(-5, 439).unwrap().naive_utc()
+                )),
+                None,
+                Some(Variant::from("n/a")),
+                Some(Variant::from(
+                    DateTime::parse_from_rfc3339("2025-09-23T12:34:56+08:00")
+                        .unwrap()
+                        .naive_utc()
+                )),
+            ]
+        )
+    }
 
-    // The following two tests wants to cover the micro with timezone -> milli/second cases
-    // there are three test items, which contains some items can be cast safely, and some can't
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_micro_as_timestamp_second,
-        DataType::Timestamp(TimeUnit::Second, Some(Arc::from("+00:00"))),
-        perfectly_shredded_timestamp_micro_variant_array_for_second_and_milli_second,
-        arrow::array::TimestampSecondArray::from(vec![
+    partially_shredded_variant_array_gen!(partially_shredded_timestamp_nano_variant_array, || {
+        arrow::array::TimestampNanosecondArray::from(vec![
+            Some(-4999999561),
             None,
-            None, // Return None if can't be cast to second safely
-            Some(1234)
-        ])
-        .with_timezone("+00:00")
-    );
-
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_micro_as_timestamp_milli,
-        DataType::Timestamp(TimeUnit::Millisecond, Some(Arc::from("+00:00"))),
-        perfectly_shredded_timestamp_micro_variant_array_for_second_and_milli_second,
-        arrow::array::TimestampMillisecondArray::from(vec![
-            None, // Return None if can't be cast to millisecond safely
-            Some(1234),
-            Some(1234000)
+            None,
+            Some(1758602096000000000),
         ])
         .with_timezone("+00:00")
-    );
+    });
 
-    perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_timestamp_micro_ntz_variant_array_for_second_and_milli_second,
-        || {
-            arrow::array::TimestampMicrosecondArray::from(vec![
-                Some(1234),       // can't be cast to second & millisecond
-                Some(1234000),    // can be cast to millisecond, but not second
-                Some(1234000000), // can be cast to second & millisecond
-            ])
-        }
-    );
+    #[test]
+    fn get_variant_partial_shredded_timestamp_nano_as_variant() {
+        let array = partially_shredded_timestamp_nano_variant_array();
+        assert_variant_get_as_variant_array_with_default_option!(
+            array,
+            vec![
+                Some(Variant::from(
+                    DateTime::from_timestamp(-5, 439).unwrap().to_utc()
+                )),
+                None,
+                Some(Variant::from("n/a")),
+                Some(Variant::from(
+                    DateTime::parse_from_rfc3339("2025-09-23T12:34:56+08:00")
+                        .unwrap()
+                        .to_utc()
+                )),
+            ]
+        )
+    }
 
-    // The following two tests wants to cover the micro_ntz -> milli/second cases
-    // there are three test items, which contains some items can be cast safely, and some can't
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_micro_ntz_as_timestamp_second,
-        DataType::Timestamp(TimeUnit::Second, None),
-        perfectly_shredded_timestamp_micro_ntz_variant_array_for_second_and_milli_second,
-        arrow::array::TimestampSecondArray::from(vec![
+    /// Shredding: extract a value as an Int32Array
+    #[test]
+    fn get_variant_shredded_int32_as_int32_safe_cast() {
+        // Extract the typed value as Int32Array
+        let array = partially_shredded_int32_variant_array();
+        // specify we want the typed value as Int32
+        let field = Field::new("typed_value", DataType::Int32, true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&array, options).unwrap();
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(34),
             None,
-            None, // Return None if can't be cast to second safely
-            Some(1234)
-        ])
-    );
+            None, // "n/a" is not an Int32 so converted to null
+            Some(100),
+        ]));
+        assert_eq!(&result, &expected)
+    }
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_micro_ntz_as_timestamp_milli,
-        DataType::Timestamp(TimeUnit::Millisecond, None),
-        perfectly_shredded_timestamp_micro_ntz_variant_array_for_second_and_milli_second,
-        arrow::array::TimestampMillisecondArray::from(vec![
-            None, // Return None if can't be cast to millisecond safely
-            Some(1234),
-            Some(1234000)
-        ])
-    );
+    /// Shredding: extract a value as an Int32Array, unsafe cast (should error on "n/a")
+    #[test]
+    fn get_variant_shredded_int32_as_int32_unsafe_cast() {
+        // Extract the typed value as Int32Array
+        let array = partially_shredded_int32_variant_array();
+        let field = Field::new("typed_value", DataType::Int32, true);
+        let cast_options = CastOptions {
+            safe: false, // unsafe cast
+            ..Default::default()
+        };
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(cast_options);
 
-    perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_timestamp_nano_variant_array_for_second_and_milli_second,
-        || {
-            arrow::array::TimestampNanosecondArray::from(vec![
-                Some(1234000),       // can't be cast to second & millisecond
-                Some(1234000000),    // can be cast to millisecond, but not second
-                Some(1234000000000), // can be cast to second & millisecond
-            ])
-            .with_timezone("+00:00")
-        }
-    );
+        let err = variant_get(&array, options).unwrap_err();
+        // TODO make this error message nicer (not Debug format)
+        assert_eq!(
+            err.to_string(),
+            "Cast error: row 2: Failed to extract primitive of type Int32 from variant ShortString(ShortString(\"n/a\")) at path $"
+        );
+    }
 
-    // The following two tests wants to cover the nano with timezone -> milli/second cases
-    // there are three test items, which contains some items can be cast safely, and some can't
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_nano_as_timestamp_second,
-        DataType::Timestamp(TimeUnit::Second, Some(Arc::from("+00:00"))),
-        perfectly_shredded_timestamp_nano_variant_array_for_second_and_milli_second,
-        arrow::array::TimestampSecondArray::from(vec![
+    /// Integers of any width are widened losslessly, and only narrowing can overflow
+    #[test]
+    fn get_integers_across_widths() {
+        let array = VariantArray::from(vec![
+            Some(Variant::Int8(1)),
+            Some(Variant::Int8(-1)),
+            Some(Variant::Int16(300)),
+            Some(Variant::Int64(100_000)),
             None,
-            None, // Return None if can't be cast to second safely
-            Some(1234)
-        ])
-        .with_timezone("+00:00")
-    );
+        ]);
+        let shredded = shred_variant(&array, &DataType::Int64).unwrap();
+
+        for input in [&array, &shredded] {
+            let input = ArrayRef::from(input.clone());
+            let expected: ArrayRef = Arc::new(Int64Array::from(vec![
+                Some(1),
+                Some(-1),
+                Some(300),
+                Some(100_000),
+                None,
+            ]));
+            for safe in [true, false] {
+                assert_eq!(&get(&input, "", Some(Int64), safe).unwrap(), &expected);
+            }
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_nano_as_timestamp_milli,
-        DataType::Timestamp(TimeUnit::Millisecond, Some(Arc::from("+00:00"))),
-        perfectly_shredded_timestamp_nano_variant_array_for_second_and_milli_second,
-        arrow::array::TimestampMillisecondArray::from(vec![
-            None, // Return None if can't be cast to millisecond safely
-            Some(1234),
-            Some(1234000)
-        ])
-        .with_timezone("+00:00")
-    );
+            let expected: ArrayRef = Arc::new(Int16Array::from(vec![
+                Some(1),
+                Some(-1),
+                Some(300),
+                None,
+                None,
+            ]));
+            assert_eq!(&get(&input, "", Some(Int16), true).unwrap(), &expected);
+            assert!(get(&input, "", Some(Int16), false).is_err());
 
-    perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_timestamp_nano_ntz_variant_array_for_second_and_milli_second,
-        || {
-            arrow::array::TimestampNanosecondArray::from(vec![
-                Some(1234000),       // can't be cast to second & millisecond
-                Some(1234000000),    // can be cast to millisecond, but not second
-                Some(1234000000000), // can be cast to second & millisecond
-            ])
+            let expected: ArrayRef =
+                Arc::new(UInt8Array::from(vec![Some(1), None, None, None, None]));
+            assert_eq!(
+                &get(&input, "", Some(DataType::UInt8), true).unwrap(),
+                &expected
+            );
+            assert!(get(&input, "", Some(DataType::UInt8), false).is_err());
         }
-    );
 
-    // The following two tests wants to cover the nano_ntz -> milli/second cases
-    // there are three test items, which contains some items can be cast safely, and some can't
+        let err = get(&ArrayRef::from(array), "", Some(Int16), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: row 3: Failed to extract primitive of type Int16 from variant Int64(100000) at path $"
+        );
+    }
+
+    /// Perfect Shredding: extract the typed value as a VariantArray
+    macro_rules! numeric_perfectly_shredded_test {
+        ($primitive_type:ty, $data_fn:ident) => {
+            let array = $data_fn();
+            let options = GetOptions::new();
+            let result = variant_get(&array, options).unwrap();
+
+            // expect the result is a VariantArray
+            let result = VariantArray::try_new(&result).unwrap();
+            assert_eq!(result.len(), 3);
+
+            // Expect the values are the same as the original values
+            assert_eq!(
+                result.value(0),
+                Variant::from(<$primitive_type>::try_from(1u8).unwrap())
+            );
+            assert_eq!(
+                result.value(1),
+                Variant::from(<$primitive_type>::try_from(2u8).unwrap())
+            );
+            assert_eq!(
+                result.value(2),
+                Variant::from(<$primitive_type>::try_from(3u8).unwrap())
+            );
+        };
+    }
+
+    #[test]
+    fn get_variant_perfectly_shredded_int8_as_variant() {
+        numeric_perfectly_shredded_test!(i8, perfectly_shredded_int8_variant_array);
+    }
+
+    #[test]
+    fn get_variant_perfectly_shredded_int16_as_variant() {
+        numeric_perfectly_shredded_test!(i16, perfectly_shredded_int16_variant_array);
+    }
+
+    #[test]
+    fn get_variant_perfectly_shredded_int32_as_variant() {
+        numeric_perfectly_shredded_test!(i32, perfectly_shredded_int32_variant_array);
+    }
+
+    #[test]
+    fn get_variant_perfectly_shredded_int64_as_variant() {
+        numeric_perfectly_shredded_test!(i64, perfectly_shredded_int64_variant_array);
+    }
+
+    #[test]
+    fn get_variant_perfectly_shredded_float32_as_variant() {
+        numeric_perfectly_shredded_test!(f32, perfectly_shredded_float32_variant_array);
+    }
+
+    #[test]
+    fn get_variant_perfectly_shredded_float64_as_variant() {
+        numeric_perfectly_shredded_test!(f64, perfectly_shredded_float64_variant_array);
+    }
+
+    /// AllNull: extract a value as a VariantArray
+    #[test]
+    fn get_variant_all_null_as_variant() {
+        let array = all_null_variant_array();
+        let options = GetOptions::new();
+        let result = variant_get(&array, options).unwrap();
+
+        // expect the result is a VariantArray
+        let result = VariantArray::try_new(&result).unwrap();
+        assert_eq!(result.len(), 3);
+
+        // All values should be null
+        assert!(!result.is_valid(0));
+        assert!(!result.is_valid(1));
+        assert!(!result.is_valid(2));
+    }
+
+    /// AllNull: extract a value as an Int32Array
+    #[test]
+    fn get_variant_all_null_as_int32() {
+        let array = all_null_variant_array();
+        // specify we want the typed value as Int32
+        let field = Field::new("typed_value", DataType::Int32, true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&array, options).unwrap();
+
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+            Option::<i32>::None,
+            Option::<i32>::None,
+            Option::<i32>::None,
+        ]));
+        assert_eq!(&result, &expected)
+    }
+
+    macro_rules! perfectly_shredded_to_arrow_primitive_test {
+        ($name:ident, $primitive_type:expr, $perfectly_shredded_array_gen_fun:ident, $expected_array:expr) => {
+            #[test]
+            fn $name() {
+                let array = $perfectly_shredded_array_gen_fun();
+                let field = Field::new("typed_value", $primitive_type, true);
+                let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+                let result = variant_get(&array, options).unwrap();
+                let expected_array: ArrayRef = Arc::new($expected_array);
+                assert_eq!(&result, &expected_array);
+            }
+        };
+    }
+
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_nano_ntz_as_timestamp_second,
-        DataType::Timestamp(TimeUnit::Second, None),
-        perfectly_shredded_timestamp_nano_ntz_variant_array_for_second_and_milli_second,
-        arrow::array::TimestampSecondArray::from(vec![
-            None,
-            None, // Return None if can't be cast to second safely
-            Some(1234)
-        ])
+        get_variant_perfectly_shredded_int18_as_int8,
+        Int8,
+        perfectly_shredded_int8_variant_array,
+        Int8Array::from(vec![Some(1), Some(2), Some(3)])
     );
 
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_nano_ntz_as_timestamp_milli,
-        DataType::Timestamp(TimeUnit::Millisecond, None),
-        perfectly_shredded_timestamp_nano_ntz_variant_array_for_second_and_milli_second,
-        arrow::array::TimestampMillisecondArray::from(vec![
-            None, // Return None if can't be cast to millisecond safely
-            Some(1234),
-            Some(1234000)
-        ])
+        get_variant_perfectly_shredded_int16_as_int16,
+        Int16,
+        perfectly_shredded_int16_variant_array,
+        Int16Array::from(vec![Some(1), Some(2), Some(3)])
     );
 
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_nano_ntz_as_timestamp_nano_ntz,
-        DataType::Timestamp(TimeUnit::Nanosecond, None),
-        perfectly_shredded_timestamp_nano_ntz_variant_array,
-        arrow::array::TimestampNanosecondArray::from(vec![
-            Some(-4999999561),
-            Some(1758602096000000001),
-            Some(1758602096000000002),
-        ])
+        get_variant_perfectly_shredded_int32_as_int32,
+        Int32,
+        perfectly_shredded_int32_variant_array,
+        Int32Array::from(vec![Some(1), Some(2), Some(3)])
     );
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_timestamp_nano_variant_array, || {
-        arrow::array::TimestampNanosecondArray::from(vec![
-            Some(-4999999561),
-            Some(1758602096000000001),
-            Some(1758602096000000002),
-        ])
-        .with_timezone("+00:00")
-    });
-
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_timestamp_nano_as_timestamp_nano,
-        DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("+00:00"))),
-        perfectly_shredded_timestamp_nano_variant_array,
-        arrow::array::TimestampNanosecondArray::from(vec![
-            Some(-4999999561),
-            Some(1758602096000000001),
-            Some(1758602096000000002),
-        ])
-        .with_timezone("+00:00")
+        get_variant_perfectly_shredded_int64_as_int64,
+        Int64,
+        perfectly_shredded_int64_variant_array,
+        Int64Array::from(vec![Some(1), Some(2), Some(3)])
     );
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_date_variant_array, || {
-        Date32Array::from(vec![Some(-12345), Some(17586), Some(20000)])
-    });
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_float32_as_float32,
+        Float32,
+        perfectly_shredded_float32_variant_array,
+        Float32Array::from(vec![Some(1.0), Some(2.0), Some(3.0)])
+    );
 
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_date_as_date,
-        DataType::Date32,
-        perfectly_shredded_date_variant_array,
-        Date32Array::from(vec![Some(-12345), Some(17586), Some(20000)])
+        get_variant_perfectly_shredded_float64_as_float64,
+        Float64,
+        perfectly_shredded_float64_variant_array,
+        Float64Array::from(vec![Some(1.0), Some(2.0), Some(3.0)])
     );
 
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_date_as_date64,
-        DataType::Date64,
-        perfectly_shredded_date_variant_array,
-        Date64Array::from(vec![
-            Some(-1066608000000),
-            Some(1519430400000),
-            Some(1728000000000)
-        ])
+        get_variant_perfectly_shredded_boolean_as_boolean,
+        Boolean,
+        perfectly_shredded_bool_variant_array,
+        BooleanArray::from(vec![Some(true), Some(false), Some(true)])
     );
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_time_variant_array, || {
-        Time64MicrosecondArray::from(vec![Some(12345000), Some(87654000), Some(135792000)])
-    });
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_utf8_as_utf8,
+        DataType::Utf8,
+        perfectly_shredded_utf8_variant_array,
+        StringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
+    );
 
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_time_as_time,
-        DataType::Time64(TimeUnit::Microsecond),
-        perfectly_shredded_time_variant_array,
-        Time64MicrosecondArray::from(vec![Some(12345000), Some(87654000), Some(135792000)])
+        get_variant_perfectly_shredded_large_utf8_as_utf8,
+        DataType::Utf8,
+        perfectly_shredded_large_utf8_variant_array,
+        StringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
     );
 
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_time_as_time64_nano,
-        DataType::Time64(TimeUnit::Nanosecond),
-        perfectly_shredded_time_variant_array,
-        Time64NanosecondArray::from(vec![
-            Some(12345000000),
-            Some(87654000000),
-            Some(135792000000)
-        ])
+        get_variant_perfectly_shredded_utf8_view_as_utf8,
+        DataType::Utf8,
+        perfectly_shredded_utf8_view_variant_array,
+        StringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
     );
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_time_variant_array_for_time32, || {
-        Time64MicrosecondArray::from(vec![
-            Some(1234),        // This can't be cast to Time32 losslessly
-            Some(7654000),     // This can be cast to Time32(Millisecond), but not Time32(Second)
-            Some(35792000000), // This can be cast to Time32(Second) & Time32(Millisecond)
-        ])
-    });
-
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_time_as_time32_second,
-        DataType::Time32(TimeUnit::Second),
-        perfectly_shredded_time_variant_array_for_time32,
-        Time32SecondArray::from(vec![
-            None,
-            None, // Return None if can't be cast to Time32(Second) safely
-            Some(35792)
-        ])
-    );
+    macro_rules! perfectly_shredded_variant_array_fn {
+        ($func:ident, $typed_value_gen:expr) => {
+            fn $func() -> ArrayRef {
+                // Prefer producing fixtures with shred_variant from unshredded input.
+                // Fall back for remaining non-shreddable test-only Arrow types (currently Null).
+                let typed_value: ArrayRef = Arc::new($typed_value_gen());
+                if let Some(shredded) = cast_to_variant(typed_value.as_ref())
+                    .ok()
+                    .and_then(|unshredded| shred_variant(&unshredded, typed_value.data_type()).ok())
+                {
+                    return shredded.into();
+                }
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_time_as_time32_milli,
-        DataType::Time32(TimeUnit::Millisecond),
-        perfectly_shredded_time_variant_array_for_time32,
-        Time32MillisecondArray::from(vec![
-            None, // Return None if can't be cast to Time32(Second) safely
-            Some(7654),
-            Some(35792000)
-        ])
-    );
+                let metadata = BinaryViewArray::from_iter_values(std::iter::repeat_n(
+                    EMPTY_VARIANT_METADATA_BYTES,
+                    typed_value.len(),
+                ));
+                VariantArray::from_parts(metadata, None, Some(typed_value), None).into()
+            }
+        };
+    }
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_null_variant_array, || {
-        let mut builder = NullBuilder::new();
-        builder.append_nulls(3);
-        builder.finish()
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_utf8_variant_array, || {
+        StringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
     });
 
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_null_as_null,
-        DataType::Null,
-        perfectly_shredded_null_variant_array,
-        arrow::array::NullArray::new(3)
-    );
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_large_utf8_variant_array, || {
+        LargeStringArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
+    });
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_null_variant_array_with_int, || {
-        Int32Array::from(vec![Some(32), Some(64), Some(48)])
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_utf8_view_variant_array, || {
+        StringViewArray::from(vec![Some("foo"), Some("bar"), Some("baz")])
     });
 
-    // We append null values if type miss match happens in safe mode
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_null_with_type_missmatch_in_safe_mode,
-        DataType::Null,
-        perfectly_shredded_null_variant_array_with_int,
-        arrow::array::NullArray::new(3)
-    );
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_bool_variant_array, || {
+        BooleanArray::from(vec![Some(true), Some(false), Some(true)])
+    });
 
-    // We'll return an error if type miss match happens in strict mode
-    #[test]
-    fn get_variant_perfectly_shredded_null_as_null_with_type_missmatch_in_strict_mode() {
-        let array = perfectly_shredded_null_variant_array_with_int();
-        let field = Field::new("typed_value", DataType::Null, true);
-        let options = GetOptions::new()
-            .with_as_type(Some(FieldRef::from(field)))
-            .with_cast_options(CastOptions {
-                safe: false,
-                format_options: FormatOptions::default(),
+    /// Return a VariantArray that represents a perfectly "shredded" variant
+    /// for the given typed value.
+    ///
+    /// The schema of the corresponding `StructArray` would look like this:
+    ///
+    /// ```text
+    /// StructArray {
+    ///   metadata: BinaryViewArray,
+    ///   typed_value: Int32Array,
+    /// }
+    /// ```
+    macro_rules! numeric_perfectly_shredded_variant_array_fn {
+        ($func:ident, $array_type:ident, $primitive_type:ty) => {
+            perfectly_shredded_variant_array_fn!($func, || {
+                $array_type::from(vec![
+                    Some(<$primitive_type>::try_from(1u8).unwrap()),
+                    Some(<$primitive_type>::try_from(2u8).unwrap()),
+                    Some(<$primitive_type>::try_from(3u8).unwrap()),
+                ])
             });
-
-        let result = variant_get(&array, options);
-
-        assert!(result.is_err());
-        let error_msg = format!("{}", result.unwrap_err());
-        assert!(
-            error_msg
-                .contains("Cast error: Failed to extract primitive of type Null from variant Int32(32) at path VariantPath([])"),
-            "Expected=[Cast error: Failed to extract primitive of type Null from variant Int32(32) at path VariantPath([])],\
-                Got error message=[{}]",
-            error_msg
-        );
+        };
     }
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_decimal4_variant_array, || {
-        Decimal32Array::from(vec![Some(12345), Some(23400), Some(-12342)])
-            .with_precision_and_scale(5, 2)
-            .unwrap()
-    });
-
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_decimal4_as_decimal4,
-        DataType::Decimal32(5, 2),
-        perfectly_shredded_decimal4_variant_array,
-        Decimal32Array::from(vec![Some(12345), Some(23400), Some(-12342)])
-            .with_precision_and_scale(5, 2)
-            .unwrap()
+    numeric_perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_int8_variant_array,
+        Int8Array,
+        i8
     );
-
-    perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_decimal8_variant_array_cast2decimal32,
-        || {
-            Decimal64Array::from(vec![Some(123456), Some(145678), Some(-123456)])
-                .with_precision_and_scale(6, 1)
-                .unwrap()
-        }
+    numeric_perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_int16_variant_array,
+        Int16Array,
+        i16
     );
-
-    // The input will be cast to Decimal32 when transformed to Variant
-    // This tests will covert the logic DataType::Decimal64(the original array)
-    // -> Variant::Decimal4(VariantArray) -> DataType::Decimal64(the result array)
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_decimal8_through_decimal32_as_decimal8,
-        DataType::Decimal64(6, 1),
-        perfectly_shredded_decimal8_variant_array_cast2decimal32,
-        Decimal64Array::from(vec![Some(123456), Some(145678), Some(-123456)])
-            .with_precision_and_scale(6, 1)
-            .unwrap()
+    numeric_perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_int32_variant_array,
+        Int32Array,
+        i32
     );
-
-    // This tests will covert the logic DataType::Decimal64(the original array)
-    //  -> Variant::Decimal8(VariantArray) -> DataType::Decimal64(the result array)
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_decimal8_variant_array, || {
-        Decimal64Array::from(vec![Some(1234567809), Some(1456787000), Some(-1234561203)])
-            .with_precision_and_scale(10, 1)
-            .unwrap()
-    });
-
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_decimal8_as_decimal8,
-        DataType::Decimal64(10, 1),
-        perfectly_shredded_decimal8_variant_array,
-        Decimal64Array::from(vec![Some(1234567809), Some(1456787000), Some(-1234561203)])
-            .with_precision_and_scale(10, 1)
-            .unwrap()
+    numeric_perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_int64_variant_array,
+        Int64Array,
+        i64
+    );
+    numeric_perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_float32_variant_array,
+        Float32Array,
+        f32
+    );
+    numeric_perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_float64_variant_array,
+        Float64Array,
+        f64
     );
 
-    // This tests will covert the logic DataType::Decimal128(the original array)
-    //  -> Variant::Decimal4(VariantArray) -> DataType::Decimal128(the result array)
     perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_decimal16_within_decimal4_variant_array,
+        perfectly_shredded_timestamp_micro_ntz_variant_array,
         || {
-            Decimal128Array::from(vec![
-                Some(i128::from(1234589)),
-                Some(i128::from(2344444)),
-                Some(i128::from(-1234789)),
+            arrow::array::TimestampMicrosecondArray::from(vec![
+                Some(-456000),
+                Some(1758602096000001),
+                Some(1758602096000002),
             ])
-            .with_precision_and_scale(7, 3)
-            .unwrap()
         }
     );
 
-    // This tests will covert the logic DataType::Decimal128(the original array)
-    // -> Variant::Decimal4(VariantArray) -> DataType::Decimal128(the result array)
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_decimal16_within_decimal4_as_decimal16,
-        DataType::Decimal128(7, 3),
-        perfectly_shredded_decimal16_within_decimal4_variant_array,
-        Decimal128Array::from(vec![
-            Some(i128::from(1234589)),
-            Some(i128::from(2344444)),
-            Some(i128::from(-1234789)),
+        get_variant_perfectly_shredded_timestamp_micro_ntz_as_timestamp_micro_ntz,
+        DataType::Timestamp(TimeUnit::Microsecond, None),
+        perfectly_shredded_timestamp_micro_ntz_variant_array,
+        arrow::array::TimestampMicrosecondArray::from(vec![
+            Some(-456000),
+            Some(1758602096000001),
+            Some(1758602096000002),
         ])
-        .with_precision_and_scale(7, 3)
-        .unwrap()
     );
 
-    perfectly_shredded_variant_array_fn!(
-        perfectly_shredded_decimal16_within_decimal8_variant_array,
-        || {
-            Decimal128Array::from(vec![Some(1234567809), Some(1456787000), Some(-1234561203)])
-                .with_precision_and_scale(10, 1)
-                .unwrap()
-        }
+    // test converting micro to nano
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_timestamp_micro_ntz_as_nano_ntz,
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        perfectly_shredded_timestamp_micro_ntz_variant_array,
+        arrow::array::TimestampNanosecondArray::from(vec![
+            Some(-456000000),
+            Some(1758602096000001000),
+            Some(1758602096000002000)
+        ])
     );
 
-    // This tests will covert the logic DataType::Decimal128(the original array)
-    // -> Variant::Decimal8(VariantArray) -> DataType::Decimal128(the result array)
-    perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_decimal16_within8_as_decimal16,
-        DataType::Decimal128(10, 1),
-        perfectly_shredded_decimal16_within_decimal8_variant_array,
-        Decimal128Array::from(vec![Some(1234567809), Some(1456787000), Some(-1234561203)])
-            .with_precision_and_scale(10, 1)
-            .unwrap()
-    );
-
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_decimal16_variant_array, || {
-        Decimal128Array::from(vec![
-            Some(i128::from_str("12345678901234567899").unwrap()),
-            Some(i128::from_str("23445677483748324300").unwrap()),
-            Some(i128::from_str("-12345678901234567899").unwrap()),
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_timestamp_micro_variant_array, || {
+        arrow::array::TimestampMicrosecondArray::from(vec![
+            Some(-456000),
+            Some(1758602096000001),
+            Some(1758602096000002),
         ])
-        .with_precision_and_scale(20, 3)
-        .unwrap()
+        .with_timezone("+00:00")
     });
 
-    // This tests will covert the logic DataType::Decimal128(the original array)
-    // -> Variant::Decimal16(VariantArray) -> DataType::Decimal128(the result array)
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_decimal16_as_decimal16,
-        DataType::Decimal128(20, 3),
-        perfectly_shredded_decimal16_variant_array,
-        Decimal128Array::from(vec![
-            Some(i128::from_str("12345678901234567899").unwrap()),
-            Some(i128::from_str("23445677483748324300").unwrap()),
-            Some(i128::from_str("-12345678901234567899").unwrap())
+        get_variant_perfectly_shredded_timestamp_micro_as_timestamp_micro,
+        DataType::Timestamp(TimeUnit::Microsecond, Some(Arc::from("+00:00"))),
+        perfectly_shredded_timestamp_micro_variant_array,
+        arrow::array::TimestampMicrosecondArray::from(vec![
+            Some(-456000),
+            Some(1758602096000001),
+            Some(1758602096000002),
         ])
-        .with_precision_and_scale(20, 3)
-        .unwrap()
+        .with_timezone("+00:00")
     );
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_binary_variant_array, || {
-        BinaryArray::from(vec![
-            Some(b"Apache" as &[u8]),
-            Some(b"Arrow-rs" as &[u8]),
-            Some(b"Parquet-variant" as &[u8]),
-        ])
-    });
-
+    // test converting micro to nano
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_binary_as_binary,
-        DataType::Binary,
-        perfectly_shredded_binary_variant_array,
-        BinaryArray::from(vec![
-            Some(b"Apache" as &[u8]),
-            Some(b"Arrow-rs" as &[u8]),
-            Some(b"Parquet-variant" as &[u8]),
+        get_variant_perfectly_shredded_timestamp_micro_as_nano,
+        DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("+00:00"))),
+        perfectly_shredded_timestamp_micro_variant_array,
+        arrow::array::TimestampNanosecondArray::from(vec![
+            Some(-456000000),
+            Some(1758602096000001000),
+            Some(1758602096000002000)
         ])
+        .with_timezone("+00:00")
     );
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_large_binary_variant_array, || {
-        LargeBinaryArray::from(vec![
-            Some(b"Apache" as &[u8]),
-            Some(b"Arrow-rs" as &[u8]),
-            Some(b"Parquet-variant" as &[u8]),
+    perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_timestamp_nano_ntz_variant_array,
+        || {
+            arrow::array::TimestampNanosecondArray::from(vec![
+                Some(-4999999561),
+                Some(1758602096000000001),
+                Some(1758602096000000002),
+            ])
+        }
+    );
+
+    perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_timestamp_micro_variant_array_for_second_and_milli_second,
+        || {
+            arrow::array::TimestampMicrosecondArray::from(vec![
+                Some(1234),       // can't be cast to second & millisecond
+                Some(1234000),    // can be cast to millisecond, but not second
+                Some(1234000000), // can be cast to second & millisecond
+            ])
+            .with_timezone("+00:00")
+        }
+    );
+
+    // The following two tests wants to cover the micro with timezone -> milli/second cases
+    // there are three test items, which contains some items can be cast safely, and some can't
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_timestamp_micro_as_timestamp_second,
+        DataType::Timestamp(TimeUnit::Second, Some(Arc::from("+00:00"))),
+        perfectly_shredded_timestamp_micro_variant_array_for_second_and_milli_second,
+        arrow::array::TimestampSecondArray::from(vec![
+            None,
+            None, // Return None if can't be cast to second safely
+            Some(1234)
         ])
-    });
+        .with_timezone("+00:00")
+    );
 
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_large_binary_as_large_binary,
-        DataType::LargeBinary,
-        perfectly_shredded_large_binary_variant_array,
-        LargeBinaryArray::from(vec![
-            Some(b"Apache" as &[u8]),
-            Some(b"Arrow-rs" as &[u8]),
-            Some(b"Parquet-variant" as &[u8]),
+        get_variant_perfectly_shredded_timestamp_micro_as_timestamp_milli,
+        DataType::Timestamp(TimeUnit::Millisecond, Some(Arc::from("+00:00"))),
+        perfectly_shredded_timestamp_micro_variant_array_for_second_and_milli_second,
+        arrow::array::TimestampMillisecondArray::from(vec![
+            None, // Return None if can't be cast to millisecond safely
+            Some(1234),
+            Some(1234000)
         ])
+        .with_timezone("+00:00")
     );
 
-    perfectly_shredded_variant_array_fn!(perfectly_shredded_binary_view_variant_array, || {
-        BinaryViewArray::from(vec![
-            Some(b"Apache" as &[u8]),
-            Some(b"Arrow-rs" as &[u8]),
-            Some(b"Parquet-variant" as &[u8]),
+    perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_timestamp_micro_ntz_variant_array_for_second_and_milli_second,
+        || {
+            arrow::array::TimestampMicrosecondArray::from(vec![
+                Some(1234),       // can't be cast to second & millisecond
+                Some(1234000),    // can be cast to millisecond, but not second
+                Some(1234000000), // can be cast to second & millisecond
+            ])
+        }
+    );
+
+    // The following two tests wants to cover the micro_ntz -> milli/second cases
+    // there are three test items, which contains some items can be cast safely, and some can't
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_timestamp_micro_ntz_as_timestamp_second,
+        DataType::Timestamp(TimeUnit::Second, None),
+        perfectly_shredded_timestamp_micro_ntz_variant_array_for_second_and_milli_second,
+        arrow::array::TimestampSecondArray::from(vec![
+            None,
+            None, // Return None if can't be cast to second safely
+            Some(1234)
         ])
-    });
+    );
 
     perfectly_shredded_to_arrow_primitive_test!(
-        get_variant_perfectly_shredded_binary_view_as_binary_view,
-        DataType::BinaryView,
-        perfectly_shredded_binary_view_variant_array,
-        BinaryViewArray::from(vec![
-            Some(b"Apache" as &[u8]),
-            Some(b"Arrow-rs" as &[u8]),
-            Some(b"Parquet-variant" as &[u8]),
+        get_variant_perfectly_shredded_timestamp_micro_ntz_as_timestamp_milli,
+        DataType::Timestamp(TimeUnit::Millisecond, None),
+        perfectly_shredded_timestamp_micro_ntz_variant_array_for_second_and_milli_second,
+        arrow::array::TimestampMillisecondArray::from(vec![
+            None, // Return None if can't be cast to millisecond safely
+            Some(1234),
+            Some(1234000)
         ])
     );
 
-    /// Return a VariantArray that represents an "all null" variant
-    /// for the following example (3 null values):
-    ///
-    /// ```text
-    /// null
-    /// null
-    /// null
-    /// ```
-    ///
-    /// The schema of the corresponding `StructArray` would look like this:
-    ///
-    /// ```text
-    /// StructArray {
-    ///   metadata: BinaryViewArray,
-    /// }
-    /// ```
-    fn all_null_variant_array() -> ArrayRef {
-        let nulls = NullBuffer::from(vec![
-            false, // row 0 is null
-            false, // row 1 is null
-            false, // row 2 is null
-        ]);
+    perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_timestamp_nano_variant_array_for_second_and_milli_second,
+        || {
+            arrow::array::TimestampNanosecondArray::from(vec![
+                Some(1234000),       // can't be cast to second & millisecond
+                Some(1234000000),    // can be cast to millisecond, but not second
+                Some(1234000000000), // can be cast to second & millisecond
+            ])
+            .with_timezone("+00:00")
+        }
+    );
 
-        // metadata is the same for all rows (though they're all null)
-        let metadata =
-            BinaryViewArray::from_iter_values(std::iter::repeat_n(EMPTY_VARIANT_METADATA_BYTES, 3));
+    // The following two tests wants to cover the nano with timezone -> milli/second cases
+    // there are three test items, which contains some items can be cast safely, and some can't
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_timestamp_nano_as_timestamp_second,
+        DataType::Timestamp(TimeUnit::Second, Some(Arc::from("+00:00"))),
+        perfectly_shredded_timestamp_nano_variant_array_for_second_and_milli_second,
+        arrow::array::TimestampSecondArray::from(vec![
+            None,
+            None, // Return None if can't be cast to second safely
+            Some(1234)
+        ])
+        .with_timezone("+00:00")
+    );
 
-        ArrayRef::from(VariantArray::from_parts(metadata, None, None, Some(nulls)))
-    }
-    /// This test manually constructs a shredded variant array representing objects
-    /// like {"x": 1, "y": "foo"} and {"x": 42} and tests extracting the "x" field
-    /// as VariantArray using variant_get.
-    #[test]
-    fn test_shredded_object_field_access() {
-        let array = shredded_object_with_x_field_variant_array();
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_timestamp_nano_as_timestamp_milli,
+        DataType::Timestamp(TimeUnit::Millisecond, Some(Arc::from("+00:00"))),
+        perfectly_shredded_timestamp_nano_variant_array_for_second_and_milli_second,
+        arrow::array::TimestampMillisecondArray::from(vec![
+            None, // Return None if can't be cast to millisecond safely
+            Some(1234),
+            Some(1234000)
+        ])
+        .with_timezone("+00:00")
+    );
 
-        // Test: Extract the "x" field as VariantArray first
-        let options = GetOptions::new_with_path(VariantPath::try_from("x").unwrap());
-        let result = variant_get(&array, options).unwrap();
+    perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_timestamp_nano_ntz_variant_array_for_second_and_milli_second,
+        || {
+            arrow::array::TimestampNanosecondArray::from(vec![
+                Some(1234000),       // can't be cast to second & millisecond
+                Some(1234000000),    // can be cast to millisecond, but not second
+                Some(1234000000000), // can be cast to second & millisecond
+            ])
+        }
+    );
 
-        let result_variant = VariantArray::try_new(&result).unwrap();
-        assert_eq!(result_variant.len(), 2);
+    // The following two tests wants to cover the nano_ntz -> milli/second cases
+    // there are three test items, which contains some items can be cast safely, and some can't
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_timestamp_nano_ntz_as_timestamp_second,
+        DataType::Timestamp(TimeUnit::Second, None),
+        perfectly_shredded_timestamp_nano_ntz_variant_array_for_second_and_milli_second,
+        arrow::array::TimestampSecondArray::from(vec![
+            None,
+            None, // Return None if can't be cast to second safely
+            Some(1234)
+        ])
+    );
 
-        // Row 0: expect x=1
-        assert_eq!(result_variant.value(0), Variant::Int32(1));
-        // Row 1: expect x=42
-        assert_eq!(result_variant.value(1), Variant::Int32(42));
-    }
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_timestamp_nano_ntz_as_timestamp_milli,
+        DataType::Timestamp(TimeUnit::Millisecond, None),
+        perfectly_shredded_timestamp_nano_ntz_variant_array_for_second_and_milli_second,
+        arrow::array::TimestampMillisecondArray::from(vec![
+            None, // Return None if can't be cast to millisecond safely
+            Some(1234),
+            Some(1234000)
+        ])
+    );
 
-    /// Test extracting shredded object field with type conversion
-    #[test]
-    fn test_shredded_object_field_as_int32() {
-        let array = shredded_object_with_x_field_variant_array();
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_timestamp_nano_ntz_as_timestamp_nano_ntz,
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        perfectly_shredded_timestamp_nano_ntz_variant_array,
+        arrow::array::TimestampNanosecondArray::from(vec![
+            Some(-4999999561),
+            Some(1758602096000000001),
+            Some(1758602096000000002),
+        ])
+    );
 
-        // Test: Extract the "x" field as Int32Array (type conversion)
-        let field = Field::new("x", DataType::Int32, false);
-        let options = GetOptions::new_with_path(VariantPath::try_from("x").unwrap())
-            .with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&array, options).unwrap();
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_timestamp_nano_variant_array, || {
+        arrow::array::TimestampNanosecondArray::from(vec![
+            Some(-4999999561),
+            Some(1758602096000000001),
+            Some(1758602096000000002),
+        ])
+        .with_timezone("+00:00")
+    });
 
-        // Should get Int32Array
-        let expected: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), Some(42)]));
-        assert_eq!(&result, &expected);
-    }
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_timestamp_nano_as_timestamp_nano,
+        DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("+00:00"))),
+        perfectly_shredded_timestamp_nano_variant_array,
+        arrow::array::TimestampNanosecondArray::from(vec![
+            Some(-4999999561),
+            Some(1758602096000000001),
+            Some(1758602096000000002),
+        ])
+        .with_timezone("+00:00")
+    );
 
-    /// Helper function to create a shredded variant array representing objects
-    ///
-    /// This creates an array that represents:
-    /// Row 0: {"x": 1, "y": "foo"}  (x is shredded, y is in value field)
-    /// Row 1: {"x": 42}             (x is shredded, perfect shredding)
-    ///
-    /// The physical layout follows the shredding spec where:
-    /// - metadata: contains object metadata
-    /// - typed_value: StructArray with field "x" (ShreddedVariantFieldArray)
-    /// - value: contains fallback for unshredded fields like {"y": "foo"}
-    /// - The "x" field has typed_value=Int32Array and value=NULL (perfect shredding)
-    fn shredded_object_with_x_field_variant_array() -> ArrayRef {
-        // Create the base metadata for objects
-        let (metadata, y_field_value) = {
-            let mut builder = parquet_variant::VariantBuilder::new();
-            let mut obj = builder.new_object();
-            obj.insert("x", Variant::Int32(42));
-            obj.insert("y", Variant::from("foo"));
-            obj.finish();
-            builder.finish()
-        };
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_date_variant_array, || {
+        Date32Array::from(vec![Some(-12345), Some(17586), Some(20000)])
+    });
 
-        // Create metadata array (same for both rows)
-        let metadata_array = BinaryViewArray::from_iter_values(std::iter::repeat_n(&metadata, 2));
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_date_as_date,
+        DataType::Date32,
+        perfectly_shredded_date_variant_array,
+        Date32Array::from(vec![Some(-12345), Some(17586), Some(20000)])
+    );
 
-        // Create the main value field per the 3-step shredding spec:
-        // Step 2: If field not in shredding schema, check value field
-        // Row 0: {"y": "foo"} (y is not shredded, stays in value for step 2)
-        // Row 1: {} (empty object - no unshredded fields)
-        let empty_object_value = {
-            let mut builder = parquet_variant::VariantBuilder::new();
-            let obj = builder.new_object();
-            obj.finish();
-            let (_, value) = builder.finish();
-            value
-        };
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_date_as_date64,
+        DataType::Date64,
+        perfectly_shredded_date_variant_array,
+        Date64Array::from(vec![
+            Some(-1066608000000),
+            Some(1519430400000),
+            Some(1728000000000)
+        ])
+    );
 
-        let value_array = BinaryViewArray::from(vec![
-            Some(y_field_value.as_slice()),      // Row 0 has {"y": "foo"}
-            Some(empty_object_value.as_slice()), // Row 1 has {}
-        ]);
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_time_variant_array, || {
+        Time64MicrosecondArray::from(vec![Some(12345000), Some(87654000), Some(135792000)])
+    });
 
-        // Create the "x" field as a ShreddedVariantFieldArray
-        // This represents the shredded Int32 values for the "x" field
-        let x_field_typed_value = Int32Array::from(vec![Some(1), Some(42)]);
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_time_as_time,
+        DataType::Time64(TimeUnit::Microsecond),
+        perfectly_shredded_time_variant_array,
+        Time64MicrosecondArray::from(vec![Some(12345000), Some(87654000), Some(135792000)])
+    );
 
-        // For perfect shredding of the x field, no "value" column, only typed_value
-        let x_field_shredded = ShreddedVariantFieldArray::from_parts(
-            None,
-            Some(Arc::new(x_field_typed_value) as ArrayRef),
-            None,
-        );
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_time_as_time64_nano,
+        DataType::Time64(TimeUnit::Nanosecond),
+        perfectly_shredded_time_variant_array,
+        Time64NanosecondArray::from(vec![
+            Some(12345000000),
+            Some(87654000000),
+            Some(135792000000)
+        ])
+    );
 
-        // Create the main typed_value as a struct containing the "x" field
-        let typed_value_fields = Fields::from(vec![Field::new(
-            "x",
-            x_field_shredded.data_type().clone(),
-            true,
-        )]);
-        let typed_value_struct = StructArray::try_new(
-            typed_value_fields,
-            vec![ArrayRef::from(x_field_shredded)],
-            None, // No nulls - both rows have the object structure
-        )
-        .unwrap();
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_time_variant_array_for_time32, || {
+        Time64MicrosecondArray::from(vec![
+            Some(1234),        // This can't be cast to Time32 losslessly
+            Some(7654000),     // This can be cast to Time32(Millisecond), but not Time32(Second)
+            Some(35792000000), // This can be cast to Time32(Second) & Time32(Millisecond)
+        ])
+    });
 
-        // Create the main VariantArray
-        ArrayRef::from(VariantArray::from_parts(
-            metadata_array,
-            Some(value_array),
-            Some(Arc::new(typed_value_struct)),
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_time_as_time32_second,
+        DataType::Time32(TimeUnit::Second),
+        perfectly_shredded_time_variant_array_for_time32,
+        Time32SecondArray::from(vec![
             None,
-        ))
-    }
+            None, // Return None if can't be cast to Time32(Second) safely
+            Some(35792)
+        ])
+    );
 
-    /// Simple test to check if nested paths are supported by current implementation
-    #[test]
-    fn test_simple_nested_path_support() {
-        // Check: How does VariantPath parse different strings?
-        println!("Testing path parsing:");
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_time_as_time32_milli,
+        DataType::Time32(TimeUnit::Millisecond),
+        perfectly_shredded_time_variant_array_for_time32,
+        Time32MillisecondArray::from(vec![
+            None, // Return None if can't be cast to Time32(Second) safely
+            Some(7654),
+            Some(35792000)
+        ])
+    );
 
-        let path_x = VariantPath::try_from("x").unwrap();
-        let elements_x: Vec<_> = path_x.iter().collect();
-        println!("  'x' -> {} elements: {:?}", elements_x.len(), elements_x);
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_null_variant_array, || {
+        let mut builder = NullBuilder::new();
+        builder.append_nulls(3);
+        builder.finish()
+    });
 
-        let path_ax = VariantPath::try_from("a.x").unwrap();
-        let elements_ax: Vec<_> = path_ax.iter().collect();
-        println!(
-            "  'a.x' -> {} elements: {:?}",
-            elements_ax.len(),
-            elements_ax
-        );
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_null_as_null,
+        DataType::Null,
+        perfectly_shredded_null_variant_array,
+        arrow::array::NullArray::new(3)
+    );
 
-        let path_ax_alt = VariantPath::try_from("$.a.x").unwrap();
-        let elements_ax_alt: Vec<_> = path_ax_alt.iter().collect();
-        println!(
-            "  '$.a.x' -> {} elements: {:?}",
-            elements_ax_alt.len(),
-            elements_ax_alt
-        );
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_null_variant_array_with_int, || {
+        Int32Array::from(vec![Some(32), Some(64), Some(48)])
+    });
 
-        let path_nested = VariantPath::try_from("a").unwrap().join("x");
-        let elements_nested: Vec<_> = path_nested.iter().collect();
-        println!(
-            "  VariantPath::try_from('a').unwrap().join('x') -> {} elements: {:?}",
-            elements_nested.len(),
-            elements_nested
-        );
+    // We append null values if type miss match happens in safe mode
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_null_with_type_missmatch_in_safe_mode,
+        DataType::Null,
+        perfectly_shredded_null_variant_array_with_int,
+        arrow::array::NullArray::new(3)
+    );
 
-        // Use your existing simple test data but try "a.x" instead of "x"
-        let array = shredded_object_with_x_field_variant_array();
+    // We'll return an error if type miss match happens in strict mode
+    #[test]
+    fn get_variant_perfectly_shredded_null_as_null_with_type_missmatch_in_strict_mode() {
+        let array = perfectly_shredded_null_variant_array_with_int();
+        let field = Field::new("typed_value", DataType::Null, true);
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(CastOptions {
+                safe: false,
+                format_options: FormatOptions::default(),
+            });
 
-        // Test if variant_get with REAL nested path throws not implemented error
-        let real_nested_path = VariantPath::try_from("a").unwrap().join("x");
-        let options = GetOptions::new_with_path(real_nested_path);
         let result = variant_get(&array, options);
 
-        match result {
-            Ok(_) => {
-                println!("Nested path 'a.x' works unexpectedly!");
-            }
-            Err(e) => {
-                println!("Nested path 'a.x' error: {}", e);
-                if e.to_string().contains("Not yet implemented")
-                    || e.to_string().contains("NotYetImplemented")
-                {
-                    println!("This is expected - nested paths are not implemented");
-                    return;
-                }
-                // Any other error is also expected for now
-                println!("This shows nested paths need implementation");
-            }
-        }
+        assert!(result.is_err());
+        let error_msg = format!("{}", result.unwrap_err());
+        assert!(
+            error_msg
+                .contains("Cast error: row 0: Failed to extract primitive of type Null from variant Int32(32) at path $"),
+            "Expected=[Cast error: row 0: Failed to extract primitive of type Null from variant Int32(32) at path $],\
+                Got error message=[{}]",
+            error_msg
+        );
     }
 
-    /// Test comprehensive variant_get scenarios with Int32 conversion
-    /// Test depth 0: Direct field access "x" with Int32 conversion
-    /// Covers shredded vs non-shredded VariantArrays for simple field access
-    #[test]
-    fn test_depth_0_int32_conversion() {
-        println!("=== Testing Depth 0: Direct field access ===");
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_decimal4_variant_array, || {
+        Decimal32Array::from(vec![Some(12345), Some(23400), Some(-12342)])
+            .with_precision_and_scale(5, 2)
+            .unwrap()
+    });
 
-        // Non-shredded test data: [{"x": 42}, {"x": "foo"}, {"y": 10}]
-        let unshredded_array = create_depth_0_test_data();
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_decimal4_as_decimal4,
+        DataType::Decimal32(5, 2),
+        perfectly_shredded_decimal4_variant_array,
+        Decimal32Array::from(vec![Some(12345), Some(23400), Some(-12342)])
+            .with_precision_and_scale(5, 2)
+            .unwrap()
+    );
 
-        let field = Field::new("result", DataType::Int32, true);
-        let path = VariantPath::try_from("x").unwrap();
-        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&unshredded_array, options).unwrap();
+    perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_decimal8_variant_array_cast2decimal32,
+        || {
+            Decimal64Array::from(vec![Some(123456), Some(145678), Some(-123456)])
+                .with_precision_and_scale(6, 1)
+                .unwrap()
+        }
+    );
 
-        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
-            Some(42), // {"x": 42} -> 42
-            None,     // {"x": "foo"} -> NULL (type mismatch)
-            None,     // {"y": 10} -> NULL (field missing)
-        ]));
-        assert_eq!(&result, &expected);
-        println!("Depth 0 (unshredded) passed");
+    // The input will be cast to Decimal32 when transformed to Variant
+    // This tests will covert the logic DataType::Decimal64(the original array)
+    // -> Variant::Decimal4(VariantArray) -> DataType::Decimal64(the result array)
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_decimal8_through_decimal32_as_decimal8,
+        DataType::Decimal64(6, 1),
+        perfectly_shredded_decimal8_variant_array_cast2decimal32,
+        Decimal64Array::from(vec![Some(123456), Some(145678), Some(-123456)])
+            .with_precision_and_scale(6, 1)
+            .unwrap()
+    );
 
-        // Shredded test data: using simplified approach based on working pattern
-        let shredded_array = create_depth_0_shredded_test_data_simple();
+    // This tests will covert the logic DataType::Decimal64(the original array)
+    //  -> Variant::Decimal8(VariantArray) -> DataType::Decimal64(the result array)
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_decimal8_variant_array, || {
+        Decimal64Array::from(vec![Some(1234567809), Some(1456787000), Some(-1234561203)])
+            .with_precision_and_scale(10, 1)
+            .unwrap()
+    });
 
-        let field = Field::new("result", DataType::Int32, true);
-        let path = VariantPath::try_from("x").unwrap();
-        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&shredded_array, options).unwrap();
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_decimal8_as_decimal8,
+        DataType::Decimal64(10, 1),
+        perfectly_shredded_decimal8_variant_array,
+        Decimal64Array::from(vec![Some(1234567809), Some(1456787000), Some(-1234561203)])
+            .with_precision_and_scale(10, 1)
+            .unwrap()
+    );
 
-        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
-            Some(42), // {"x": 42} -> 42 (from typed_value)
-            None,     // {"x": "foo"} -> NULL (type mismatch, from value field)
-        ]));
-        assert_eq!(&result, &expected);
-        println!("Depth 0 (shredded) passed");
-    }
+    // This tests will covert the logic DataType::Decimal128(the original array)
+    //  -> Variant::Decimal4(VariantArray) -> DataType::Decimal128(the result array)
+    perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_decimal16_within_decimal4_variant_array,
+        || {
+            Decimal128Array::from(vec![
+                Some(i128::from(1234589)),
+                Some(i128::from(2344444)),
+                Some(i128::from(-1234789)),
+            ])
+            .with_precision_and_scale(7, 3)
+            .unwrap()
+        }
+    );
 
-    /// Test depth 1: Single nested field access "a.x" with Int32 conversion
-    /// Covers shredded vs non-shredded VariantArrays for nested field access
-    #[test]
-    fn test_depth_1_int32_conversion() {
-        println!("=== Testing Depth 1: Single nested field access ===");
+    // This tests will covert the logic DataType::Decimal128(the original array)
+    // -> Variant::Decimal4(VariantArray) -> DataType::Decimal128(the result array)
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_decimal16_within_decimal4_as_decimal16,
+        DataType::Decimal128(7, 3),
+        perfectly_shredded_decimal16_within_decimal4_variant_array,
+        Decimal128Array::from(vec![
+            Some(i128::from(1234589)),
+            Some(i128::from(2344444)),
+            Some(i128::from(-1234789)),
+        ])
+        .with_precision_and_scale(7, 3)
+        .unwrap()
+    );
 
-        // Non-shredded test data from the GitHub issue
-        let unshredded_array = create_nested_path_test_data();
+    perfectly_shredded_variant_array_fn!(
+        perfectly_shredded_decimal16_within_decimal8_variant_array,
+        || {
+            Decimal128Array::from(vec![Some(1234567809), Some(1456787000), Some(-1234561203)])
+                .with_precision_and_scale(10, 1)
+                .unwrap()
+        }
+    );
 
-        let field = Field::new("result", DataType::Int32, true);
-        let path = VariantPath::try_from("a.x").unwrap(); // Dot notation!
-        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&unshredded_array, options).unwrap();
+    // This tests will covert the logic DataType::Decimal128(the original array)
+    // -> Variant::Decimal8(VariantArray) -> DataType::Decimal128(the result array)
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_decimal16_within8_as_decimal16,
+        DataType::Decimal128(10, 1),
+        perfectly_shredded_decimal16_within_decimal8_variant_array,
+        Decimal128Array::from(vec![Some(1234567809), Some(1456787000), Some(-1234561203)])
+            .with_precision_and_scale(10, 1)
+            .unwrap()
+    );
 
-        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
-            Some(55), // {"a": {"x": 55}} -> 55
-            None,     // {"a": {"x": "foo"}} -> NULL (type mismatch)
-        ]));
-        assert_eq!(&result, &expected);
-        println!("Depth 1 (unshredded) passed");
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_decimal16_variant_array, || {
+        Decimal128Array::from(vec![
+            Some(i128::from_str("12345678901234567899").unwrap()),
+            Some(i128::from_str("23445677483748324300").unwrap()),
+            Some(i128::from_str("-12345678901234567899").unwrap()),
+        ])
+        .with_precision_and_scale(20, 3)
+        .unwrap()
+    });
 
-        // Shredded test data: depth 1 nested shredding
-        let shredded_array = create_depth_1_shredded_test_data_working();
+    // This tests will covert the logic DataType::Decimal128(the original array)
+    // -> Variant::Decimal16(VariantArray) -> DataType::Decimal128(the result array)
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_decimal16_as_decimal16,
+        DataType::Decimal128(20, 3),
+        perfectly_shredded_decimal16_variant_array,
+        Decimal128Array::from(vec![
+            Some(i128::from_str("12345678901234567899").unwrap()),
+            Some(i128::from_str("23445677483748324300").unwrap()),
+            Some(i128::from_str("-12345678901234567899").unwrap())
+        ])
+        .with_precision_and_scale(20, 3)
+        .unwrap()
+    );
 
-        let field = Field::new("result", DataType::Int32, true);
-        let path = VariantPath::try_from("a.x").unwrap(); // Dot notation!
-        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&shredded_array, options).unwrap();
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_binary_variant_array, || {
+        BinaryArray::from(vec![
+            Some(b"Apache" as &[u8]),
+            Some(b"Arrow-rs" as &[u8]),
+            Some(b"Parquet-variant" as &[u8]),
+        ])
+    });
 
-        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
-            Some(55), // {"a": {"x": 55}} -> 55 (from nested shredded x)
-            None,     // {"a": {"x": "foo"}} -> NULL (type mismatch in nested value)
-        ]));
-        assert_eq!(&result, &expected);
-        println!("Depth 1 (shredded) passed");
-    }
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_binary_as_binary,
+        DataType::Binary,
+        perfectly_shredded_binary_variant_array,
+        BinaryArray::from(vec![
+            Some(b"Apache" as &[u8]),
+            Some(b"Arrow-rs" as &[u8]),
+            Some(b"Parquet-variant" as &[u8]),
+        ])
+    );
 
-    /// Test depth 2: Double nested field access "a.b.x" with Int32 conversion  
-    /// Covers shredded vs non-shredded VariantArrays for deeply nested field access
-    #[test]
-    fn test_depth_2_int32_conversion() {
-        println!("=== Testing Depth 2: Double nested field access ===");
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_large_binary_variant_array, || {
+        LargeBinaryArray::from(vec![
+            Some(b"Apache" as &[u8]),
+            Some(b"Arrow-rs" as &[u8]),
+            Some(b"Parquet-variant" as &[u8]),
+        ])
+    });
 
-        // Non-shredded test data: [{"a": {"b": {"x": 100}}}, {"a": {"b": {"x": "bar"}}}, {"a": {"b": {"y": 200}}}]
-        let unshredded_array = create_depth_2_test_data();
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_large_binary_as_large_binary,
+        DataType::LargeBinary,
+        perfectly_shredded_large_binary_variant_array,
+        LargeBinaryArray::from(vec![
+            Some(b"Apache" as &[u8]),
+            Some(b"Arrow-rs" as &[u8]),
+            Some(b"Parquet-variant" as &[u8]),
+        ])
+    );
 
-        let field = Field::new("result", DataType::Int32, true);
-        let path = VariantPath::try_from("a.b.x").unwrap(); // Double nested dot notation!
-        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&unshredded_array, options).unwrap();
+    perfectly_shredded_variant_array_fn!(perfectly_shredded_binary_view_variant_array, || {
+        BinaryViewArray::from(vec![
+            Some(b"Apache" as &[u8]),
+            Some(b"Arrow-rs" as &[u8]),
+            Some(b"Parquet-variant" as &[u8]),
+        ])
+    });
 
-        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
-            Some(100), // {"a": {"b": {"x": 100}}} -> 100
-            None,      // {"a": {"b": {"x": "bar"}}} -> NULL (type mismatch)
-            None,      // {"a": {"b": {"y": 200}}} -> NULL (field missing)
-        ]));
-        assert_eq!(&result, &expected);
-        println!("Depth 2 (unshredded) passed");
+    perfectly_shredded_to_arrow_primitive_test!(
+        get_variant_perfectly_shredded_binary_view_as_binary_view,
+        DataType::BinaryView,
+        perfectly_shredded_binary_view_variant_array,
+        BinaryViewArray::from(vec![
+            Some(b"Apache" as &[u8]),
+            Some(b"Arrow-rs" as &[u8]),
+            Some(b"Parquet-variant" as &[u8]),
+        ])
+    );
 
-        // Shredded test data: depth 2 nested shredding
-        let shredded_array = create_depth_2_shredded_test_data_working();
+    /// Return a VariantArray that represents an "all null" variant
+    /// for the following example (3 null values):
+    ///
+    /// ```text
+    /// null
+    /// null
+    /// null
+    /// ```
+    ///
+    /// The schema of the corresponding `StructArray` would look like this:
+    ///
+    /// ```text
+    /// StructArray {
+    ///   metadata: BinaryViewArray,
+    /// }
+    /// ```
+    fn all_null_variant_array() -> ArrayRef {
+        let nulls = NullBuffer::from(vec![
+            false, // row 0 is null
+            false, // row 1 is null
+            false, // row 2 is null
+        ]);
 
-        let field = Field::new("result", DataType::Int32, true);
-        let path = VariantPath::try_from("a.b.x").unwrap(); // Double nested dot notation!
-        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&shredded_array, options).unwrap();
+        // metadata is the same for all rows (though they're all null)
+        let metadata =
+            BinaryViewArray::from_iter_values(std::iter::repeat_n(EMPTY_VARIANT_METADATA_BYTES, 3));
 
-        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
-            Some(100), // {"a": {"b": {"x": 100}}} -> 100 (from deeply nested shredded x)
-            None,      // {"a": {"b": {"x": "bar"}}} -> NULL (type mismatch in deep value)
-            None,      // {"a": {"b": {"y": 200}}} -> NULL (field missing in deep structure)
-        ]));
-        assert_eq!(&result, &expected);
-        println!("Depth 2 (shredded) passed");
+        ArrayRef::from(VariantArray::from_parts(metadata, None, None, Some(nulls)))
     }
-
-    /// Test that demonstrates what CURRENTLY WORKS
-    ///
-    /// This shows that nested path functionality does work, but only when the
-    /// test data matches what the current implementation expects
+    /// This test manually constructs a shredded variant array representing objects
+    /// like {"x": 1, "y": "foo"} and {"x": 42} and tests extracting the "x" field
+    /// as VariantArray using variant_get.
     #[test]
-    fn test_current_nested_path_functionality() {
+    fn test_shredded_object_field_access() {
         let array = shredded_object_with_x_field_variant_array();
 
-        // Test: Extract the "x" field (single level) - this works
-        let single_path = VariantPath::try_from("x").unwrap();
-        let field = Field::new("result", DataType::Int32, true);
-        let options =
-            GetOptions::new_with_path(single_path).with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&array, options).unwrap();
-
-        println!("Single path 'x' works - result: {:?}", result);
-
-        // Test: Try nested path "a.x" - this is what we need to implement
-        let nested_path = VariantPath::try_from("a").unwrap().join("x");
-        let field = Field::new("result", DataType::Int32, true);
-        let options =
-            GetOptions::new_with_path(nested_path).with_as_type(Some(FieldRef::from(field)));
+        // Test: Extract the "x" field as VariantArray first
+        let options = GetOptions::new_with_path(VariantPath::try_from("x").unwrap());
         let result = variant_get(&array, options).unwrap();
 
-        println!("Nested path 'a.x' result: {:?}", result);
-    }
-
-    /// Create test data for depth 0 (direct field access)
-    /// [{"x": 42}, {"x": "foo"}, {"y": 10}]
-    fn create_depth_0_test_data() -> ArrayRef {
-        let mut builder = crate::VariantArrayBuilder::new(3);
-
-        // Row 1: {"x": 42}
-        {
-            let json_str = r#"{"x": 42}"#;
-            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
-            if let Ok(variant_array) = json_to_variant(&string_array) {
-                builder.append_variant(variant_array.value(0));
-            } else {
-                builder.append_null();
-            }
-        }
-
-        // Row 2: {"x": "foo"}
-        {
-            let json_str = r#"{"x": "foo"}"#;
-            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
-            if let Ok(variant_array) = json_to_variant(&string_array) {
-                builder.append_variant(variant_array.value(0));
-            } else {
-                builder.append_null();
-            }
-        }
-
-        // Row 3: {"y": 10} (missing "x" field)
-        {
-            let json_str = r#"{"y": 10}"#;
-            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
-            if let Ok(variant_array) = json_to_variant(&string_array) {
-                builder.append_variant(variant_array.value(0));
-            } else {
-                builder.append_null();
-            }
-        }
-
-        ArrayRef::from(builder.build())
-    }
-
-    /// Create test data for depth 1 (single nested field)
-    /// This represents the exact scenarios from the GitHub issue: "a.x"
-    fn create_nested_path_test_data() -> ArrayRef {
-        let mut builder = crate::VariantArrayBuilder::new(2);
-
-        // Row 1: {"a": {"x": 55}, "b": 42}
-        {
-            let json_str = r#"{"a": {"x": 55}, "b": 42}"#;
-            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
-            if let Ok(variant_array) = json_to_variant(&string_array) {
-                builder.append_variant(variant_array.value(0));
-            } else {
-                builder.append_null();
-            }
-        }
-
-        // Row 2: {"a": {"x": "foo"}, "b": 42}
-        {
-            let json_str = r#"{"a": {"x": "foo"}, "b": 42}"#;
-            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
-            if let Ok(variant_array) = json_to_variant(&string_array) {
-                builder.append_variant(variant_array.value(0));
-            } else {
-                builder.append_null();
-            }
-        }
+        let result_variant = VariantArray::try_new(&result).unwrap();
+        assert_eq!(result_variant.len(), 2);
 
-        ArrayRef::from(builder.build())
+        // Row 0: expect x=1
+        assert_eq!(result_variant.value(0), Variant::Int32(1));
+        // Row 1: expect x=42
+        assert_eq!(result_variant.value(1), Variant::Int32(42));
     }
 
-    /// Create test data for depth 2 (double nested field)
-    /// [{"a": {"b": {"x": 100}}}, {"a": {"b": {"x": "bar"}}}, {"a": {"b": {"y": 200}}}]
-    fn create_depth_2_test_data() -> ArrayRef {
-        let mut builder = crate::VariantArrayBuilder::new(3);
-
-        // Row 1: {"a": {"b": {"x": 100}}}
-        {
-            let json_str = r#"{"a": {"b": {"x": 100}}}"#;
-            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
-            if let Ok(variant_array) = json_to_variant(&string_array) {
-                builder.append_variant(variant_array.value(0));
-            } else {
-                builder.append_null();
-            }
-        }
-
-        // Row 2: {"a": {"b": {"x": "bar"}}}
-        {
-            let json_str = r#"{"a": {"b": {"x": "bar"}}}"#;
-            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
-            if let Ok(variant_array) = json_to_variant(&string_array) {
-                builder.append_variant(variant_array.value(0));
-            } else {
-                builder.append_null();
-            }
-        }
+    /// Test extracting shredded object field with type conversion
+    #[test]
+    fn test_shredded_object_field_as_int32() {
+        let array = shredded_object_with_x_field_variant_array();
 
-        // Row 3: {"a": {"b": {"y": 200}}} (missing "x" field)
-        {
-            let json_str = r#"{"a": {"b": {"y": 200}}}"#;
-            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
-            if let Ok(variant_array) = json_to_variant(&string_array) {
-                builder.append_variant(variant_array.value(0));
-            } else {
-                builder.append_null();
-            }
-        }
+        // Test: Extract the "x" field as Int32Array (type conversion)
+        let field = Field::new("x", DataType::Int32, false);
+        let options = GetOptions::new_with_path(VariantPath::try_from("x").unwrap())
+            .with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&array, options).unwrap();
 
-        ArrayRef::from(builder.build())
+        // Should get Int32Array
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), Some(42)]));
+        assert_eq!(&result, &expected);
     }
 
-    /// Create simple shredded test data for depth 0 using a simplified working pattern
-    /// Creates 2 rows: [{"x": 42}, {"x": "foo"}] with "x" shredded where possible
-    fn create_depth_0_shredded_test_data_simple() -> ArrayRef {
-        // Create base metadata using the working pattern
-        let (metadata, string_x_value) = {
+    /// Helper function to create a shredded variant array representing objects
+    ///
+    /// This creates an array that represents:
+    /// Row 0: {"x": 1, "y": "foo"}  (x is shredded, y is in value field)
+    /// Row 1: {"x": 42}             (x is shredded, perfect shredding)
+    ///
+    /// The physical layout follows the shredding spec where:
+    /// - metadata: contains object metadata
+    /// - typed_value: StructArray with field "x" (ShreddedVariantFieldArray)
+    /// - value: contains fallback for unshredded fields like {"y": "foo"}
+    /// - The "x" field has typed_value=Int32Array and value=NULL (perfect shredding)
+    fn shredded_object_with_x_field_variant_array() -> ArrayRef {
+        // Create the base metadata for objects
+        let (metadata, y_field_value) = {
             let mut builder = parquet_variant::VariantBuilder::new();
             let mut obj = builder.new_object();
-            obj.insert("x", Variant::from("foo"));
+            obj.insert("x", Variant::Int32(42));
+            obj.insert("y", Variant::from("foo"));
             obj.finish();
             builder.finish()
         };
 
-        // Metadata array (same for both rows)
+        // Create metadata array (same for both rows)
         let metadata_array = BinaryViewArray::from_iter_values(std::iter::repeat_n(&metadata, 2));
 
-        // Value array following the 3-step shredding spec:
-        // Row 0: {} (x is shredded, no unshredded fields)
-        // Row 1: {"x": "foo"} (x is a string, can't be shredded to Int32)
+        // Create the main value field per the 3-step shredding spec:
+        // Step 2: If field not in shredding schema, check value field
+        // Row 0: {"y": "foo"} (y is not shredded, stays in value for step 2)
+        // Row 1: {} (empty object - no unshredded fields)
         let empty_object_value = {
             let mut builder = parquet_variant::VariantBuilder::new();
             let obj = builder.new_object();
@@ -2177,14 +3974,15 @@ mod test {
         };
 
         let value_array = BinaryViewArray::from(vec![
-            Some(empty_object_value.as_slice()), // Row 0: {} (x shredded out)
-            Some(string_x_value.as_slice()),     // Row 1: {"x": "foo"} (fallback)
+            Some(y_field_value.as_slice()),      // Row 0 has {"y": "foo"}
+            Some(empty_object_value.as_slice()), // Row 1 has {}
         ]);
 
         // Create the "x" field as a ShreddedVariantFieldArray
-        let x_field_typed_value = Int32Array::from(vec![Some(42), None]);
+        // This represents the shredded Int32 values for the "x" field
+        let x_field_typed_value = Int32Array::from(vec![Some(1), Some(42)]);
 
-        // For the x field, only typed_value (perfect shredding when possible)
+        // For perfect shredding of the x field, no "value" column, only typed_value
         let x_field_shredded = ShreddedVariantFieldArray::from_parts(
             None,
             Some(Arc::new(x_field_typed_value) as ArrayRef),
@@ -2200,11 +3998,11 @@ mod test {
         let typed_value_struct = StructArray::try_new(
             typed_value_fields,
             vec![ArrayRef::from(x_field_shredded)],
-            None,
+            None, // No nulls - both rows have the object structure
         )
         .unwrap();
 
-        // Build final VariantArray
+        // Create the main VariantArray
         ArrayRef::from(VariantArray::from_parts(
             metadata_array,
             Some(value_array),
@@ -2213,235 +4011,370 @@ mod test {
         ))
     }
 
-    /// Create working depth 1 shredded test data based on the existing working pattern
-    /// This creates a properly structured shredded variant for "a.x" where:
-    /// - Row 0: {"a": {"x": 55}, "b": 42} with a.x shredded into typed_value
-    /// - Row 1: {"a": {"x": "foo"}, "b": 42} with a.x fallback to value field due to type mismatch
-    fn create_depth_1_shredded_test_data_working() -> ArrayRef {
-        // Create metadata following the working pattern from shredded_object_with_x_field_variant_array
-        let (metadata, _) = {
-            // Create nested structure: {"a": {"x": 55}, "b": 42}
-            let mut builder = parquet_variant::VariantBuilder::new();
-            let mut obj = builder.new_object();
+    /// Simple test to check if nested paths are supported by current implementation
+    #[test]
+    fn test_simple_nested_path_support() {
+        // Check: How does VariantPath parse different strings?
+        println!("Testing path parsing:");
 
-            // Create the nested "a" object
-            let mut a_obj = obj.new_object("a");
-            a_obj.insert("x", Variant::Int32(55));
-            a_obj.finish();
+        let path_x = VariantPath::try_from("x").unwrap();
+        let elements_x: Vec<_> = path_x.iter().collect();
+        println!("  'x' -> {} elements: {:?}", elements_x.len(), elements_x);
 
-            obj.insert("b", Variant::Int32(42));
-            obj.finish();
-            builder.finish()
-        };
+        let path_ax = VariantPath::try_from("a.x").unwrap();
+        let elements_ax: Vec<_> = path_ax.iter().collect();
+        println!(
+            "  'a.x' -> {} elements: {:?}",
+            elements_ax.len(),
+            elements_ax
+        );
 
-        let metadata_array = BinaryViewArray::from_iter_values(std::iter::repeat_n(&metadata, 2));
+        let path_ax_alt = VariantPath::try_from("$.a.x").unwrap();
+        let elements_ax_alt: Vec<_> = path_ax_alt.iter().collect();
+        println!(
+            "  '$.a.x' -> {} elements: {:?}",
+            elements_ax_alt.len(),
+            elements_ax_alt
+        );
 
-        // Create value arrays for the fallback case
-        // Following the spec: if field cannot be shredded, it stays in value
-        let empty_object_value = {
-            let mut builder = parquet_variant::VariantBuilder::new();
-            let obj = builder.new_object();
-            obj.finish();
-            let (_, value) = builder.finish();
-            value
-        };
+        let path_nested = VariantPath::try_from("a").unwrap().join("x");
+        let elements_nested: Vec<_> = path_nested.iter().collect();
+        println!(
+            "  VariantPath::try_from('a').unwrap().join('x') -> {} elements: {:?}",
+            elements_nested.len(),
+            elements_nested
+        );
 
-        // Row 1 fallback: use the working pattern from the existing shredded test
-        // This avoids metadata issues by using the simple fallback approach
-        let row1_fallback = {
-            let mut builder = parquet_variant::VariantBuilder::new();
-            let mut obj = builder.new_object();
-            obj.insert("fallback", Variant::from("data"));
-            obj.finish();
-            let (_, value) = builder.finish();
-            value
-        };
+        // Use your existing simple test data but try "a.x" instead of "x"
+        let array = shredded_object_with_x_field_variant_array();
 
-        let value_array = BinaryViewArray::from(vec![
-            Some(empty_object_value.as_slice()), // Row 0: {} (everything shredded except b in unshredded fields)
-            Some(row1_fallback.as_slice()), // Row 1: {"a": {"x": "foo"}, "b": 42} (a.x can't be shredded)
-        ]);
+        // Test if variant_get with REAL nested path throws not implemented error
+        let real_nested_path = VariantPath::try_from("a").unwrap().join("x");
+        let options = GetOptions::new_with_path(real_nested_path);
+        let result = variant_get(&array, options);
 
-        // Create the nested shredded structure
-        // Level 2: x field (the deepest level)
-        let x_typed_value = Int32Array::from(vec![Some(55), None]);
-        let x_field_shredded = ShreddedVariantFieldArray::from_parts(
-            None,
-            Some(Arc::new(x_typed_value) as ArrayRef),
-            None,
-        );
+        match result {
+            Ok(_) => {
+                println!("Nested path 'a.x' works unexpectedly!");
+            }
+            Err(e) => {
+                println!("Nested path 'a.x' error: {}", e);
+                if e.to_string().contains("Not yet implemented")
+                    || e.to_string().contains("NotYetImplemented")
+                {
+                    println!("This is expected - nested paths are not implemented");
+                    return;
+                }
+                // Any other error is also expected for now
+                println!("This shows nested paths need implementation");
+            }
+        }
+    }
 
-        // Level 1: a field containing x field + value field for fallbacks
-        // The "a" field needs both typed_value (for shredded x) and value (for fallback cases)
+    /// Test comprehensive variant_get scenarios with Int32 conversion
+    /// Test depth 0: Direct field access "x" with Int32 conversion
+    /// Covers shredded vs non-shredded VariantArrays for simple field access
+    #[test]
+    fn test_depth_0_int32_conversion() {
+        println!("=== Testing Depth 0: Direct field access ===");
 
-        // Create the value field for "a" (for cases where a.x can't be shredded)
-        let a_value_data = {
-            let mut builder = parquet_variant::VariantBuilder::new();
-            let obj = builder.new_object();
-            obj.finish();
-            let (_, value) = builder.finish();
-            value
-        };
-        let a_value_array = BinaryViewArray::from(vec![
-            None,                          // Row 0: x is shredded, so no value fallback needed
-            Some(a_value_data.as_slice()), // Row 1: fallback for a.x="foo" (but logic will check typed_value first)
-        ]);
+        // Non-shredded test data: [{"x": 42}, {"x": "foo"}, {"y": 10}]
+        let unshredded_array = create_depth_0_test_data();
 
-        let a_inner_fields = Fields::from(vec![Field::new(
-            "x",
-            x_field_shredded.data_type().clone(),
-            true,
-        )]);
-        let a_inner_typed_value = Arc::new(
-            StructArray::try_new(a_inner_fields, vec![ArrayRef::from(x_field_shredded)], None)
-                .unwrap(),
-        ) as ArrayRef;
-        let a_field_shredded = ShreddedVariantFieldArray::from_parts(
-            Some(a_value_array),
-            Some(a_inner_typed_value),
-            None,
-        );
+        let field = Field::new("result", DataType::Int32, true);
+        let path = VariantPath::try_from("x").unwrap();
+        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&unshredded_array, options).unwrap();
 
-        // Level 0: main typed_value struct containing a field
-        let typed_value_fields = Fields::from(vec![Field::new(
-            "a",
-            a_field_shredded.data_type().clone(),
-            true,
-        )]);
-        let typed_value_struct = StructArray::try_new(
-            typed_value_fields,
-            vec![ArrayRef::from(a_field_shredded)],
-            None,
-        )
-        .unwrap();
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(42), // {"x": 42} -> 42
+            None,     // {"x": "foo"} -> NULL (type mismatch)
+            None,     // {"y": 10} -> NULL (field missing)
+        ]));
+        assert_eq!(&result, &expected);
+        println!("Depth 0 (unshredded) passed");
 
-        // Build final VariantArray
-        ArrayRef::from(VariantArray::from_parts(
-            metadata_array,
-            Some(value_array),
-            Some(Arc::new(typed_value_struct)),
-            None,
-        ))
+        // Shredded test data: using simplified approach based on working pattern
+        let shredded_array = create_depth_0_shredded_test_data_simple();
+
+        let field = Field::new("result", DataType::Int32, true);
+        let path = VariantPath::try_from("x").unwrap();
+        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&shredded_array, options).unwrap();
+
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(42), // {"x": 42} -> 42 (from typed_value)
+            None,     // {"x": "foo"} -> NULL (type mismatch, from value field)
+        ]));
+        assert_eq!(&result, &expected);
+        println!("Depth 0 (shredded) passed");
     }
 
-    /// Create working depth 2 shredded test data for "a.b.x" paths
-    /// This creates a 3-level nested shredded structure where:
-    /// - Row 0: {"a": {"b": {"x": 100}}} with a.b.x shredded into typed_value
-    /// - Row 1: {"a": {"b": {"x": "bar"}}} with type mismatch fallback
-    /// - Row 2: {"a": {"b": {"y": 200}}} with missing field fallback
-    fn create_depth_2_shredded_test_data_working() -> ArrayRef {
-        // Create metadata following the working pattern
-        let (metadata, _) = {
-            // Create deeply nested structure: {"a": {"b": {"x": 100}}}
-            let mut builder = parquet_variant::VariantBuilder::new();
-            let mut obj = builder.new_object();
+    /// Test depth 1: Single nested field access "a.x" with Int32 conversion
+    /// Covers shredded vs non-shredded VariantArrays for nested field access
+    #[test]
+    fn test_depth_1_int32_conversion() {
+        println!("=== Testing Depth 1: Single nested field access ===");
 
-            // Create the nested "a.b" structure
-            let mut a_obj = obj.new_object("a");
-            let mut b_obj = a_obj.new_object("b");
-            b_obj.insert("x", Variant::Int32(100));
-            b_obj.finish();
-            a_obj.finish();
+        // Non-shredded test data from the GitHub issue
+        let unshredded_array = create_nested_path_test_data();
 
-            obj.finish();
-            builder.finish()
-        };
+        let field = Field::new("result", DataType::Int32, true);
+        let path = VariantPath::try_from("a.x").unwrap(); // Dot notation!
+        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&unshredded_array, options).unwrap();
 
-        let metadata_array = BinaryViewArray::from_iter_values(std::iter::repeat_n(&metadata, 3));
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(55), // {"a": {"x": 55}} -> 55
+            None,     // {"a": {"x": "foo"}} -> NULL (type mismatch)
+        ]));
+        assert_eq!(&result, &expected);
+        println!("Depth 1 (unshredded) passed");
 
-        // Create value arrays for fallback cases
-        let empty_object_value = {
-            let mut builder = parquet_variant::VariantBuilder::new();
-            let obj = builder.new_object();
-            obj.finish();
-            let (_, value) = builder.finish();
-            value
-        };
+        // Shredded test data: depth 1 nested shredding
+        let shredded_array = create_depth_1_shredded_test_data_working();
 
-        // Simple fallback values - avoiding complex nested metadata
-        let value_array = BinaryViewArray::from(vec![
-            Some(empty_object_value.as_slice()), // Row 0: fully shredded
-            Some(empty_object_value.as_slice()), // Row 1: fallback (simplified)
-            Some(empty_object_value.as_slice()), // Row 2: fallback (simplified)
-        ]);
+        let field = Field::new("result", DataType::Int32, true);
+        let path = VariantPath::try_from("a.x").unwrap(); // Dot notation!
+        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&shredded_array, options).unwrap();
 
-        // Create the deeply nested shredded structure: a.b.x
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(55), // {"a": {"x": 55}} -> 55 (from nested shredded x)
+            None,     // {"a": {"x": "foo"}} -> NULL (type mismatch in nested value)
+        ]));
+        assert_eq!(&result, &expected);
+        println!("Depth 1 (shredded) passed");
+    }
 
-        // Level 3: x field (deepest level)
-        let x_typed_value = Int32Array::from(vec![Some(100), None, None]);
-        let x_field_shredded = ShreddedVariantFieldArray::from_parts(
-            None,
-            Some(Arc::new(x_typed_value) as ArrayRef),
-            None,
-        );
+    /// Test depth 2: Double nested field access "a.b.x" with Int32 conversion  
+    /// Covers shredded vs non-shredded VariantArrays for deeply nested field access
+    #[test]
+    fn test_depth_2_int32_conversion() {
+        println!("=== Testing Depth 2: Double nested field access ===");
 
-        // Level 2: b field containing x field + value field
-        let b_value_data = {
-            let mut builder = parquet_variant::VariantBuilder::new();
-            let obj = builder.new_object();
-            obj.finish();
-            let (_, value) = builder.finish();
-            value
+        // Non-shredded test data: [{"a": {"b": {"x": 100}}}, {"a": {"b": {"x": "bar"}}}, {"a": {"b": {"y": 200}}}]
+        let unshredded_array = create_depth_2_test_data();
+
+        let field = Field::new("result", DataType::Int32, true);
+        let path = VariantPath::try_from("a.b.x").unwrap(); // Double nested dot notation!
+        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&unshredded_array, options).unwrap();
+
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(100), // {"a": {"b": {"x": 100}}} -> 100
+            None,      // {"a": {"b": {"x": "bar"}}} -> NULL (type mismatch)
+            None,      // {"a": {"b": {"y": 200}}} -> NULL (field missing)
+        ]));
+        assert_eq!(&result, &expected);
+        println!("Depth 2 (unshredded) passed");
+
+        // Shredded test data: depth 2 nested shredding
+        let shredded_array = create_depth_2_shredded_test_data_working();
+
+        let field = Field::new("result", DataType::Int32, true);
+        let path = VariantPath::try_from("a.b.x").unwrap(); // Double nested dot notation!
+        let options = GetOptions::new_with_path(path).with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&shredded_array, options).unwrap();
+
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(100), // {"a": {"b": {"x": 100}}} -> 100 (from deeply nested shredded x)
+            None,      // {"a": {"b": {"x": "bar"}}} -> NULL (type mismatch in deep value)
+            None,      // {"a": {"b": {"y": 200}}} -> NULL (field missing in deep structure)
+        ]));
+        assert_eq!(&result, &expected);
+        println!("Depth 2 (shredded) passed");
+    }
+
+    /// Test that demonstrates what CURRENTLY WORKS
+    ///
+    /// This shows that nested path functionality does work, but only when the
+    /// test data matches what the current implementation expects
+    #[test]
+    fn test_current_nested_path_functionality() {
+        let array = shredded_object_with_x_field_variant_array();
+
+        // Test: Extract the "x" field (single level) - this works
+        let single_path = VariantPath::try_from("x").unwrap();
+        let field = Field::new("result", DataType::Int32, true);
+        let options =
+            GetOptions::new_with_path(single_path).with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&array, options).unwrap();
+
+        println!("Single path 'x' works - result: {:?}", result);
+
+        // Test: Try nested path "a.x" - this is what we need to implement
+        let nested_path = VariantPath::try_from("a").unwrap().join("x");
+        let field = Field::new("result", DataType::Int32, true);
+        let options =
+            GetOptions::new_with_path(nested_path).with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&array, options).unwrap();
+
+        println!("Nested path 'a.x' result: {:?}", result);
+    }
+
+    /// Create test data for depth 0 (direct field access)
+    /// [{"x": 42}, {"x": "foo"}, {"y": 10}]
+    fn create_depth_0_test_data() -> ArrayRef {
+        let mut builder = crate::VariantArrayBuilder::new(3);
+
+        // Row 1: {"x": 42}
+        {
+            let json_str = r#"{"x": 42}"#;
+            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
+            if let Ok(variant_array) = json_to_variant(&string_array) {
+                builder.append_variant(variant_array.value(0));
+            } else {
+                builder.append_null();
+            }
+        }
+
+        // Row 2: {"x": "foo"}
+        {
+            let json_str = r#"{"x": "foo"}"#;
+            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
+            if let Ok(variant_array) = json_to_variant(&string_array) {
+                builder.append_variant(variant_array.value(0));
+            } else {
+                builder.append_null();
+            }
+        }
+
+        // Row 3: {"y": 10} (missing "x" field)
+        {
+            let json_str = r#"{"y": 10}"#;
+            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
+            if let Ok(variant_array) = json_to_variant(&string_array) {
+                builder.append_variant(variant_array.value(0));
+            } else {
+                builder.append_null();
+            }
+        }
+
+        ArrayRef::from(builder.build())
+    }
+
+    /// Create test data for depth 1 (single nested field)
+    /// This represents the exact scenarios from the GitHub issue: "a.x"
+    fn create_nested_path_test_data() -> ArrayRef {
+        let mut builder = crate::VariantArrayBuilder::new(2);
+
+        // Row 1: {"a": {"x": 55}, "b": 42}
+        {
+            let json_str = r#"{"a": {"x": 55}, "b": 42}"#;
+            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
+            if let Ok(variant_array) = json_to_variant(&string_array) {
+                builder.append_variant(variant_array.value(0));
+            } else {
+                builder.append_null();
+            }
+        }
+
+        // Row 2: {"a": {"x": "foo"}, "b": 42}
+        {
+            let json_str = r#"{"a": {"x": "foo"}, "b": 42}"#;
+            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
+            if let Ok(variant_array) = json_to_variant(&string_array) {
+                builder.append_variant(variant_array.value(0));
+            } else {
+                builder.append_null();
+            }
+        }
+
+        ArrayRef::from(builder.build())
+    }
+
+    /// Create test data for depth 2 (double nested field)
+    /// [{"a": {"b": {"x": 100}}}, {"a": {"b": {"x": "bar"}}}, {"a": {"b": {"y": 200}}}]
+    fn create_depth_2_test_data() -> ArrayRef {
+        let mut builder = crate::VariantArrayBuilder::new(3);
+
+        // Row 1: {"a": {"b": {"x": 100}}}
+        {
+            let json_str = r#"{"a": {"b": {"x": 100}}}"#;
+            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
+            if let Ok(variant_array) = json_to_variant(&string_array) {
+                builder.append_variant(variant_array.value(0));
+            } else {
+                builder.append_null();
+            }
+        }
+
+        // Row 2: {"a": {"b": {"x": "bar"}}}
+        {
+            let json_str = r#"{"a": {"b": {"x": "bar"}}}"#;
+            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
+            if let Ok(variant_array) = json_to_variant(&string_array) {
+                builder.append_variant(variant_array.value(0));
+            } else {
+                builder.append_null();
+            }
+        }
+
+        // Row 3: {"a": {"b": {"y": 200}}} (missing "x" field)
+        {
+            let json_str = r#"{"a": {"b": {"y": 200}}}"#;
+            let string_array: ArrayRef = Arc::new(StringArray::from(vec![json_str]));
+            if let Ok(variant_array) = json_to_variant(&string_array) {
+                builder.append_variant(variant_array.value(0));
+            } else {
+                builder.append_null();
+            }
+        }
+
+        ArrayRef::from(builder.build())
+    }
+
+    /// Create simple shredded test data for depth 0 using a simplified working pattern
+    /// Creates 2 rows: [{"x": 42}, {"x": "foo"}] with "x" shredded where possible
+    fn create_depth_0_shredded_test_data_simple() -> ArrayRef {
+        // Create base metadata using the working pattern
+        let (metadata, string_x_value) = {
+            let mut builder = parquet_variant::VariantBuilder::new();
+            let mut obj = builder.new_object();
+            obj.insert("x", Variant::from("foo"));
+            obj.finish();
+            builder.finish()
         };
-        let b_value_array = BinaryViewArray::from(vec![
-            None,                          // Row 0: x is shredded
-            Some(b_value_data.as_slice()), // Row 1: fallback for b.x="bar"
-            Some(b_value_data.as_slice()), // Row 2: fallback for b.y=200
-        ]);
 
-        let b_inner_fields = Fields::from(vec![Field::new(
-            "x",
-            x_field_shredded.data_type().clone(),
-            true,
-        )]);
-        let b_inner_typed_value = Arc::new(
-            StructArray::try_new(b_inner_fields, vec![ArrayRef::from(x_field_shredded)], None)
-                .unwrap(),
-        ) as ArrayRef;
-        let b_field_shredded = ShreddedVariantFieldArray::from_parts(
-            Some(b_value_array),
-            Some(b_inner_typed_value),
-            None,
-        );
+        // Metadata array (same for both rows)
+        let metadata_array = BinaryViewArray::from_iter_values(std::iter::repeat_n(&metadata, 2));
 
-        // Level 1: a field containing b field + value field
-        let a_value_data = {
+        // Value array following the 3-step shredding spec:
+        // Row 0: {} (x is shredded, no unshredded fields)
+        // Row 1: {"x": "foo"} (x is a string, can't be shredded to Int32)
+        let empty_object_value = {
             let mut builder = parquet_variant::VariantBuilder::new();
             let obj = builder.new_object();
             obj.finish();
             let (_, value) = builder.finish();
             value
         };
-        let a_value_array = BinaryViewArray::from(vec![
-            None,                          // Row 0: b is shredded
-            Some(a_value_data.as_slice()), // Row 1: fallback for a.b.*
-            Some(a_value_data.as_slice()), // Row 2: fallback for a.b.*
+
+        let value_array = BinaryViewArray::from(vec![
+            Some(empty_object_value.as_slice()), // Row 0: {} (x shredded out)
+            Some(string_x_value.as_slice()),     // Row 1: {"x": "foo"} (fallback)
         ]);
 
-        let a_inner_fields = Fields::from(vec![Field::new(
-            "b",
-            b_field_shredded.data_type().clone(),
-            true,
-        )]);
-        let a_inner_typed_value = Arc::new(
-            StructArray::try_new(a_inner_fields, vec![ArrayRef::from(b_field_shredded)], None)
-                .unwrap(),
-        ) as ArrayRef;
-        let a_field_shredded = ShreddedVariantFieldArray::from_parts(
-            Some(a_value_array),
-            Some(a_inner_typed_value),
+        // Create the "x" field as a ShreddedVariantFieldArray
+        let x_field_typed_value = Int32Array::from(vec![Some(42), None]);
+
+        // For the x field, only typed_value (perfect shredding when possible)
+        let x_field_shredded = ShreddedVariantFieldArray::from_parts(
+            None,
+            Some(Arc::new(x_field_typed_value) as ArrayRef),
             None,
         );
 
-        // Level 0: main typed_value struct containing a field
+        // Create the main typed_value as a struct containing the "x" field
         let typed_value_fields = Fields::from(vec![Field::new(
-            "a",
-            a_field_shredded.data_type().clone(),
+            "x",
+            x_field_shredded.data_type().clone(),
             true,
         )]);
         let typed_value_struct = StructArray::try_new(
             typed_value_fields,
-            vec![ArrayRef::from(a_field_shredded)],
+            vec![ArrayRef::from(x_field_shredded)],
             None,
         )
         .unwrap();
@@ -2455,22 +4388,265 @@ mod test {
         ))
     }
 
-    #[test]
-    fn test_strict_cast_options_downcast_failure() {
-        use arrow::compute::CastOptions;
-        use arrow::datatypes::{DataType, Field};
-        use arrow::error::ArrowError;
-        use parquet_variant::VariantPath;
-        use std::sync::Arc;
-
-        // Use the existing simple test data that has Int32 as typed_value
-        let variant_array = perfectly_shredded_int32_variant_array();
-
+    /// Create working depth 1 shredded test data based on the existing working pattern
+    /// This creates a properly structured shredded variant for "a.x" where:
+    /// - Row 0: {"a": {"x": 55}, "b": 42} with a.x shredded into typed_value
+    /// - Row 1: {"a": {"x": "foo"}, "b": 42} with a.x fallback to value field due to type mismatch
+    fn create_depth_1_shredded_test_data_working() -> ArrayRef {
+        // Create metadata following the working pattern from shredded_object_with_x_field_variant_array
+        let (metadata, _) = {
+            // Create nested structure: {"a": {"x": 55}, "b": 42}
+            let mut builder = parquet_variant::VariantBuilder::new();
+            let mut obj = builder.new_object();
+
+            // Create the nested "a" object
+            let mut a_obj = obj.new_object("a");
+            a_obj.insert("x", Variant::Int32(55));
+            a_obj.finish();
+
+            obj.insert("b", Variant::Int32(42));
+            obj.finish();
+            builder.finish()
+        };
+
+        let metadata_array = BinaryViewArray::from_iter_values(std::iter::repeat_n(&metadata, 2));
+
+        // Create value arrays for the fallback case
+        // Following the spec: if field cannot be shredded, it stays in value
+        let empty_object_value = {
+            let mut builder = parquet_variant::VariantBuilder::new();
+            let obj = builder.new_object();
+            obj.finish();
+            let (_, value) = builder.finish();
+            value
+        };
+
+        // Row 1 fallback: use the working pattern from the existing shredded test
+        // This avoids metadata issues by using the simple fallback approach
+        let row1_fallback = {
+            let mut builder = parquet_variant::VariantBuilder::new();
+            let mut obj = builder.new_object();
+            obj.insert("fallback", Variant::from("data"));
+            obj.finish();
+            let (_, value) = builder.finish();
+            value
+        };
+
+        let value_array = BinaryViewArray::from(vec![
+            Some(empty_object_value.as_slice()), // Row 0: {} (everything shredded except b in unshredded fields)
+            Some(row1_fallback.as_slice()), // Row 1: {"a": {"x": "foo"}, "b": 42} (a.x can't be shredded)
+        ]);
+
+        // Create the nested shredded structure
+        // Level 2: x field (the deepest level)
+        let x_typed_value = Int32Array::from(vec![Some(55), None]);
+        let x_field_shredded = ShreddedVariantFieldArray::from_parts(
+            None,
+            Some(Arc::new(x_typed_value) as ArrayRef),
+            None,
+        );
+
+        // Level 1: a field containing x field + value field for fallbacks
+        // The "a" field needs both typed_value (for shredded x) and value (for fallback cases)
+
+        // Create the value field for "a" (for cases where a.x can't be shredded)
+        let a_value_data = {
+            let mut builder = parquet_variant::VariantBuilder::new();
+            let obj = builder.new_object();
+            obj.finish();
+            let (_, value) = builder.finish();
+            value
+        };
+        let a_value_array = BinaryViewArray::from(vec![
+            None,                          // Row 0: x is shredded, so no value fallback needed
+            Some(a_value_data.as_slice()), // Row 1: fallback for a.x="foo" (but logic will check typed_value first)
+        ]);
+
+        let a_inner_fields = Fields::from(vec![Field::new(
+            "x",
+            x_field_shredded.data_type().clone(),
+            true,
+        )]);
+        let a_inner_typed_value = Arc::new(
+            StructArray::try_new(a_inner_fields, vec![ArrayRef::from(x_field_shredded)], None)
+                .unwrap(),
+        ) as ArrayRef;
+        let a_field_shredded = ShreddedVariantFieldArray::from_parts(
+            Some(a_value_array),
+            Some(a_inner_typed_value),
+            None,
+        );
+
+        // Level 0: main typed_value struct containing a field
+        let typed_value_fields = Fields::from(vec![Field::new(
+            "a",
+            a_field_shredded.data_type().clone(),
+            true,
+        )]);
+        let typed_value_struct = StructArray::try_new(
+            typed_value_fields,
+            vec![ArrayRef::from(a_field_shredded)],
+            None,
+        )
+        .unwrap();
+
+        // Build final VariantArray
+        ArrayRef::from(VariantArray::from_parts(
+            metadata_array,
+            Some(value_array),
+            Some(Arc::new(typed_value_struct)),
+            None,
+        ))
+    }
+
+    /// Create working depth 2 shredded test data for "a.b.x" paths
+    /// This creates a 3-level nested shredded structure where:
+    /// - Row 0: {"a": {"b": {"x": 100}}} with a.b.x shredded into typed_value
+    /// - Row 1: {"a": {"b": {"x": "bar"}}} with type mismatch fallback
+    /// - Row 2: {"a": {"b": {"y": 200}}} with missing field fallback
+    fn create_depth_2_shredded_test_data_working() -> ArrayRef {
+        // Create metadata following the working pattern
+        let (metadata, _) = {
+            // Create deeply nested structure: {"a": {"b": {"x": 100}}}
+            let mut builder = parquet_variant::VariantBuilder::new();
+            let mut obj = builder.new_object();
+
+            // Create the nested "a.b" structure
+            let mut a_obj = obj.new_object("a");
+            let mut b_obj = a_obj.new_object("b");
+            b_obj.insert("x", Variant::Int32(100));
+            b_obj.finish();
+            a_obj.finish();
+
+            obj.finish();
+            builder.finish()
+        };
+
+        let metadata_array = BinaryViewArray::from_iter_values(std::iter::repeat_n(&metadata, 3));
+
+        // Create value arrays for fallback cases
+        let empty_object_value = {
+            let mut builder = parquet_variant::VariantBuilder::new();
+            let obj = builder.new_object();
+            obj.finish();
+            let (_, value) = builder.finish();
+            value
+        };
+
+        // Simple fallback values - avoiding complex nested metadata
+        let value_array = BinaryViewArray::from(vec![
+            Some(empty_object_value.as_slice()), // Row 0: fully shredded
+            Some(empty_object_value.as_slice()), // Row 1: fallback (simplified)
+            Some(empty_object_value.as_slice()), // Row 2: fallback (simplified)
+        ]);
+
+        // Create the deeply nested shredded structure: a.b.x
+
+        // Level 3: x field (deepest level)
+        let x_typed_value = Int32Array::from(vec![Some(100), None, None]);
+        let x_field_shredded = ShreddedVariantFieldArray::from_parts(
+            None,
+            Some(Arc::new(x_typed_value) as ArrayRef),
+            None,
+        );
+
+        // Level 2: b field containing x field + value field
+        let b_value_data = {
+            let mut builder = parquet_variant::VariantBuilder::new();
+            let obj = builder.new_object();
+            obj.finish();
+            let (_, value) = builder.finish();
+            value
+        };
+        let b_value_array = BinaryViewArray::from(vec![
+            None,                          // Row 0: x is shredded
+            Some(b_value_data.as_slice()), // Row 1: fallback for b.x="bar"
+            Some(b_value_data.as_slice()), // Row 2: fallback for b.y=200
+        ]);
+
+        let b_inner_fields = Fields::from(vec![Field::new(
+            "x",
+            x_field_shredded.data_type().clone(),
+            true,
+        )]);
+        let b_inner_typed_value = Arc::new(
+            StructArray::try_new(b_inner_fields, vec![ArrayRef::from(x_field_shredded)], None)
+                .unwrap(),
+        ) as ArrayRef;
+        let b_field_shredded = ShreddedVariantFieldArray::from_parts(
+            Some(b_value_array),
+            Some(b_inner_typed_value),
+            None,
+        );
+
+        // Level 1: a field containing b field + value field
+        let a_value_data = {
+            let mut builder = parquet_variant::VariantBuilder::new();
+            let obj = builder.new_object();
+            obj.finish();
+            let (_, value) = builder.finish();
+            value
+        };
+        let a_value_array = BinaryViewArray::from(vec![
+            None,                          // Row 0: b is shredded
+            Some(a_value_data.as_slice()), // Row 1: fallback for a.b.*
+            Some(a_value_data.as_slice()), // Row 2: fallback for a.b.*
+        ]);
+
+        let a_inner_fields = Fields::from(vec![Field::new(
+            "b",
+            b_field_shredded.data_type().clone(),
+            true,
+        )]);
+        let a_inner_typed_value = Arc::new(
+            StructArray::try_new(a_inner_fields, vec![ArrayRef::from(b_field_shredded)], None)
+                .unwrap(),
+        ) as ArrayRef;
+        let a_field_shredded = ShreddedVariantFieldArray::from_parts(
+            Some(a_value_array),
+            Some(a_inner_typed_value),
+            None,
+        );
+
+        // Level 0: main typed_value struct containing a field
+        let typed_value_fields = Fields::from(vec![Field::new(
+            "a",
+            a_field_shredded.data_type().clone(),
+            true,
+        )]);
+        let typed_value_struct = StructArray::try_new(
+            typed_value_fields,
+            vec![ArrayRef::from(a_field_shredded)],
+            None,
+        )
+        .unwrap();
+
+        // Build final VariantArray
+        ArrayRef::from(VariantArray::from_parts(
+            metadata_array,
+            Some(value_array),
+            Some(Arc::new(typed_value_struct)),
+            None,
+        ))
+    }
+
+    #[test]
+    fn test_strict_cast_options_downcast_failure() {
+        use arrow::compute::CastOptions;
+        use arrow::datatypes::{DataType, Field};
+        use arrow::error::ArrowError;
+        use parquet_variant::VariantPath;
+        use std::sync::Arc;
+
+        // Use the existing simple test data that has Int32 as typed_value
+        let variant_array = perfectly_shredded_int32_variant_array();
+
         // Try to access a field with safe cast options (should return NULLs)
         let safe_options = GetOptions {
             path: VariantPath::try_from("nonexistent_field").unwrap(),
             as_type: Some(Arc::new(Field::new("result", DataType::Int32, true))),
             cast_options: CastOptions::default(), // safe = true
+            ..Default::default()
         };
 
         let variant_array_ref: Arc<dyn Array> = variant_array.clone();
@@ -2491,6 +4667,7 @@ mod test {
                 safe: false,
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         let result = variant_get(&variant_array_ref, strict_options);
@@ -2519,6 +4696,7 @@ mod test {
                 safe: false,
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         let err = variant_get(&variant_array, options).unwrap_err();
@@ -2540,6 +4718,7 @@ mod test {
                 safe: false,
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         let err = variant_get(&variant_array, options).unwrap_err();
@@ -2565,6 +4744,7 @@ mod test {
                 safe: false,
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         let err = variant_get(&variant_array, options).unwrap_err();
@@ -2597,6 +4777,7 @@ mod test {
             path: VariantPath::try_from("a.x").unwrap(),
             as_type: Some(Arc::new(Field::new("result", DataType::Int32, true))),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let variant_array_ref: Arc<dyn Array> = variant_array.clone();
@@ -2653,6 +4834,7 @@ mod test {
             path: VariantPath::default(), // Extract the whole object as struct
             as_type: Some(Arc::new(Field::new("result", struct_type, true))),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let variant_array_ref = ArrayRef::from(variant_array);
@@ -2731,6 +4913,7 @@ mod test {
             path: VariantPath::try_from("x").unwrap(),
             as_type: Some(nullable_field.clone()),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let variant_array_ref = ArrayRef::from(variant_array);
@@ -2778,47 +4961,24 @@ mod test {
         // Current behavior: Should fail conversion -> NULL
         assert!(int32_result.is_null(8));
 
-        // Test 2: non-nullable field (behavior should be the same with safe casting)
+        // Test 2: non-nullable field. Safe casting would produce nulls for failures, which the
+        // field does not allow, so this is an error.
         let non_nullable_field = Arc::new(Field::new("result", DataType::Int32, false));
         let options_non_nullable = GetOptions {
             path: VariantPath::try_from("x").unwrap(),
             as_type: Some(non_nullable_field.clone()),
             cast_options: CastOptions::default(), // safe=true by default
+            ..Default::default()
         };
 
         // Create variant array again since we moved it
         let variant_array_2 = json_to_variant(&string_array).unwrap();
         let variant_array_ref_2 = ArrayRef::from(variant_array_2);
-        let result_non_nullable = variant_get(&variant_array_ref_2, options_non_nullable).unwrap();
-        let int32_result_2 = result_non_nullable
-            .as_any()
-            .downcast_ref::<Int32Array>()
-            .unwrap();
-
-        // Even with a non-nullable field, safe casting should still produce nulls for failures
-        assert_eq!(int32_result_2.len(), 9);
-
-        // Row 0: 42 converts successfully to Int32
-        assert!(!int32_result_2.is_null(0));
-        assert_eq!(int32_result_2.value(0), 42);
-
-        // Rows 1-4: All should be null due to safe casting behavior
-        // (non-nullable field specification doesn't override safe casting behavior)
-        assert!(int32_result_2.is_null(1)); // "not_a_number"
-        assert!(int32_result_2.is_null(2)); // explicit null
-        assert!(int32_result_2.is_null(3)); // "hello"
-        assert!(int32_result_2.is_null(4)); // missing field
-
-        // Rows 5-7: These should also convert successfully (numeric widening/fitting)
-        assert!(!int32_result_2.is_null(5)); // 127 (Int8 -> Int32)
-        assert_eq!(int32_result_2.value(5), 127);
-        assert!(!int32_result_2.is_null(6)); // 32767 (Int16 -> Int32)
-        assert_eq!(int32_result_2.value(6), 32767);
-        assert!(!int32_result_2.is_null(7)); // 2147483647 (fits in Int32)
-        assert_eq!(int32_result_2.value(7), 2147483647);
-
-        // Row 8: Large Int64 should fail conversion -> NULL
-        assert!(int32_result_2.is_null(8)); // 9223372036854775807 (too large for Int32)
+        let err = variant_get(&variant_array_ref_2, options_non_nullable).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot extract variant into non-nullable field 'result': 5 of 9 rows are NULL"
+        );
     }
 
     #[test]
@@ -2838,6 +4998,7 @@ mod test {
             path: VariantPath::default(),
             as_type: Some(Arc::new(Field::new("result", struct_type, true))),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let result = variant_get(&variant_array, options).unwrap();
@@ -2907,6 +5068,7 @@ mod test {
             path: VariantPath::default(),
             as_type: Some(Arc::new(Field::new("result", result_type, true))),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let result = variant_get(&variant_array, options).unwrap();
@@ -2964,6 +5126,7 @@ mod test {
             path,
             as_type: Some(Arc::new(Field::new("result", result_type, true))),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let result = variant_get(&variant_array, options).unwrap();
@@ -3007,6 +5170,7 @@ mod test {
             path,
             as_type: Some(Arc::new(Field::new("result", DataType::Int32, true))),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let result = variant_get(&variant_array, options).unwrap();
@@ -3045,6 +5209,7 @@ mod test {
             path: VariantPath::default(),
             as_type: Some(Arc::new(Field::new("result", struct_type, true))),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let result = variant_get(&variant_array, options).unwrap();
@@ -3101,6 +5266,7 @@ mod test {
             path: VariantPath::default(),
             as_type: Some(Arc::new(Field::new("result", outer_struct_type, true))),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let variant_array_ref = ArrayRef::from(variant_array);
@@ -3140,6 +5306,7 @@ mod test {
                 true,
             ))),
             cast_options: CastOptions::default(),
+            ..Default::default()
         };
 
         let result = variant_get(&variant_array_ref, options).unwrap();
@@ -3167,6 +5334,76 @@ mod test {
         assert!(field_b.is_null(2));
     }
 
+    #[test]
+    fn test_struct_extraction_at_path() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"point": {"x": 1, "y": "one", "z": true}}"#),
+            Some(r#"{"point": {"y": "two"}}"#),
+            Some(r#"{"point": {"x": "three", "y": 3}}"#),
+            Some(r#"{"point": [4]}"#),
+            None,
+        ]));
+        let input = json_to_variant(&string_array).unwrap();
+        let fields = Fields::from(vec![
+            Field::new("x", DataType::Int64, true),
+            Field::new("y", DataType::Utf8, true),
+        ]);
+        // Only "x" is shredded, so "y" is read back from the `value` column
+        let schema = DataType::Struct(
+            vec![Field::new_struct(
+                "point",
+                vec![Field::new("x", DataType::Int64, true)],
+                true,
+            )]
+            .into(),
+        );
+        let shredded = shred_variant(&input, &schema).unwrap();
+
+        let expected: ArrayRef = Arc::new(StructArray::new(
+            fields.clone(),
+            vec![
+                Arc::new(Int64Array::from(vec![Some(1), None, None, None, None])),
+                Arc::new(StringArray::from(vec![
+                    Some("one"),
+                    Some("two"),
+                    None,
+                    None,
+                    None,
+                ])),
+            ],
+            Some(NullBuffer::from(vec![true, true, true, false, false])),
+        ));
+        for input in [input, shredded] {
+            let options = GetOptions::new_with_path(VariantPath::try_from("point").unwrap())
+                .with_as_type(Some(FieldRef::from(Field::new(
+                    "result",
+                    DataType::Struct(fields.clone()),
+                    true,
+                ))));
+            let input = ArrayRef::from(input);
+            let result = variant_get(&input, options.clone()).unwrap();
+            assert_eq!(&result, &expected);
+
+            // In strict mode, the non-object row is an error
+            let x_only = Fields::from(vec![fields[0].clone()]);
+            let options = options
+                .with_as_type(Some(FieldRef::from(Field::new(
+                    "result",
+                    DataType::Struct(x_only),
+                    true,
+                ))))
+                .with_cast_options(CastOptions {
+                    safe: false,
+                    ..Default::default()
+                });
+            let err = variant_get(&input.slice(3, 1), options).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Cast error: row 0: Failed to extract struct from variant [Int8(4)] at path $.point"
+            );
+        }
+    }
+
     #[test]
     fn test_unshredded_struct_strict_cast_non_object_errors() {
         let json_strings = vec![r#"{"a": 1, "b": 2}"#, "123"];
@@ -3188,6 +5425,7 @@ mod test {
                 safe: false,
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         let err = variant_get(&variant_array_ref, options).unwrap_err();
@@ -3548,6 +5786,44 @@ mod test {
         );
     }
 
+    #[test]
+    fn get_appended_timestamps() {
+        let mut builder = crate::VariantArrayBuilder::new(2);
+        builder.append_timestamp_millis(1_700_000_000_123).unwrap();
+        builder
+            .append_timestamp_micros(1_700_000_000_123_456)
+            .unwrap();
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let data_type = DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into()));
+        let field = Field::new("result", data_type, true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_primitive::<arrow::datatypes::TimestampMicrosecondType>();
+        assert_eq!(
+            result.values(),
+            &[1_700_000_000_123_000, 1_700_000_000_123_456]
+        );
+    }
+
+    #[test]
+    fn get_appended_decimal_as_decimal128() {
+        let mut builder = crate::VariantArrayBuilder::new(3);
+        builder.append_decimal(12345, 2).unwrap(); // 123.45
+        builder.append_decimal(-(10_i128.pow(30)), 4).unwrap();
+        builder.append_null();
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal128(38, 2), true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+
+        assert_eq!(result.value(0), 12345);
+        assert_eq!(result.value(1), -(10_i128.pow(28)));
+        assert!(result.is_null(2));
+    }
+
     #[test]
     fn get_decimal64_rescaled_to_scale2() {
         let mut builder = crate::VariantArrayBuilder::new(5);
@@ -3638,741 +5914,1807 @@ mod test {
         let result = variant_get(&variant_array, options).unwrap();
         let result = result.as_any().downcast_ref::<Decimal64Array>().unwrap();
 
-        assert_eq!(result.precision(), 18);
-        assert_eq!(result.scale(), -19);
-        assert!(result.is_valid(0));
-        assert_eq!(result.value(0), 0);
-        assert!(result.is_valid(1));
-        assert_eq!(result.value(1), 0);
+        assert_eq!(result.precision(), 18);
+        assert_eq!(result.scale(), -19);
+        assert!(result.is_valid(0));
+        assert_eq!(result.value(0), 0);
+        assert!(result.is_valid(1));
+        assert_eq!(result.value(1), 0);
+    }
+
+    #[test]
+    fn get_decimal64_precision_overflow_safe() {
+        // Exceed Decimal64 after scaling and rounding
+        let mut builder = crate::VariantArrayBuilder::new(2);
+        builder.append_variant(
+            VariantDecimal8::try_new(VariantDecimal8::MAX_UNSCALED_VALUE, 0)
+                .unwrap()
+                .into(),
+        );
+        builder.append_variant(
+            VariantDecimal8::try_new(VariantDecimal8::MAX_UNSCALED_VALUE, 18)
+                .unwrap()
+                .into(),
+        ); // integer value round up overflows
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal64(2, 2), true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal64Array>().unwrap();
+
+        assert!(result.is_null(0));
+        assert!(result.is_null(1));
+    }
+
+    #[test]
+    fn get_decimal64_precision_overflow_unsafe_errors() {
+        let mut builder = crate::VariantArrayBuilder::new(1);
+        builder.append_variant(
+            VariantDecimal8::try_new(VariantDecimal8::MAX_UNSCALED_VALUE, 0)
+                .unwrap()
+                .into(),
+        );
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal64(18, 2), true);
+        let cast_options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(cast_options);
+        let err = variant_get(&variant_array, options).unwrap_err();
+
+        assert!(
+            err.to_string().contains(
+                "Failed to cast to Decimal64(precision=18, scale=2) from variant Decimal8"
+            )
+        );
+    }
+
+    #[test]
+    fn get_decimal128_rescaled_to_scale2() {
+        let mut builder = crate::VariantArrayBuilder::new(4);
+        builder.append_variant(VariantDecimal16::try_new(1234, 2).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(1234, 3).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(1234, 0).unwrap().into());
+        builder.append_null();
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal128(38, 2), true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+
+        assert_eq!(result.precision(), 38);
+        assert_eq!(result.scale(), 2);
+        assert_eq!(result.value(0), 1234);
+        assert_eq!(result.value(1), 123);
+        assert_eq!(result.value(2), 123400);
+        assert!(result.is_null(3));
+    }
+
+    #[test]
+    fn get_decimal128_from_decimal4_and_integers() {
+        let mut builder = crate::VariantArrayBuilder::new(5);
+        builder.append_variant(VariantDecimal4::try_new(123, 2).unwrap().into()); // 1.23
+        builder.append_variant(VariantDecimal8::try_new(-125, 2).unwrap().into()); // -1.25
+        builder.append_variant(Variant::Int8(7));
+        builder.append_variant(Variant::Int64(-42));
+        builder.append_variant(Variant::from("1.23"));
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        // Scale up: 1.23 -> 1.2300
+        let result = get(
+            &variant_array.slice(0, 4),
+            "",
+            Some(DataType::Decimal128(10, 4)),
+            true,
+        )
+        .unwrap();
+        let expected = Decimal128Array::from(vec![12300, -12500, 70000, -420000])
+            .with_precision_and_scale(10, 4)
+            .unwrap();
+        assert_eq!(
+            result.as_primitive::<arrow::datatypes::Decimal128Type>(),
+            &expected
+        );
+
+        // Scale down rounds half away from zero: 1.23 -> 1.2, -1.25 -> -1.3
+        let result = get(
+            &variant_array.slice(0, 4),
+            "",
+            Some(DataType::Decimal128(10, 1)),
+            true,
+        )
+        .unwrap();
+        let expected = Decimal128Array::from(vec![12, -13, 70, -420])
+            .with_precision_and_scale(10, 1)
+            .unwrap();
+        assert_eq!(
+            result.as_primitive::<arrow::datatypes::Decimal128Type>(),
+            &expected
+        );
+
+        // Values that no longer fit the precision after rescaling are NULL in safe mode...
+        let result = get(
+            &variant_array.slice(0, 4),
+            "",
+            Some(DataType::Decimal128(3, 2)),
+            true,
+        )
+        .unwrap();
+        let expected = Decimal128Array::from(vec![Some(123), Some(-125), Some(700), None])
+            .with_precision_and_scale(3, 2)
+            .unwrap();
+        assert_eq!(
+            result.as_primitive::<arrow::datatypes::Decimal128Type>(),
+            &expected
+        );
+
+        // ...and an error otherwise
+        let err = get(
+            &variant_array.slice(0, 4),
+            "",
+            Some(DataType::Decimal128(3, 2)),
+            false,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "Failed to cast to Decimal128(precision=3, scale=2) from variant Int64(-42)"
+            ),
+            "{err}"
+        );
+
+        // Strings are not parsed as decimals
+        let field = Field::new("result", DataType::Decimal128(10, 2), true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array.slice(4, 1), options).unwrap();
+        assert!(result.is_null(0));
+    }
+
+    #[test]
+    fn get_decimal128_scale_down_rounding() {
+        let mut builder = crate::VariantArrayBuilder::new(7);
+        builder.append_variant(VariantDecimal16::try_new(1235, 0).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(1245, 0).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(-1235, 0).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(-1245, 0).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(1235, 2).unwrap().into()); // 12.35 rounded down to 10 for scale -1
+        builder.append_variant(VariantDecimal16::try_new(1235, 3).unwrap().into()); // 1.235 rounded down to 0 for scale -1
+        builder.append_variant(VariantDecimal16::try_new(5235, 3).unwrap().into()); // 5.235 rounded up to 10 for scale -1
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal128(38, -1), true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+
+        assert_eq!(result.precision(), 38);
+        assert_eq!(result.scale(), -1);
+        assert_eq!(result.value(0), 124);
+        assert_eq!(result.value(1), 125);
+        assert_eq!(result.value(2), -124);
+        assert_eq!(result.value(3), -125);
+        assert_eq!(result.value(4), 1);
+        assert!(result.is_valid(5));
+        assert_eq!(result.value(5), 0);
+        assert_eq!(result.value(6), 1);
+    }
+
+    #[test]
+    fn get_decimal128_precision_overflow_safe() {
+        // Exceed Decimal128 after scaling and rounding
+        let mut builder = crate::VariantArrayBuilder::new(2);
+        builder.append_variant(
+            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 0)
+                .unwrap()
+                .into(),
+        );
+        builder.append_variant(
+            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 38)
+                .unwrap()
+                .into(),
+        ); // integer value round up overflows
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal128(2, 2), true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+
+        assert!(result.is_null(0));
+        assert!(result.is_null(1)); // should overflow because 1.00 does not fit into precision (2)
+    }
+
+    #[test]
+    fn get_decimal128_precision_overflow_unsafe_errors() {
+        let mut builder = crate::VariantArrayBuilder::new(1);
+        builder.append_variant(
+            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 0)
+                .unwrap()
+                .into(),
+        );
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal128(38, 2), true);
+        let cast_options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(cast_options);
+        let err = variant_get(&variant_array, options).unwrap_err();
+
+        assert!(err.to_string().contains(
+            "Failed to cast to Decimal128(precision=38, scale=2) from variant Decimal16"
+        ));
+    }
+
+    #[test]
+    fn get_decimal256_rescaled_to_scale2() {
+        // Build unshredded variant values with different scales using Decimal16 source
+        let mut builder = crate::VariantArrayBuilder::new(4);
+        builder.append_variant(VariantDecimal16::try_new(1234, 2).unwrap().into()); // 12.34
+        builder.append_variant(VariantDecimal16::try_new(1234, 3).unwrap().into()); // 1.234
+        builder.append_variant(VariantDecimal16::try_new(1234, 0).unwrap().into()); // 1234
+        builder.append_null();
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal256(76, 2), true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal256Array>().unwrap();
+
+        assert_eq!(result.precision(), 76);
+        assert_eq!(result.scale(), 2);
+        assert_eq!(result.value(0), i256::from_i128(1234));
+        assert_eq!(result.value(1), i256::from_i128(123));
+        assert_eq!(result.value(2), i256::from_i128(123400));
+        assert!(result.is_null(3));
+    }
+
+    #[test]
+    fn get_decimal256_scale_down_rounding() {
+        let mut builder = crate::VariantArrayBuilder::new(7);
+        builder.append_variant(VariantDecimal16::try_new(1235, 0).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(1245, 0).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(-1235, 0).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(-1245, 0).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(1235, 2).unwrap().into()); // 12.35 rounded down to 10 for scale -1
+        builder.append_variant(VariantDecimal16::try_new(1235, 3).unwrap().into()); // 1.235 rounded down to 0 for scale -1
+        builder.append_variant(VariantDecimal16::try_new(5235, 3).unwrap().into()); // 5.235 rounded up to 10 for scale -1
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal256(76, -1), true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal256Array>().unwrap();
+
+        assert_eq!(result.precision(), 76);
+        assert_eq!(result.scale(), -1);
+        assert_eq!(result.value(0), i256::from_i128(124));
+        assert_eq!(result.value(1), i256::from_i128(125));
+        assert_eq!(result.value(2), i256::from_i128(-124));
+        assert_eq!(result.value(3), i256::from_i128(-125));
+        assert_eq!(result.value(4), i256::from_i128(1));
+        assert!(result.is_valid(5));
+        assert_eq!(result.value(5), i256::from_i128(0));
+        assert_eq!(result.value(6), i256::from_i128(1));
+    }
+
+    #[test]
+    fn get_decimal256_precision_overflow_safe() {
+        // Exceed Decimal128 max precision (38) after scaling
+        let mut builder = crate::VariantArrayBuilder::new(2);
+        builder.append_variant(
+            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 1)
+                .unwrap()
+                .into(),
+        );
+        builder.append_variant(
+            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 0)
+                .unwrap()
+                .into(),
+        );
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal256(76, 39), true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal256Array>().unwrap();
+
+        // Input is Decimal16 with integer = 10^38-1 and scale = 1, target scale = 39
+        // So expected integer is (10^38-1) * 10^(39-1) = (10^38-1) * 10^38
+        let base = i256::from_i128(10);
+        let factor = base.checked_pow(38).unwrap();
+        let expected = i256::from_i128(VariantDecimal16::MAX_UNSCALED_VALUE)
+            .checked_mul(factor)
+            .unwrap();
+        assert_eq!(result.value(0), expected);
+        assert!(result.is_null(1));
+    }
+
+    #[test]
+    fn get_decimal256_precision_overflow_unsafe_errors() {
+        // Exceed Decimal128 max precision (38) after scaling
+        let mut builder = crate::VariantArrayBuilder::new(2);
+        builder.append_variant(
+            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 1)
+                .unwrap()
+                .into(),
+        );
+        builder.append_variant(
+            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 0)
+                .unwrap()
+                .into(),
+        );
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Decimal256(76, 39), true);
+        let cast_options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(cast_options);
+        let err = variant_get(&variant_array, options).unwrap_err();
+
+        assert!(err.to_string().contains(
+            "Failed to cast to Decimal256(precision=76, scale=39) from variant Decimal16"
+        ));
+    }
+
+    #[test]
+    fn get_non_supported_temporal_types_error() {
+        let values = vec![None, Some(Variant::Null), Some(Variant::BooleanFalse)];
+        let variant_array: ArrayRef = ArrayRef::from(VariantArray::from_iter(values));
+
+        let test_cases = vec![
+            FieldRef::from(Field::new(
+                "result",
+                DataType::Duration(TimeUnit::Microsecond),
+                true,
+            )),
+            FieldRef::from(Field::new(
+                "result",
+                DataType::Interval(IntervalUnit::YearMonth),
+                true,
+            )),
+        ];
+
+        for field in test_cases {
+            let options = GetOptions::new().with_as_type(Some(field));
+            let err = variant_get(&variant_array, options).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("Casting Variant to duration/interval types is not supported")
+            );
+        }
+    }
+
+    fn invalid_time_variant_array() -> ArrayRef {
+        let mut builder = VariantArrayBuilder::new(3);
+        // 86401000000 is invalid for Time64Microsecond (max is 86400000000)
+        builder.append_variant(Variant::Int64(86401000000));
+        builder.append_variant(Variant::Int64(86401000000));
+        builder.append_variant(Variant::Int64(86401000000));
+        Arc::new(builder.build().into_inner())
+    }
+
+    #[test]
+    fn test_variant_get_error_when_cast_failure_and_safe_false() {
+        let variant_array = invalid_time_variant_array();
+
+        let field = Field::new("result", DataType::Time64(TimeUnit::Microsecond), true);
+        let cast_options = CastOptions {
+            safe: false, // Will error on cast failure
+            ..Default::default()
+        };
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(cast_options);
+        let err = variant_get(&variant_array, options).unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "Cast error: row 0: Failed to extract primitive of type Time64(µs) from variant Int64(86401000000) at path $"
+            ),
+            "actual: {err}",
+        );
+    }
+
+    #[test]
+    fn test_variant_get_return_null_when_cast_failure_and_safe_true() {
+        let variant_array = invalid_time_variant_array();
+
+        let field = Field::new("result", DataType::Time64(TimeUnit::Microsecond), true);
+        let cast_options = CastOptions {
+            safe: true, // Will return null on cast failure
+            ..Default::default()
+        };
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(cast_options);
+        let result = variant_get(&variant_array, options).unwrap();
+        assert_eq!(3, result.len());
+
+        for i in 0..3 {
+            assert!(result.is_null(i));
+        }
+    }
+
+    #[test]
+    fn test_perfect_shredding_returns_same_arc_ptr() {
+        let variant_array = perfectly_shredded_int32_variant_array();
+
+        let variant_array_ref = VariantArray::try_new(&variant_array).unwrap();
+        let typed_value_arc = variant_array_ref.typed_value_field().unwrap().clone();
+
+        let field = Field::new("result", DataType::Int32, true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let result = variant_get(&variant_array, options).unwrap();
+
+        assert!(Arc::ptr_eq(&typed_value_arc, &result));
+    }
+
+    #[test]
+    fn test_perfect_shredding_three_typed_value_columns() {
+        // Column 1: perfectly shredded primitive with all nulls
+        let all_nulls_values: Arc<Int32Array> = Arc::new(Int32Array::from(vec![
+            Option::<i32>::None,
+            Option::<i32>::None,
+            Option::<i32>::None,
+        ]));
+        let all_nulls_erased: ArrayRef = all_nulls_values.clone();
+        let all_nulls_field =
+            ShreddedVariantFieldArray::from_parts(None, Some(all_nulls_erased.clone()), None);
+        let all_nulls_type = all_nulls_field.data_type().clone();
+        let all_nulls_struct: ArrayRef = ArrayRef::from(all_nulls_field);
+
+        // Column 2: perfectly shredded primitive with some nulls
+        let some_nulls_values: Arc<Int32Array> =
+            Arc::new(Int32Array::from(vec![Some(10), None, Some(30)]));
+        let some_nulls_erased: ArrayRef = some_nulls_values.clone();
+        let some_nulls_field =
+            ShreddedVariantFieldArray::from_parts(None, Some(some_nulls_erased.clone()), None);
+        let some_nulls_type = some_nulls_field.data_type().clone();
+        let some_nulls_struct: ArrayRef = ArrayRef::from(some_nulls_field);
+
+        // Column 3: perfectly shredded nested struct
+        let inner_values: Arc<Int32Array> =
+            Arc::new(Int32Array::from(vec![Some(111), None, Some(333)]));
+        let inner_erased: ArrayRef = inner_values.clone();
+        let inner_field =
+            ShreddedVariantFieldArray::from_parts(None, Some(inner_erased.clone()), None);
+        let inner_field_type = inner_field.data_type().clone();
+        let inner_struct_array: ArrayRef = ArrayRef::from(inner_field);
+
+        let nested_struct = Arc::new(
+            StructArray::try_new(
+                Fields::from(vec![Field::new("inner", inner_field_type, true)]),
+                vec![inner_struct_array],
+                None,
+            )
+            .unwrap(),
+        );
+        let nested_struct_erased: ArrayRef = nested_struct.clone();
+        let struct_field =
+            ShreddedVariantFieldArray::from_parts(None, Some(nested_struct_erased.clone()), None);
+        let struct_field_type = struct_field.data_type().clone();
+        let struct_field_struct: ArrayRef = ArrayRef::from(struct_field);
+
+        // Assemble the top-level typed_value struct with the three columns above
+        let typed_value_struct = StructArray::try_new(
+            Fields::from(vec![
+                Field::new("all_nulls", all_nulls_type, true),
+                Field::new("some_nulls", some_nulls_type, true),
+                Field::new("struct_field", struct_field_type, true),
+            ]),
+            vec![all_nulls_struct, some_nulls_struct, struct_field_struct],
+            None,
+        )
+        .unwrap();
+
+        let metadata = BinaryViewArray::from_iter_values(std::iter::repeat_n(
+            EMPTY_VARIANT_METADATA_BYTES,
+            all_nulls_values.len(),
+        ));
+        let variant_array: ArrayRef =
+            VariantArray::from_parts(metadata, None, Some(Arc::new(typed_value_struct)), None)
+                .into();
+
+        // Case 1: all-null primitive column should reuse the typed_value Arc directly
+        let all_nulls_field_ref = FieldRef::from(Field::new("result", DataType::Int32, true));
+        let all_nulls_result = variant_get(
+            &variant_array,
+            GetOptions::new_with_path(VariantPath::try_from("all_nulls").unwrap())
+                .with_as_type(Some(all_nulls_field_ref)),
+        )
+        .unwrap();
+        assert!(Arc::ptr_eq(&all_nulls_result, &all_nulls_erased));
+
+        // Case 2: primitive column with some nulls should also reuse its typed_value Arc
+        let some_nulls_field_ref = FieldRef::from(Field::new("result", DataType::Int32, true));
+        let some_nulls_result = variant_get(
+            &variant_array,
+            GetOptions::new_with_path(VariantPath::try_from("some_nulls").unwrap())
+                .with_as_type(Some(some_nulls_field_ref)),
+        )
+        .unwrap();
+        assert!(Arc::ptr_eq(&some_nulls_result, &some_nulls_erased));
+
+        // Case 3: struct column should return a StructArray composed from the nested field
+        let struct_child_fields = Fields::from(vec![Field::new("inner", DataType::Int32, true)]);
+        let struct_field_ref = FieldRef::from(Field::new(
+            "result",
+            DataType::Struct(struct_child_fields.clone()),
+            true,
+        ));
+        let struct_result = variant_get(
+            &variant_array,
+            GetOptions::new_with_path(VariantPath::try_from("struct_field").unwrap())
+                .with_as_type(Some(struct_field_ref)),
+        )
+        .unwrap();
+        let struct_array = struct_result
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(struct_array.len(), 3);
+        assert_eq!(struct_array.null_count(), 0);
+
+        let inner_values_result = struct_array
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(inner_values_result.len(), 3);
+        assert_eq!(inner_values_result.value(0), 111);
+        assert!(inner_values_result.is_null(1));
+        assert_eq!(inner_values_result.value(2), 333);
+    }
+
+    #[test]
+    fn test_variant_get_list_like_safe_cast() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            r#"{"outer":{"list":[1, "two", 3]}}"#,
+            r#"{"outer":{"list":"not a list"}}"#,
+        ]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+
+        let element_array: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, Some(3)]));
+        let field = Arc::new(Field::new("item", Int64, true));
+
+        let expectations = vec![
+            (
+                DataType::List(field.clone()),
+                Arc::new(ListArray::new(
+                    field.clone(),
+                    OffsetBuffer::new(ScalarBuffer::from(vec![0, 3, 3])),
+                    element_array.clone(),
+                    Some(NullBuffer::from(vec![true, false])),
+                )) as ArrayRef,
+            ),
+            (
+                DataType::LargeList(field.clone()),
+                Arc::new(LargeListArray::new(
+                    field.clone(),
+                    OffsetBuffer::new(ScalarBuffer::from(vec![0, 3, 3])),
+                    element_array.clone(),
+                    Some(NullBuffer::from(vec![true, false])),
+                )) as ArrayRef,
+            ),
+            (
+                DataType::ListView(field.clone()),
+                Arc::new(ListViewArray::new(
+                    field.clone(),
+                    ScalarBuffer::from(vec![0, 3]),
+                    ScalarBuffer::from(vec![3, 0]),
+                    element_array.clone(),
+                    Some(NullBuffer::from(vec![true, false])),
+                )) as ArrayRef,
+            ),
+            (
+                DataType::LargeListView(field.clone()),
+                Arc::new(LargeListViewArray::new(
+                    field,
+                    ScalarBuffer::from(vec![0, 3]),
+                    ScalarBuffer::from(vec![3, 0]),
+                    element_array,
+                    Some(NullBuffer::from(vec![true, false])),
+                )) as ArrayRef,
+            ),
+        ];
+
+        for (request_type, expected) in expectations {
+            let options =
+                GetOptions::new_with_path(VariantPath::try_from("outer").unwrap().join("list"))
+                    .with_as_type(Some(FieldRef::from(Field::new(
+                        "result",
+                        request_type.clone(),
+                        true,
+                    ))));
+
+            let result = variant_get(&variant_array, options).unwrap();
+            assert_eq!(result.data_type(), expected.data_type());
+            assert_eq!(&result, &expected);
+        }
+
+        for (idx, expected) in [
+            (0, vec![Some(1), None]),
+            (1, vec![None, None]),
+            (2, vec![Some(3), None]),
+        ] {
+            let index_options = GetOptions::new_with_path(
+                VariantPath::try_from("outer")
+                    .unwrap()
+                    .join("list")
+                    .join(idx),
+            )
+            .with_as_type(Some(FieldRef::from(Field::new(
+                "result",
+                DataType::Int64,
+                true,
+            ))));
+            let index_result = variant_get(&variant_array, index_options).unwrap();
+            let index_expected: ArrayRef = Arc::new(Int64Array::from(expected));
+            assert_eq!(&index_result, &index_expected);
+        }
+    }
+
+    #[test]
+    fn test_variant_get_list_at_path() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"nums": [1, 2.0, 3]}"#),
+            Some(r#"{"nums": []}"#),
+            Some(r#"{"nums": 4}"#),
+            Some(r#"{"other": [5]}"#),
+            None,
+        ]));
+        let input = json_to_variant(&string_array).unwrap();
+        let field = Arc::new(Field::new("item", Int64, true));
+        let schema =
+            DataType::Struct(vec![Field::new("nums", DataType::List(field.clone()), true)].into());
+        let shredded = shred_variant(&input, &schema).unwrap();
+
+        let expected: ArrayRef = Arc::new(ListArray::new(
+            field.clone(),
+            OffsetBuffer::new(ScalarBuffer::from(vec![0, 3, 3, 3, 3, 3])),
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+            Some(NullBuffer::from(vec![true, true, false, false, false])),
+        ));
+        // Shredded and unshredded inputs produce the same list
+        for input in [input, shredded] {
+            let options = GetOptions::new_with_path(VariantPath::try_from("nums").unwrap())
+                .with_as_type(Some(FieldRef::from(Field::new(
+                    "result",
+                    DataType::List(field.clone()),
+                    true,
+                ))));
+            let result = variant_get(&ArrayRef::from(input), options).unwrap();
+            assert_eq!(&result, &expected);
+        }
     }
 
     #[test]
-    fn get_decimal64_precision_overflow_safe() {
-        // Exceed Decimal64 after scaling and rounding
-        let mut builder = crate::VariantArrayBuilder::new(2);
-        builder.append_variant(
-            VariantDecimal8::try_new(VariantDecimal8::MAX_UNSCALED_VALUE, 0)
-                .unwrap()
-                .into(),
-        );
-        builder.append_variant(
-            VariantDecimal8::try_new(VariantDecimal8::MAX_UNSCALED_VALUE, 18)
-                .unwrap()
-                .into(),
-        ); // integer value round up overflows
-        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+    fn test_variant_get_nested_list() {
+        use arrow::datatypes::Int64Type;
 
-        let field = Field::new("result", DataType::Decimal64(2, 2), true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            r#"[[1, 2], [3]]"#,
+            r#"[[4], "not a list", [5, 6]]"#,
+        ]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+
+        let inner_field = Arc::new(Field::new("item", Int64, true));
+        let outer_field = Arc::new(Field::new(
+            "item",
+            DataType::List(inner_field.clone()),
+            true,
+        ));
+        let request_type = DataType::List(outer_field.clone());
+
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(Field::new(
+            "result",
+            request_type,
+            true,
+        ))));
         let result = variant_get(&variant_array, options).unwrap();
-        let result = result.as_any().downcast_ref::<Decimal64Array>().unwrap();
+        let outer = result.as_list::<i32>();
 
-        assert!(result.is_null(0));
-        assert!(result.is_null(1));
+        // Row 0: [[1, 2], [3]]
+        let row0 = outer.value(0);
+        let row0 = row0.as_list::<i32>();
+        assert_eq!(row0.len(), 2);
+        let elem0 = row0.value(0);
+        assert_eq!(elem0.as_primitive::<Int64Type>().values(), &[1, 2]);
+        let elem1 = row0.value(1);
+        assert_eq!(elem1.as_primitive::<Int64Type>().values(), &[3]);
+
+        // Row 1: [[4], null, [5, 6]] — "not a list" becomes null inner list
+        let row1 = outer.value(1);
+        let row1 = row1.as_list::<i32>();
+        assert_eq!(row1.len(), 3);
+        let elem0 = row1.value(0);
+        assert_eq!(elem0.as_primitive::<Int64Type>().values(), &[4]);
+        assert!(row1.is_null(1));
+        let elem2 = row1.value(2);
+        assert_eq!(elem2.as_primitive::<Int64Type>().values(), &[5, 6]);
     }
 
     #[test]
-    fn get_decimal64_precision_overflow_unsafe_errors() {
-        let mut builder = crate::VariantArrayBuilder::new(1);
-        builder.append_variant(
-            VariantDecimal8::try_new(VariantDecimal8::MAX_UNSCALED_VALUE, 0)
-                .unwrap()
-                .into(),
-        );
-        let variant_array: ArrayRef = ArrayRef::from(builder.build());
-
-        let field = Field::new("result", DataType::Decimal64(18, 2), true);
+    fn test_variant_get_list_like_unsafe_cast_errors_on_element_mismatch() {
+        let string_array: ArrayRef =
+            Arc::new(StringArray::from(vec![r#"[1, "two", 3]"#, "[4, 5]"]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
         let cast_options = CastOptions {
             safe: false,
             ..Default::default()
         };
-        let options = GetOptions::new()
-            .with_as_type(Some(FieldRef::from(field)))
-            .with_cast_options(cast_options);
-        let err = variant_get(&variant_array, options).unwrap_err();
 
-        assert!(
-            err.to_string().contains(
-                "Failed to cast to Decimal64(precision=18, scale=2) from variant Decimal8"
-            )
-        );
-    }
-
-    #[test]
-    fn get_decimal128_rescaled_to_scale2() {
-        let mut builder = crate::VariantArrayBuilder::new(4);
-        builder.append_variant(VariantDecimal16::try_new(1234, 2).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(1234, 3).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(1234, 0).unwrap().into());
-        builder.append_null();
-        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+        let item_field = Arc::new(Field::new("item", DataType::Int64, true));
+        let request_types = vec![
+            DataType::List(item_field.clone()),
+            DataType::LargeList(item_field.clone()),
+            DataType::ListView(item_field.clone()),
+            DataType::LargeListView(item_field),
+        ];
 
-        let field = Field::new("result", DataType::Decimal128(38, 2), true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&variant_array, options).unwrap();
-        let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        for request_type in request_types {
+            let options = GetOptions::new()
+                .with_as_type(Some(FieldRef::from(Field::new(
+                    "result",
+                    request_type.clone(),
+                    true,
+                ))))
+                .with_cast_options(cast_options.clone());
 
-        assert_eq!(result.precision(), 38);
-        assert_eq!(result.scale(), 2);
-        assert_eq!(result.value(0), 1234);
-        assert_eq!(result.value(1), 123);
-        assert_eq!(result.value(2), 123400);
-        assert!(result.is_null(3));
+            let err = variant_get(&variant_array, options).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("Failed to extract primitive of type Int64")
+            );
+        }
     }
 
     #[test]
-    fn get_decimal128_scale_down_rounding() {
-        let mut builder = crate::VariantArrayBuilder::new(7);
-        builder.append_variant(VariantDecimal16::try_new(1235, 0).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(1245, 0).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(-1235, 0).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(-1245, 0).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(1235, 2).unwrap().into()); // 12.35 rounded down to 10 for scale -1
-        builder.append_variant(VariantDecimal16::try_new(1235, 3).unwrap().into()); // 1.235 rounded down to 0 for scale -1
-        builder.append_variant(VariantDecimal16::try_new(5235, 3).unwrap().into()); // 5.235 rounded up to 10 for scale -1
-        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+    fn test_variant_get_list_like_unsafe_cast_preserves_null_elements() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![r#"[1, null, 3]"#]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+        let cast_options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(Field::new(
+                "result",
+                DataType::List(Arc::new(Field::new("item", DataType::Int64, true))),
+                true,
+            ))))
+            .with_cast_options(cast_options);
 
-        let field = Field::new("result", DataType::Decimal128(38, -1), true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
         let result = variant_get(&variant_array, options).unwrap();
-        let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        let list_array = result.as_any().downcast_ref::<ListArray>().unwrap();
+        let values = list_array
+            .values()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
 
-        assert_eq!(result.precision(), 38);
-        assert_eq!(result.scale(), -1);
-        assert_eq!(result.value(0), 124);
-        assert_eq!(result.value(1), 125);
-        assert_eq!(result.value(2), -124);
-        assert_eq!(result.value(3), -125);
-        assert_eq!(result.value(4), 1);
-        assert!(result.is_valid(5));
-        assert_eq!(result.value(5), 0);
-        assert_eq!(result.value(6), 1);
+        assert_eq!(values.len(), 3);
+        assert_eq!(values.value(0), 1);
+        assert!(values.is_null(1));
+        assert_eq!(values.value(2), 3);
     }
 
     #[test]
-    fn get_decimal128_precision_overflow_safe() {
-        // Exceed Decimal128 after scaling and rounding
-        let mut builder = crate::VariantArrayBuilder::new(2);
-        builder.append_variant(
-            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 0)
-                .unwrap()
-                .into(),
-        );
-        builder.append_variant(
-            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 38)
-                .unwrap()
-                .into(),
-        ); // integer value round up overflows
-        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+    fn test_variant_get_list_like_unsafe_cast_errors_on_non_list() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec!["[1, 2]", "\"not a list\""]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+        let cast_options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let item_field = Arc::new(Field::new("item", Int64, true));
+        let data_types = vec![
+            DataType::List(item_field.clone()),
+            DataType::LargeList(item_field.clone()),
+            DataType::ListView(item_field.clone()),
+            DataType::LargeListView(item_field),
+        ];
 
-        let field = Field::new("result", DataType::Decimal128(2, 2), true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&variant_array, options).unwrap();
-        let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        for data_type in data_types {
+            let options = GetOptions::new()
+                .with_as_type(Some(FieldRef::from(Field::new("result", data_type, true))))
+                .with_cast_options(cast_options.clone());
 
-        assert!(result.is_null(0));
-        assert!(result.is_null(1)); // should overflow because 1.00 does not fit into precision (2)
+            let err = variant_get(&variant_array, options).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("Failed to extract list from variant"),
+            );
+        }
     }
 
     #[test]
-    fn get_decimal128_precision_overflow_unsafe_errors() {
-        let mut builder = crate::VariantArrayBuilder::new(1);
-        builder.append_variant(
-            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 0)
-                .unwrap()
-                .into(),
-        );
-        let variant_array: ArrayRef = ArrayRef::from(builder.build());
-
-        let field = Field::new("result", DataType::Decimal128(38, 2), true);
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
-        let options = GetOptions::new()
-            .with_as_type(Some(FieldRef::from(field)))
-            .with_cast_options(cast_options);
-        let err = variant_get(&variant_array, options).unwrap_err();
+    fn test_variant_get_fixed_size_list_not_implemented() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec!["[1, 2]", "\"not a list\""]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+        let item_field = Arc::new(Field::new("item", Int64, true));
+        for safe in [true, false] {
+            let options = GetOptions::new()
+                .with_as_type(Some(FieldRef::from(Field::new(
+                    "result",
+                    DataType::FixedSizeList(item_field.clone(), 2),
+                    true,
+                ))))
+                .with_cast_options(CastOptions {
+                    safe,
+                    ..Default::default()
+                });
 
-        assert!(err.to_string().contains(
-            "Failed to cast to Decimal128(precision=38, scale=2) from variant Decimal16"
-        ));
+            let err = variant_get(&variant_array, options).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("Converting unshredded variant arrays to arrow fixed-size lists")
+            );
+        }
     }
 
     #[test]
-    fn get_decimal256_rescaled_to_scale2() {
-        // Build unshredded variant values with different scales using Decimal16 source
-        let mut builder = crate::VariantArrayBuilder::new(4);
-        builder.append_variant(VariantDecimal16::try_new(1234, 2).unwrap().into()); // 12.34
-        builder.append_variant(VariantDecimal16::try_new(1234, 3).unwrap().into()); // 1.234
-        builder.append_variant(VariantDecimal16::try_new(1234, 0).unwrap().into()); // 1234
+    fn test_variant_get_mixed_int_widths_as_int32() {
+        let mut builder = VariantArrayBuilder::new(6);
+        builder.append_variant(Variant::Int8(-8));
+        builder.append_variant(Variant::Int16(1600));
+        builder.append_variant(Variant::Int32(320000));
+        builder.append_variant(Variant::Int64(64));
         builder.append_null();
+        builder.append_variant(Variant::Int64(i64::MAX));
         let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
-        let field = Field::new("result", DataType::Decimal256(76, 2), true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let field = Field::new("result", DataType::Int32, true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field.clone())));
         let result = variant_get(&variant_array, options).unwrap();
-        let result = result.as_any().downcast_ref::<Decimal256Array>().unwrap();
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(-8),
+            Some(1600),
+            Some(320000),
+            Some(64),
+            None,
+            None, // i64::MAX does not fit in i32
+        ]));
+        assert_eq!(&result, &expected);
 
-        assert_eq!(result.precision(), 76);
-        assert_eq!(result.scale(), 2);
-        assert_eq!(result.value(0), i256::from_i128(1234));
-        assert_eq!(result.value(1), i256::from_i128(123));
-        assert_eq!(result.value(2), i256::from_i128(123400));
-        assert!(result.is_null(3));
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(CastOptions {
+                safe: false,
+                ..Default::default()
+            });
+        let err = variant_get(&variant_array, options).unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "Failed to extract primitive of type Int32 from variant Int64(9223372036854775807)"
+            ),
+            "actual: {err}"
+        );
     }
 
     #[test]
-    fn get_decimal256_scale_down_rounding() {
-        let mut builder = crate::VariantArrayBuilder::new(7);
-        builder.append_variant(VariantDecimal16::try_new(1235, 0).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(1245, 0).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(-1235, 0).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(-1245, 0).unwrap().into());
-        builder.append_variant(VariantDecimal16::try_new(1235, 2).unwrap().into()); // 12.35 rounded down to 10 for scale -1
-        builder.append_variant(VariantDecimal16::try_new(1235, 3).unwrap().into()); // 1.235 rounded down to 0 for scale -1
-        builder.append_variant(VariantDecimal16::try_new(5235, 3).unwrap().into()); // 5.235 rounded up to 10 for scale -1
+    fn test_variant_get_numeric_as_float64() {
+        let mut builder = VariantArrayBuilder::new(8);
+        builder.append_variant(Variant::Int8(1));
+        builder.append_variant(Variant::Int16(-2));
+        builder.append_variant(Variant::Int32(3));
+        builder.append_variant(Variant::Int64(4));
+        builder.append_variant(Variant::Float(0.5));
+        builder.append_variant(Variant::Double(1.25));
+        builder.append_variant(VariantDecimal4::try_new(123, 2).unwrap().into());
+        builder.append_variant(VariantDecimal16::try_new(-5, 1).unwrap().into());
+        builder.append_variant(Variant::from("not a number"));
         let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
-        let field = Field::new("result", DataType::Decimal256(76, -1), true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        let field = Field::new("result", DataType::Float64, true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field.clone())));
         let result = variant_get(&variant_array, options).unwrap();
-        let result = result.as_any().downcast_ref::<Decimal256Array>().unwrap();
+        let expected: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(1.0),
+            Some(-2.0),
+            Some(3.0),
+            Some(4.0),
+            Some(0.5),
+            Some(1.25),
+            Some(1.23),
+            Some(-0.5),
+            None,
+        ]));
+        assert_eq!(&result, &expected);
 
-        assert_eq!(result.precision(), 76);
-        assert_eq!(result.scale(), -1);
-        assert_eq!(result.value(0), i256::from_i128(124));
-        assert_eq!(result.value(1), i256::from_i128(125));
-        assert_eq!(result.value(2), i256::from_i128(-124));
-        assert_eq!(result.value(3), i256::from_i128(-125));
-        assert_eq!(result.value(4), i256::from_i128(1));
-        assert!(result.is_valid(5));
-        assert_eq!(result.value(5), i256::from_i128(0));
-        assert_eq!(result.value(6), i256::from_i128(1));
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(CastOptions {
+                safe: false,
+                ..Default::default()
+            });
+        let err = variant_get(&variant_array, options).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Failed to extract primitive of type Float64"),
+            "actual: {err}"
+        );
     }
 
     #[test]
-    fn get_decimal256_precision_overflow_safe() {
-        // Exceed Decimal128 max precision (38) after scaling
-        let mut builder = crate::VariantArrayBuilder::new(2);
-        builder.append_variant(
-            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 1)
-                .unwrap()
-                .into(),
+    fn test_variant_get_decimal_as_floats() {
+        let variant_array: ArrayRef = ArrayRef::from(VariantArray::from_iter([
+            Variant::from(VariantDecimal4::try_new(125, 1).unwrap()),
+            Variant::from(VariantDecimal8::try_new(-25, 2).unwrap()),
+            Variant::from(VariantDecimal16::try_new(3, 0).unwrap()),
+        ]));
+
+        let expected: ArrayRef = Arc::new(Float16Array::from(vec![
+            half::f16::from_f64(12.5),
+            half::f16::from_f64(-0.25),
+            half::f16::from_f64(3.0),
+        ]));
+        assert_eq!(
+            &get(&variant_array, "", Some(DataType::Float16), true).unwrap(),
+            &expected
         );
-        builder.append_variant(
-            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 0)
-                .unwrap()
-                .into(),
+        let expected: ArrayRef = Arc::new(Float32Array::from(vec![12.5, -0.25, 3.0]));
+        assert_eq!(
+            &get(&variant_array, "", Some(Float32), true).unwrap(),
+            &expected
+        );
+        let expected: ArrayRef = Arc::new(Float64Array::from(vec![12.5, -0.25, 3.0]));
+        assert_eq!(
+            &get(&variant_array, "", Some(Float64), true).unwrap(),
+            &expected
         );
-        let variant_array: ArrayRef = ArrayRef::from(builder.build());
-
-        let field = Field::new("result", DataType::Decimal256(76, 39), true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
-        let result = variant_get(&variant_array, options).unwrap();
-        let result = result.as_any().downcast_ref::<Decimal256Array>().unwrap();
-
-        // Input is Decimal16 with integer = 10^38-1 and scale = 1, target scale = 39
-        // So expected integer is (10^38-1) * 10^(39-1) = (10^38-1) * 10^38
-        let base = i256::from_i128(10);
-        let factor = base.checked_pow(38).unwrap();
-        let expected = i256::from_i128(VariantDecimal16::MAX_UNSCALED_VALUE)
-            .checked_mul(factor)
-            .unwrap();
-        assert_eq!(result.value(0), expected);
-        assert!(result.is_null(1));
     }
 
     #[test]
-    fn get_decimal256_precision_overflow_unsafe_errors() {
-        // Exceed Decimal128 max precision (38) after scaling
-        let mut builder = crate::VariantArrayBuilder::new(2);
-        builder.append_variant(
-            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 1)
-                .unwrap()
-                .into(),
-        );
-        builder.append_variant(
-            VariantDecimal16::try_new(VariantDecimal16::MAX_UNSCALED_VALUE, 0)
-                .unwrap()
-                .into(),
-        );
-        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+    fn test_cast_variant() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("1"),
+            Some("2.5"),
+            Some("10000000000"),
+            None,
+            Some(r#""three""#),
+        ]));
+        let array = json_to_variant(&json).unwrap();
 
-        let field = Field::new("result", DataType::Decimal256(76, 39), true);
-        let cast_options = CastOptions {
+        let result = cast_variant(&array, &DataType::Float64, &CastOptions::default()).unwrap();
+        let expected: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(1.0),
+            Some(2.5),
+            Some(1e10),
+            None,
+            None,
+        ]));
+        assert_eq!(&result, &expected);
+
+        let strict = CastOptions {
             safe: false,
             ..Default::default()
         };
-        let options = GetOptions::new()
-            .with_as_type(Some(FieldRef::from(field)))
-            .with_cast_options(cast_options);
-        let err = variant_get(&variant_array, options).unwrap_err();
-
-        assert!(err.to_string().contains(
-            "Failed to cast to Decimal256(precision=76, scale=39) from variant Decimal16"
-        ));
+        let err = cast_variant(&array, &DataType::Float64, &strict).unwrap_err();
+        assert!(
+            err.to_string().contains("Float64 from variant ShortString"),
+            "actual: {err}"
+        );
     }
 
     #[test]
-    fn get_non_supported_temporal_types_error() {
-        let values = vec![None, Some(Variant::Null), Some(Variant::BooleanFalse)];
-        let variant_array: ArrayRef = ArrayRef::from(VariantArray::from_iter(values));
-
-        let test_cases = vec![
-            FieldRef::from(Field::new(
-                "result",
-                DataType::Duration(TimeUnit::Microsecond),
-                true,
-            )),
-            FieldRef::from(Field::new(
-                "result",
-                DataType::Interval(IntervalUnit::YearMonth),
-                true,
-            )),
-        ];
+    fn test_variant_get_numeric_as_float32() {
+        let mut builder = VariantArrayBuilder::new(7);
+        builder.append_variant(Variant::Int32(16_777_217));
+        builder.append_variant(Variant::Int64(-3));
+        builder.append_variant(Variant::Float(0.5));
+        builder.append_variant(Variant::Double(1.25));
+        builder.append_variant(VariantDecimal8::try_new(-125, 2).unwrap().into());
+        builder.append_variant(Variant::Double(f64::INFINITY));
+        builder.append_variant(Variant::Double(1e39));
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+        let shredded = ArrayRef::from(
+            shred_variant(
+                &VariantArray::try_new(&variant_array).unwrap(),
+                &DataType::Float64,
+            )
+            .unwrap(),
+        );
 
-        for field in test_cases {
-            let options = GetOptions::new().with_as_type(Some(field));
-            let err = variant_get(&variant_array, options).unwrap_err();
+        // Doubles beyond the f32 range are an overflow, other values may be rounded
+        let expected: ArrayRef = Arc::new(Float32Array::from(vec![
+            Some(16_777_216.0),
+            Some(-3.0),
+            Some(0.5),
+            Some(1.25),
+            Some(-1.25),
+            Some(f32::INFINITY),
+            None,
+        ]));
+        for input in [&variant_array, &shredded] {
+            assert_eq!(
+                &get(input, "", Some(DataType::Float32), true).unwrap(),
+                &expected
+            );
+            let err = get(input, "", Some(DataType::Float32), false).unwrap_err();
             assert!(
-                err.to_string()
-                    .contains("Casting Variant to duration/interval types is not supported")
+                err.to_string().contains(
+                    "Failed to extract primitive of type Float32 from variant Double(1e39)"
+                ),
+                "actual: {err}"
             );
         }
     }
 
-    fn invalid_time_variant_array() -> ArrayRef {
+    #[test]
+    fn test_variant_get_non_string_as_utf8() {
         let mut builder = VariantArrayBuilder::new(3);
-        // 86401000000 is invalid for Time64Microsecond (max is 86400000000)
-        builder.append_variant(Variant::Int64(86401000000));
-        builder.append_variant(Variant::Int64(86401000000));
-        builder.append_variant(Variant::Int64(86401000000));
-        Arc::new(builder.build().into_inner())
+        builder.append_variant(Variant::from("hello"));
+        builder.append_variant(Variant::Int64(1234));
+        builder.new_object().with_field("a", 1).finish();
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let field = Field::new("result", DataType::Utf8, true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field.clone())));
+        let result = variant_get(&variant_array, options).unwrap();
+        let expected: ArrayRef = Arc::new(StringArray::from(vec![Some("hello"), None, None]));
+        assert_eq!(&result, &expected);
+
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(CastOptions {
+                safe: false,
+                ..Default::default()
+            });
+        let err = variant_get(&variant_array, options).unwrap_err();
+        assert!(
+            err.to_string().contains("from variant Int64(1234)"),
+            "actual: {err}"
+        );
     }
 
     #[test]
-    fn test_variant_get_error_when_cast_failure_and_safe_false() {
-        let variant_array = invalid_time_variant_array();
+    fn test_variant_get_long_strings_as_each_string_type() {
+        // Short strings, long strings (beyond the 63 byte short string limit, and beyond the 12 byte
+        // inline limit of string views) and non-strings
+        let long = "a string that is long enough to need the long string encoding".repeat(4);
+        let values = vec![Some("short"), Some(long.as_str()), None, Some("")];
+        let mut builder = VariantArrayBuilder::new(5);
+        for (i, value) in values.iter().enumerate() {
+            let mut object = builder.new_object();
+            match value {
+                Some(value) => object.insert("s", *value),
+                None => object.insert("s", i as i64),
+            }
+            object.finish();
+        }
+        builder.append_null();
+        let input = builder.build();
+        let schema = DataType::Struct(vec![Field::new("s", DataType::Utf8, true)].into());
+        let shredded = shred_variant(&input, &schema).unwrap();
+
+        let mut expected = values;
+        expected.push(None);
+        let expected_arrays: [ArrayRef; 3] = [
+            Arc::new(StringArray::from(expected.clone())),
+            Arc::new(LargeStringArray::from(expected.clone())),
+            Arc::new(StringViewArray::from(expected)),
+        ];
+        for input in [ArrayRef::from(input), ArrayRef::from(shredded)] {
+            for expected in &expected_arrays {
+                let field = Field::new("s", expected.data_type().clone(), true);
+                let options = GetOptions::new_with_path(VariantPath::try_from("s").unwrap())
+                    .with_as_type(Some(FieldRef::from(field)));
+                let result = variant_get(&input, options).unwrap();
+                assert_eq!(&result, expected);
+            }
+        }
+    }
 
-        let field = Field::new("result", DataType::Time64(TimeUnit::Microsecond), true);
-        let cast_options = CastOptions {
-            safe: false, // Will error on cast failure
-            ..Default::default()
-        };
-        let options = GetOptions::new()
+    #[test]
+    fn test_variant_get_field_as_boolean() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"active": true}"#),
+            Some(r#"{"active": false}"#),
+            Some(r#"{"active": "maybe"}"#),
+            Some(r#"{"inactive": true}"#),
+            None,
+        ]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+
+        let field = Field::new("active", DataType::Boolean, true);
+        let options = GetOptions::new_with_path(VariantPath::try_from("active").unwrap())
+            .with_as_type(Some(FieldRef::from(field.clone())));
+        let result = variant_get(&variant_array, options).unwrap();
+        let expected: ArrayRef = Arc::new(BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None, // not a boolean
+            None, // missing field
+            None, // null row
+        ]));
+        assert_eq!(&result, &expected);
+
+        let options = GetOptions::new_with_path(VariantPath::try_from("active").unwrap())
             .with_as_type(Some(FieldRef::from(field)))
-            .with_cast_options(cast_options);
+            .with_cast_options(CastOptions {
+                safe: false,
+                ..Default::default()
+            });
         let err = variant_get(&variant_array, options).unwrap_err();
         assert!(
-            err.to_string().contains(
-                "Cast error: Failed to extract primitive of type Time64(µs) from variant Int64(86401000000) at path VariantPath([])"
-            ),
-            "actual: {err}",
+            err.to_string()
+                .contains("Failed to extract primitive of type Boolean"),
+            "actual: {err}"
         );
     }
 
     #[test]
-    fn test_variant_get_return_null_when_cast_failure_and_safe_true() {
-        let variant_array = invalid_time_variant_array();
+    fn test_variant_get_as_variant_nulls_missing_paths() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"b": 1}}"#),
+            Some(r#"{"a": {"c": 2}}"#),
+            Some(r#"{"a": {"b": null}}"#),
+            Some(r#"{"a": 3}"#),
+            None,
+        ]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+
+        let options = GetOptions::new_with_path(VariantPath::try_from("a.b").unwrap());
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = VariantArray::try_new(&result).unwrap();
+
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.value(0), Variant::Int8(1));
+        assert!(result.is_null(1)); // missing field
+        assert!(result.is_valid(2));
+        assert_eq!(result.value(2), Variant::Null); // explicit JSON null
+        assert!(result.is_null(3)); // not an object
+        assert!(result.is_null(4)); // null row
+    }
+
+    #[test]
+    fn test_variant_get_negative_index() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"items": [1, 2, 3]}"#),
+            Some(r#"{"items": [4]}"#),
+            Some(r#"{"items": []}"#),
+            Some(r#"{"items": "not a list"}"#),
+            Some(r#"{"other": [5]}"#),
+            None,
+        ]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+
+        for (path, expected) in [
+            (
+                "$.items[-1]",
+                vec![Some(3), Some(4), None, None, None, None],
+            ),
+            ("$.items[-2]", vec![Some(2), None, None, None, None, None]),
+            ("$.items[-3]", vec![Some(1), None, None, None, None, None]),
+            ("$.items[-4]", vec![None, None, None, None, None, None]),
+        ] {
+            let options = GetOptions::new_with_path(VariantPath::try_from(path).unwrap())
+                .with_as_data_type(Int64);
+            let result = variant_get(&variant_array, options).unwrap();
+            let expected: ArrayRef = Arc::new(Int64Array::from(expected));
+            assert_eq!(&result, &expected, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_variant_get_wildcard() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"tags": [1, 2, "three"]}"#),
+            Some(r#"{"tags": []}"#),
+            Some(r#"{"tags": "not a list"}"#),
+            Some(r#"{"other": [5]}"#),
+            None,
+        ]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
 
-        let field = Field::new("result", DataType::Time64(TimeUnit::Microsecond), true);
-        let cast_options = CastOptions {
-            safe: true, // Will return null on cast failure
-            ..Default::default()
-        };
-        let options = GetOptions::new()
-            .with_as_type(Some(FieldRef::from(field)))
-            .with_cast_options(cast_options);
-        let result = variant_get(&variant_array, options).unwrap();
-        assert_eq!(3, result.len());
+        let options = GetOptions::new_with_path(VariantPath::try_from("$.tags[*]").unwrap())
+            .with_as_data_type(Int64);
+        let result = variant_get(&variant_array, options.clone()).unwrap();
 
-        for i in 0..3 {
-            assert!(result.is_null(i));
-        }
+        let expected = ListArray::from_iter_primitive::<arrow::datatypes::Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), None]),
+            Some(vec![]),
+            None,
+            None,
+            None,
+        ]);
+        assert_eq!(result.as_list::<i32>(), &expected);
+
+        // Shredded lists are expanded the same way
+        let schema = DataType::Struct(
+            vec![Field::new_list(
+                "tags",
+                Field::new_list_field(Int64, true),
+                true,
+            )]
+            .into(),
+        );
+        let variant_array = VariantArray::try_new(&variant_array).unwrap();
+        let shredded = ArrayRef::from(shred_variant(&variant_array, &schema).unwrap());
+        let result = variant_get(&shredded, options).unwrap();
+        assert_eq!(result.as_list::<i32>(), &expected);
     }
 
     #[test]
-    fn test_perfect_shredding_returns_same_arc_ptr() {
-        let variant_array = perfectly_shredded_int32_variant_array();
-
-        let variant_array_ref = VariantArray::try_new(&variant_array).unwrap();
-        let typed_value_arc = variant_array_ref.typed_value_field().unwrap().clone();
+    fn test_variant_get_wildcard_nested() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            r#"{"items": [{"name": "a", "sizes": [1, 2]}, {"name": "b"}, {"sizes": [3]}]}"#,
+            r#"{"items": [{"name": 1}]}"#,
+        ]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
 
-        let field = Field::new("result", DataType::Int32, true);
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field)));
+        // a path after the wildcard is applied to each element
+        let options = GetOptions::new_with_path(VariantPath::try_from("items[*].name").unwrap())
+            .with_as_type(Some(FieldRef::from(Field::new(
+                "result",
+                DataType::Utf8,
+                true,
+            ))));
         let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_list::<i32>();
+        assert_eq!(result.value_offsets(), &[0, 3, 4]);
+        let names = result.values().as_string::<i32>();
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            vec![Some("a"), Some("b"), None, None]
+        );
 
-        assert!(Arc::ptr_eq(&typed_value_arc, &result));
+        // multiple wildcards produce nested lists
+        let options =
+            GetOptions::new_with_path(VariantPath::try_from("items[*].sizes[*]").unwrap())
+                .with_as_data_type(Int64);
+        let result = variant_get(&variant_array, options).unwrap();
+        let outer = result.as_list::<i32>();
+        assert_eq!(outer.value_offsets(), &[0, 3, 4]);
+        let inner = outer.values().as_list::<i32>();
+        let expected = ListArray::from_iter_primitive::<arrow::datatypes::Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+            None,
+        ]);
+        assert_eq!(inner, &expected);
     }
 
     #[test]
-    fn test_perfect_shredding_three_typed_value_columns() {
-        // Column 1: perfectly shredded primitive with all nulls
-        let all_nulls_values: Arc<Int32Array> = Arc::new(Int32Array::from(vec![
-            Option::<i32>::None,
-            Option::<i32>::None,
-            Option::<i32>::None,
+    fn test_variant_get_wildcard_as_variant() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"[1, "two", {"three": 3}]"#),
+            Some("42"),
         ]));
-        let all_nulls_erased: ArrayRef = all_nulls_values.clone();
-        let all_nulls_field =
-            ShreddedVariantFieldArray::from_parts(None, Some(all_nulls_erased.clone()), None);
-        let all_nulls_type = all_nulls_field.data_type().clone();
-        let all_nulls_struct: ArrayRef = ArrayRef::from(all_nulls_field);
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
 
-        // Column 2: perfectly shredded primitive with some nulls
-        let some_nulls_values: Arc<Int32Array> =
-            Arc::new(Int32Array::from(vec![Some(10), None, Some(30)]));
-        let some_nulls_erased: ArrayRef = some_nulls_values.clone();
-        let some_nulls_field =
-            ShreddedVariantFieldArray::from_parts(None, Some(some_nulls_erased.clone()), None);
-        let some_nulls_type = some_nulls_field.data_type().clone();
-        let some_nulls_struct: ArrayRef = ArrayRef::from(some_nulls_field);
+        let options = GetOptions::new_with_path(VariantPath::try_from("[*]").unwrap());
+        let result = variant_get(&variant_array, options).unwrap();
+        let result = result.as_list::<i32>();
+        assert!(result.is_valid(0));
+        assert!(result.is_null(1));
 
-        // Column 3: perfectly shredded nested struct
-        let inner_values: Arc<Int32Array> =
-            Arc::new(Int32Array::from(vec![Some(111), None, Some(333)]));
-        let inner_erased: ArrayRef = inner_values.clone();
-        let inner_field =
-            ShreddedVariantFieldArray::from_parts(None, Some(inner_erased.clone()), None);
-        let inner_field_type = inner_field.data_type().clone();
-        let inner_struct_array: ArrayRef = ArrayRef::from(inner_field);
+        let DataType::List(item_field) = result.data_type() else {
+            panic!("expected a list, got {}", result.data_type());
+        };
+        assert!(
+            item_field
+                .try_extension_type::<crate::VariantType>()
+                .is_ok()
+        );
 
-        let nested_struct = Arc::new(
-            StructArray::try_new(
-                Fields::from(vec![Field::new("inner", inner_field_type, true)]),
-                vec![inner_struct_array],
-                None,
-            )
-            .unwrap(),
+        let values = VariantArray::try_new(result.values()).unwrap();
+        assert_eq!(values.len(), 3);
+        assert_eq!(values.value(0), Variant::from(1i8));
+        assert_eq!(values.value(1), Variant::from("two"));
+        assert_eq!(
+            values.value(2).get_object_field("three"),
+            Some(Variant::from(3i8))
         );
-        let nested_struct_erased: ArrayRef = nested_struct.clone();
-        let struct_field =
-            ShreddedVariantFieldArray::from_parts(None, Some(nested_struct_erased.clone()), None);
-        let struct_field_type = struct_field.data_type().clone();
-        let struct_field_struct: ArrayRef = ArrayRef::from(struct_field);
+    }
 
-        // Assemble the top-level typed_value struct with the three columns above
-        let typed_value_struct = StructArray::try_new(
-            Fields::from(vec![
-                Field::new("all_nulls", all_nulls_type, true),
-                Field::new("some_nulls", some_nulls_type, true),
-                Field::new("struct_field", struct_field_type, true),
-            ]),
-            vec![all_nulls_struct, some_nulls_struct, struct_field_struct],
-            None,
-        )
-        .unwrap();
+    #[test]
+    fn test_variant_get_timestamp_unit_conversion_and_strings() {
+        use arrow::array::{TimestampMicrosecondArray, TimestampMillisecondArray};
+        use chrono::DateTime;
 
-        let metadata = BinaryViewArray::from_iter_values(std::iter::repeat_n(
-            EMPTY_VARIANT_METADATA_BYTES,
-            all_nulls_values.len(),
-        ));
-        let variant_array: ArrayRef =
-            VariantArray::from_parts(metadata, None, Some(Arc::new(typed_value_struct)), None)
-                .into();
+        let micros = DateTime::from_timestamp_micros(1_700_000_000_123_456).unwrap();
+        let exact_nanos = DateTime::from_timestamp_nanos(1_700_000_000_123_456_000);
+        let inexact_nanos = DateTime::from_timestamp_nanos(1_700_000_000_123_456_789);
 
-        // Case 1: all-null primitive column should reuse the typed_value Arc directly
-        let all_nulls_field_ref = FieldRef::from(Field::new("result", DataType::Int32, true));
-        let all_nulls_result = variant_get(
-            &variant_array,
-            GetOptions::new_with_path(VariantPath::try_from("all_nulls").unwrap())
-                .with_as_type(Some(all_nulls_field_ref)),
-        )
-        .unwrap();
-        assert!(Arc::ptr_eq(&all_nulls_result, &all_nulls_erased));
+        let mut builder = crate::VariantArrayBuilder::new(7);
+        builder.append_variant(Variant::TimestampMicros(micros));
+        builder.append_variant(Variant::TimestampNanos(exact_nanos));
+        builder.append_variant(Variant::TimestampNanos(inexact_nanos));
+        builder.append_variant(Variant::from("2023-11-14T23:13:20.123456+01:00"));
+        builder.append_variant(Variant::from("2023-11-14T22:13:20.123456")); // no offset
+        builder.append_variant(Variant::from("not a timestamp"));
+        builder.append_null();
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
-        // Case 2: primitive column with some nulls should also reuse its typed_value Arc
-        let some_nulls_field_ref = FieldRef::from(Field::new("result", DataType::Int32, true));
-        let some_nulls_result = variant_get(
+        // The requested timezone is attached to the output; values are always UTC instants
+        let result = get(
             &variant_array,
-            GetOptions::new_with_path(VariantPath::try_from("some_nulls").unwrap())
-                .with_as_type(Some(some_nulls_field_ref)),
+            "",
+            Some(DataType::Timestamp(
+                TimeUnit::Microsecond,
+                Some("+05:30".into()),
+            )),
+            true,
         )
         .unwrap();
-        assert!(Arc::ptr_eq(&some_nulls_result, &some_nulls_erased));
+        let expected = TimestampMicrosecondArray::from(vec![
+            Some(1_700_000_000_123_456),
+            Some(1_700_000_000_123_456),
+            None,
+            Some(1_700_000_000_123_456),
+            None,
+            None,
+            None,
+        ])
+        .with_timezone("+05:30");
+        assert_eq!(result.as_primitive(), &expected);
 
-        // Case 3: struct column should return a StructArray composed from the nested field
-        let struct_child_fields = Fields::from(vec![Field::new("inner", DataType::Int32, true)]);
-        let struct_field_ref = FieldRef::from(Field::new(
-            "result",
-            DataType::Struct(struct_child_fields.clone()),
-            true,
+        // Every value above has sub-millisecond precision, so none converts to millis exactly
+        let millis_utc = DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()));
+        let result = get(&variant_array, "", Some(millis_utc.clone()), true).unwrap();
+        let expected = TimestampMillisecondArray::from(vec![None::<i64>; 7]).with_timezone("UTC");
+        assert_eq!(result.as_primitive(), &expected);
+
+        // Values that are whole milliseconds convert in every unit and representation
+        let mut builder = crate::VariantArrayBuilder::new(4);
+        builder.append_variant(Variant::TimestampMicros(
+            DateTime::from_timestamp_micros(1_700_000_000_123_000).unwrap(),
         ));
-        let struct_result = variant_get(
+        builder.append_variant(Variant::TimestampNanos(DateTime::from_timestamp_nanos(
+            1_700_000_000_456_000_000,
+        )));
+        builder.append_variant(Variant::from("2023-11-14T23:13:20.789+01:00"));
+        builder.append_null();
+        let whole_millis: ArrayRef = ArrayRef::from(builder.build());
+        let result = get(&whole_millis, "", Some(millis_utc), false).unwrap();
+        let expected = TimestampMillisecondArray::from(vec![
+            Some(1_700_000_000_123),
+            Some(1_700_000_000_456),
+            Some(1_700_000_000_789),
+            None,
+        ])
+        .with_timezone("UTC");
+        assert_eq!(result.as_primitive(), &expected);
+
+        // NTZ targets only accept strings without an offset
+        let result = get(
             &variant_array,
-            GetOptions::new_with_path(VariantPath::try_from("struct_field").unwrap())
-                .with_as_type(Some(struct_field_ref)),
+            "",
+            Some(DataType::Timestamp(TimeUnit::Microsecond, None)),
+            true,
         )
         .unwrap();
-        let struct_array = struct_result
-            .as_any()
-            .downcast_ref::<StructArray>()
-            .unwrap();
-        assert_eq!(struct_array.len(), 3);
-        assert_eq!(struct_array.null_count(), 0);
-
-        let inner_values_result = struct_array
-            .column(0)
-            .as_any()
-            .downcast_ref::<Int32Array>()
-            .unwrap();
-        assert_eq!(inner_values_result.len(), 3);
-        assert_eq!(inner_values_result.value(0), 111);
-        assert!(inner_values_result.is_null(1));
-        assert_eq!(inner_values_result.value(2), 333);
+        let expected = TimestampMicrosecondArray::from(vec![
+            None,
+            None,
+            None,
+            None,
+            Some(1_700_000_000_123_456),
+            None,
+            None,
+        ]);
+        assert_eq!(result.as_primitive(), &expected);
+
+        // Inexact values and unparseable strings are errors in strict mode
+        let micros_tz = DataType::Timestamp(TimeUnit::Microsecond, Some("+05:30".into()));
+        let err = get(
+            &variant_array.slice(2, 1),
+            "",
+            Some(micros_tz.clone()),
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("TimestampNanos"), "{err}");
+        let err = get(&variant_array.slice(5, 1), "", Some(micros_tz), false).unwrap_err();
+        assert!(err.to_string().contains("not a timestamp"), "{err}");
     }
 
     #[test]
-    fn test_variant_get_list_like_safe_cast() {
-        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
-            r#"{"outer":{"list":[1, "two", 3]}}"#,
-            r#"{"outer":{"list":"not a list"}}"#,
-        ]));
-        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+    fn test_variant_get_date32_from_dates_timestamps_and_strings() {
+        use chrono::{DateTime, NaiveDate};
 
-        let element_array: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, Some(3)]));
-        let field = Arc::new(Field::new("item", Int64, true));
+        let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        // 2024-03-15T23:59:59.999999Z truncates to 2024-03-15
+        let timestamp = DateTime::from_timestamp_micros(1_710_547_199_999_999).unwrap();
+        // 1969-12-31T12:00:00 truncates to the day before the epoch
+        let before_epoch = DateTime::from_timestamp(-43_200, 0).unwrap().naive_utc();
 
-        let expectations = vec![
-            (
-                DataType::List(field.clone()),
-                Arc::new(ListArray::new(
-                    field.clone(),
-                    OffsetBuffer::new(ScalarBuffer::from(vec![0, 3, 3])),
-                    element_array.clone(),
-                    Some(NullBuffer::from(vec![true, false])),
-                )) as ArrayRef,
-            ),
-            (
-                DataType::LargeList(field.clone()),
-                Arc::new(LargeListArray::new(
-                    field.clone(),
-                    OffsetBuffer::new(ScalarBuffer::from(vec![0, 3, 3])),
-                    element_array.clone(),
-                    Some(NullBuffer::from(vec![true, false])),
-                )) as ArrayRef,
-            ),
-            (
-                DataType::ListView(field.clone()),
-                Arc::new(ListViewArray::new(
-                    field.clone(),
-                    ScalarBuffer::from(vec![0, 3]),
-                    ScalarBuffer::from(vec![3, 0]),
-                    element_array.clone(),
-                    Some(NullBuffer::from(vec![true, false])),
-                )) as ArrayRef,
-            ),
-            (
-                DataType::LargeListView(field.clone()),
-                Arc::new(LargeListViewArray::new(
-                    field,
-                    ScalarBuffer::from(vec![0, 3]),
-                    ScalarBuffer::from(vec![3, 0]),
-                    element_array,
-                    Some(NullBuffer::from(vec![true, false])),
-                )) as ArrayRef,
-            ),
-        ];
+        let mut builder = crate::VariantArrayBuilder::new(7);
+        builder.append_variant(Variant::from(date));
+        builder.append_variant(Variant::TimestampMicros(timestamp));
+        builder.append_variant(Variant::TimestampNtzMicros(before_epoch));
+        builder.append_variant(Variant::from("2024-03-15"));
+        builder.append_variant(Variant::from("15/03/2024"));
+        builder.append_variant(Variant::Int32(19797));
+        builder.append_null();
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
-        for (request_type, expected) in expectations {
-            let options =
-                GetOptions::new_with_path(VariantPath::try_from("outer").unwrap().join("list"))
-                    .with_as_type(Some(FieldRef::from(Field::new(
-                        "result",
-                        request_type.clone(),
-                        true,
-                    ))));
+        let field = Field::new("result", DataType::Date32, true);
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(field.clone())));
+        let result = variant_get(&variant_array, options).unwrap();
+        let expected = Date32Array::from(vec![
+            Some(19797),
+            Some(19797),
+            Some(-1),
+            Some(19797),
+            None,
+            None,
+            None,
+        ]);
+        assert_eq!(result.as_primitive(), &expected);
+
+        let options = GetOptions::new()
+            .with_as_type(Some(FieldRef::from(field)))
+            .with_cast_options(CastOptions {
+                safe: false,
+                ..Default::default()
+            });
+        let err = variant_get(&variant_array, options).unwrap_err();
+        assert!(err.to_string().contains("15/03/2024"), "{err}");
+    }
 
-            let result = variant_get(&variant_array, options).unwrap();
-            assert_eq!(result.data_type(), expected.data_type());
-            assert_eq!(&result, &expected);
-        }
+    #[test]
+    fn test_variant_get_unshredded_binary() {
+        let blobs: [&[u8]; 3] = [b"\x00\x01\x02\xff", b"", b"opaque"];
 
-        for (idx, expected) in [
-            (0, vec![Some(1), None]),
-            (1, vec![None, None]),
-            (2, vec![Some(3), None]),
-        ] {
-            let index_options = GetOptions::new_with_path(
-                VariantPath::try_from("outer")
-                    .unwrap()
-                    .join("list")
-                    .join(idx),
-            )
-            .with_as_type(Some(FieldRef::from(Field::new(
-                "result",
-                DataType::Int64,
-                true,
-            ))));
-            let index_result = variant_get(&variant_array, index_options).unwrap();
-            let index_expected: ArrayRef = Arc::new(Int64Array::from(expected));
-            assert_eq!(&index_result, &index_expected);
+        let mut builder = crate::VariantArrayBuilder::new(5);
+        for blob in blobs {
+            let mut obj = builder.new_object();
+            obj.insert("blob", blob);
+            obj.finish();
         }
+        builder.new_object().with_field("blob", "text").finish();
+        builder.append_null();
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
+
+        let expected: Vec<_> = blobs.into_iter().map(Some).chain([None, None]).collect();
+
+        let result = get(&variant_array, "blob", Some(DataType::Binary), true).unwrap();
+        assert_eq!(
+            result.as_binary::<i32>(),
+            &BinaryArray::from(expected.clone())
+        );
+
+        let result = get(&variant_array, "blob", Some(DataType::LargeBinary), true).unwrap();
+        assert_eq!(result.as_binary::<i64>(), &LargeBinaryArray::from(expected));
+
+        let err = get(&variant_array, "blob", Some(DataType::Binary), false).unwrap_err();
+        assert!(err.to_string().contains("ShortString"), "{err}");
     }
 
     #[test]
-    fn test_variant_get_nested_list() {
-        use arrow::datatypes::Int64Type;
+    fn test_variant_get_uint64_type_mismatch() {
+        let mut builder = crate::VariantArrayBuilder::new(4);
+        builder.append_variant(Variant::Int64(7));
+        builder.append_variant(Variant::from(u64::MAX as f64));
+        builder.append_null();
+        builder.append_variant(Variant::from("7"));
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
-        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
-            r#"[[1, 2], [3]]"#,
-            r#"[[4], "not a list", [5, 6]]"#,
-        ]));
-        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+        let result = get(&variant_array, "", Some(DataType::UInt64), true).unwrap();
+        let expected = arrow::array::UInt64Array::from(vec![Some(7), None, None, None]);
+        assert_eq!(result.as_primitive(), &expected);
 
-        let inner_field = Arc::new(Field::new("item", Int64, true));
-        let outer_field = Arc::new(Field::new(
-            "item",
-            DataType::List(inner_field.clone()),
-            true,
-        ));
-        let request_type = DataType::List(outer_field.clone());
+        let err = get(&variant_array, "", Some(DataType::UInt64), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: row 1: Failed to extract primitive of type UInt64 from variant Double(1.8446744073709552e19) at path $"
+        );
+    }
 
-        let options = GetOptions::new().with_as_type(Some(FieldRef::from(Field::new(
-            "result",
-            request_type,
-            true,
-        ))));
-        let result = variant_get(&variant_array, options).unwrap();
-        let outer = result.as_list::<i32>();
+    #[test]
+    fn test_variant_get_negative_int_as_unsigned_does_not_wrap() {
+        let mut builder = crate::VariantArrayBuilder::new(4);
+        builder.append_variant(Variant::Int64(-1));
+        builder.append_variant(Variant::Int64(i64::MAX));
+        builder.append_variant(Variant::Int8(-128));
+        builder.append_variant(Variant::Int32(0));
+        let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
-        // Row 0: [[1, 2], [3]]
-        let row0 = outer.value(0);
-        let row0 = row0.as_list::<i32>();
-        assert_eq!(row0.len(), 2);
-        let elem0 = row0.value(0);
-        assert_eq!(elem0.as_primitive::<Int64Type>().values(), &[1, 2]);
-        let elem1 = row0.value(1);
-        assert_eq!(elem1.as_primitive::<Int64Type>().values(), &[3]);
+        let result = get(&variant_array, "", Some(DataType::UInt64), true).unwrap();
+        let expected =
+            arrow::array::UInt64Array::from(vec![None, Some(i64::MAX as u64), None, Some(0)]);
+        assert_eq!(result.as_primitive(), &expected);
 
-        // Row 1: [[4], null, [5, 6]] — "not a list" becomes null inner list
-        let row1 = outer.value(1);
-        let row1 = row1.as_list::<i32>();
-        assert_eq!(row1.len(), 3);
-        let elem0 = row1.value(0);
-        assert_eq!(elem0.as_primitive::<Int64Type>().values(), &[4]);
-        assert!(row1.is_null(1));
-        let elem2 = row1.value(2);
-        assert_eq!(elem2.as_primitive::<Int64Type>().values(), &[5, 6]);
+        let result = get(&variant_array, "", Some(DataType::UInt8), true).unwrap();
+        let expected = arrow::array::UInt8Array::from(vec![None, None, None, Some(0)]);
+        assert_eq!(result.as_primitive(), &expected);
+
+        let err = get(&variant_array, "", Some(DataType::UInt64), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: row 0: Failed to extract primitive of type UInt64 from variant Int64(-1) at path $"
+        );
     }
 
     #[test]
-    fn test_variant_get_list_like_unsafe_cast_errors_on_element_mismatch() {
-        let string_array: ArrayRef =
-            Arc::new(StringArray::from(vec![r#"[1, "two", 3]"#, "[4, 5]"]));
-        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
-
-        let item_field = Arc::new(Field::new("item", DataType::Int64, true));
-        let request_types = vec![
-            DataType::List(item_field.clone()),
-            DataType::LargeList(item_field.clone()),
-            DataType::ListView(item_field.clone()),
-            DataType::LargeListView(item_field),
+    fn test_variant_get_narrow_unsigned_bounds() {
+        let cases = [
+            (DataType::UInt8, Variant::Int16(255), Variant::Int16(256)),
+            (
+                DataType::UInt16,
+                Variant::Int32(65535),
+                Variant::Int32(65536),
+            ),
+            (
+                DataType::UInt32,
+                Variant::Int64(u32::MAX as i64),
+                Variant::Int64(u32::MAX as i64 + 1),
+            ),
         ];
+        for (data_type, max, overflow) in cases {
+            let input = ArrayRef::from(VariantArray::from_iter([
+                max.clone(),
+                Variant::Int8(-1),
+                overflow.clone(),
+            ]));
+            let result = get(&input, "", Some(data_type.clone()), true).unwrap();
+            let expected = arrow::compute::cast(
+                &Int64Array::from(vec![max.as_int64(), None, None]),
+                &data_type,
+            )
+            .unwrap();
+            assert_eq!(&result, &expected);
 
-        for request_type in request_types {
-            let options = GetOptions::new()
-                .with_as_type(Some(FieldRef::from(Field::new(
-                    "result",
-                    request_type.clone(),
-                    true,
-                ))))
-                .with_cast_options(cast_options.clone());
+            for value in [Variant::Int8(-1), overflow] {
+                let input = ArrayRef::from(VariantArray::from_iter([value.clone()]));
+                let err = get(&input, "", Some(data_type.clone()), false).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    format!(
+                        "Cast error: row 0: Failed to extract primitive of type {data_type} from variant {value:?} at path $"
+                    )
+                );
+            }
+        }
+    }
 
-            let err = variant_get(&variant_array, options).unwrap_err();
-            assert!(
-                err.to_string()
-                    .contains("Failed to extract primitive of type Int64")
-            );
+    #[test]
+    fn test_variant_get_sliced_input() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"b": 1}, "l": [1, 2]}"#),
+            None,
+            Some(r#"{"a": {"b": "two", "c": 2}, "l": [3]}"#),
+            Some(r#"{"a": 3, "l": "not a list"}"#),
+            Some(r#"{"a": {"b": 4}, "l": []}"#),
+            Some(r#"{"l": [5, 6, 7]}"#),
+            Some(r#"{"a": {"b": 7}}"#),
+        ]));
+        let unshredded = json_to_variant(&json).unwrap();
+        let schema = DataType::Struct(
+            vec![
+                Field::new_struct("a", vec![Field::new("b", Int64, true)], true),
+                Field::new_list("l", Field::new_list_field(Int64, true), true),
+            ]
+            .into(),
+        );
+        let shredded = shred_variant(&unshredded, &schema).unwrap();
+
+        let struct_type = DataType::Struct(vec![Field::new("b", Int64, true)].into());
+        let list_type = DataType::List(Arc::new(Field::new_list_field(Int64, true)));
+        let as_types = [None, Some(Int64), Some(struct_type), Some(list_type)];
+        let paths = ["", "a", "a.b", "l", "l[1]", "l[*]"];
+        for input in [unshredded, shredded] {
+            let input = ArrayRef::from(input);
+            for path in paths {
+                for as_type in &as_types {
+                    // Every combination succeeds, on shredded input too
+                    let full = get(&input, path, as_type.clone(), true).unwrap();
+                    for (offset, len) in [(1, 4), (2, 5), (3, 0), (6, 1)] {
+                        let sliced =
+                            get(&input.slice(offset, len), path, as_type.clone(), true).unwrap();
+                        let expected = full.slice(offset, len);
+                        let context = format!("{path:?} as {as_type:?} at {offset}..+{len}");
+                        match as_type {
+                            // Compare variants by value, since their encodings may differ
+                            None if matches!(sliced.data_type(), DataType::Struct(_)) => {
+                                let sliced = VariantArray::try_new(&sliced).unwrap();
+                                let expected = VariantArray::try_new(&expected).unwrap();
+                                let sliced = crate::unshred_variant(&sliced).unwrap();
+                                let expected = crate::unshred_variant(&expected).unwrap();
+                                let sliced: Vec<_> = sliced.iter().collect();
+                                let expected: Vec<_> = expected.iter().collect();
+                                assert_eq!(sliced, expected, "{context}");
+                            }
+                            _ => assert_eq!(&sliced, &expected, "{context}"),
+                        }
+                    }
+                }
+            }
         }
     }
 
     #[test]
-    fn test_variant_get_list_like_unsafe_cast_preserves_null_elements() {
-        let string_array: ArrayRef = Arc::new(StringArray::from(vec![r#"[1, null, 3]"#]));
-        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
-        let options = GetOptions::new()
-            .with_as_type(Some(FieldRef::from(Field::new(
-                "result",
-                DataType::List(Arc::new(Field::new("item", DataType::Int64, true))),
+    fn test_get_strategies_agree() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"b": 1, "c": "x"}, "l": [1, 2.5, "three"]}"#),
+            Some(r#"{"a": {"b": "two"}, "l": [{"b": 4}]}"#),
+            Some(r#"{"a": [1], "l": "not a list"}"#),
+            Some(r#"{"a": null, "l": []}"#),
+            Some("5"),
+            None,
+        ]));
+        let unshredded = json_to_variant(&json).unwrap();
+        let schema = DataType::Struct(
+            vec![Field::new_struct(
+                "a",
+                vec![Field::new("b", Int64, true)],
                 true,
-            ))))
-            .with_cast_options(cast_options);
+            )]
+            .into(),
+        );
+        let shredded = shred_variant(&unshredded, &schema).unwrap();
 
-        let result = variant_get(&variant_array, options).unwrap();
-        let list_array = result.as_any().downcast_ref::<ListArray>().unwrap();
-        let values = list_array
-            .values()
-            .as_any()
-            .downcast_ref::<Int64Array>()
+        let struct_type = DataType::Struct(
+            vec![
+                Field::new("b", Int64, true),
+                Field::new("c", DataType::Utf8, true),
+            ]
+            .into(),
+        );
+        let as_types = [
+            None,
+            Some(Int64),
+            Some(Float64),
+            Some(DataType::Utf8),
+            Some(struct_type),
+        ];
+        let paths = ["", "a", "a.b", "a.c", "l[1]", "l[0].b", "l[*]", "missing.b"];
+        for input in [unshredded, shredded] {
+            let input = ArrayRef::from(input);
+            for (path, as_type, safe) in paths
+                .iter()
+                .flat_map(|path| as_types.iter().map(move |as_type| (path, as_type)))
+                .flat_map(|(path, as_type)| [true, false].map(|safe| (path, as_type, safe)))
+            {
+                let get = |strategy| {
+                    let options = get_options(path, as_type.clone(), safe).with_strategy(strategy);
+                    variant_get(&input, options).map_err(|e| e.to_string())
+                };
+                let auto = get(GetStrategy::Auto);
+                let context = format!("path {path:?} as {as_type:?} safe {safe}");
+                assert_eq!(get(GetStrategy::Rowwise), auto, "{context}");
+                assert_eq!(get(GetStrategy::Columnar), auto, "{context}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_columnar_get_primitive_matches_rowwise() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("1"),
+            Some("-2.5"),
+            Some("true"),
+            Some(r#""text""#),
+            Some(r#""a longer string that does not fit in a short string value""#),
+            Some(r#"{"a": 1}"#),
+            Some("[1, 2]"),
+            Some("null"),
+            None,
+            Some("9223372036854775807"),
+        ]));
+        let variant_array = json_to_variant(&string_array).unwrap();
+
+        for data_type in [
+            DataType::Int64,
+            DataType::Int8,
+            DataType::UInt32,
+            DataType::Float64,
+            DataType::Boolean,
+            DataType::Utf8,
+            DataType::Utf8View,
+            DataType::Binary,
+        ] {
+            let cast_options = CastOptions::default();
+            let columnar = columnar_get_primitive(
+                &variant_array,
+                &data_type,
+                &cast_options,
+                ErrorContext::default(),
+                &[],
+                &[],
+            )
             .unwrap();
 
-        assert_eq!(values.len(), 3);
-        assert_eq!(values.value(0), 1);
-        assert!(values.is_null(1));
-        assert_eq!(values.value(2), 3);
+            let mut rowwise = make_primitive_variant_to_arrow_row_builder(
+                &data_type,
+                &cast_options,
+                variant_array.len(),
+            )
+            .unwrap();
+            for value in variant_array.iter() {
+                match value {
+                    Some(value) => rowwise.append_value(&value).map(|_| ()).unwrap(),
+                    None => rowwise.append_null().unwrap(),
+                }
+            }
+            let rowwise = rowwise.finish().unwrap();
+            assert_eq!(&columnar, &rowwise, "{data_type}");
+        }
+
+        // Objects and lists are decoded with their metadata for error messages
+        let options = GetOptions::new()
+            .with_as_data_type(Int64)
+            .with_cast_options(CastOptions {
+                safe: false,
+                ..Default::default()
+            });
+        let err = variant_get(&ArrayRef::from(variant_array.slice(5, 1)), options).unwrap_err();
+        assert!(err.to_string().contains(r#""a": Int8(1)"#), "{err}");
     }
 
     #[test]
-    fn test_variant_get_list_like_unsafe_cast_errors_on_non_list() {
-        let string_array: ArrayRef = Arc::new(StringArray::from(vec!["[1, 2]", "\"not a list\""]));
+    fn test_variant_get_non_nullable_field() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            r#"{"a": 1}"#,
+            r#"{"a": 2}"#,
+            r#"{"a": 3}"#,
+        ]));
         let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
+
+        let get = |path| {
+            let options = GetOptions::new_with_path(VariantPath::try_from(path).unwrap())
+                .with_as_type(Some(FieldRef::from(Field::new("result", Int64, false))));
+            variant_get(&variant_array, options)
         };
-        let item_field = Arc::new(Field::new("item", Int64, true));
-        let data_types = vec![
-            DataType::List(item_field.clone()),
-            DataType::LargeList(item_field.clone()),
-            DataType::ListView(item_field.clone()),
-            DataType::LargeListView(item_field),
-        ];
 
-        for data_type in data_types {
-            let options = GetOptions::new()
-                .with_as_type(Some(FieldRef::from(Field::new("result", data_type, true))))
-                .with_cast_options(cast_options.clone());
+        let result = get("a").unwrap();
+        assert!(result.nulls().is_none());
+        assert_eq!(result.as_ref(), &Int64Array::from(vec![1, 2, 3]));
 
-            let err = variant_get(&variant_array, options).unwrap_err();
-            assert!(
-                err.to_string()
-                    .contains("Failed to extract list from variant"),
-            );
-        }
+        let err = get("b").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot extract variant into non-nullable field 'result': 3 of 3 rows are NULL"
+        );
+
+        // A type mismatch that would null a row in safe mode is also an error
+        let string_array: ArrayRef =
+            Arc::new(StringArray::from(vec![r#"{"a": 1}"#, r#"{"a": "x"}"#]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+        let options = GetOptions::new_with_path(VariantPath::try_from("a").unwrap())
+            .with_as_type(Some(FieldRef::from(Field::new("result", Int64, false))));
+        let err = variant_get(&variant_array, options).unwrap_err();
+        assert!(err.to_string().contains("1 of 2 rows are NULL"), "{err}");
     }
 
     #[test]
-    fn test_variant_get_fixed_size_list_not_implemented() {
-        let string_array: ArrayRef = Arc::new(StringArray::from(vec!["[1, 2]", "\"not a list\""]));
+    fn test_variant_get_strict_error_reports_row_and_path() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            r#"{"a": {"b": 1}}"#,
+            r#"{"a": 2}"#,
+            r#"{"a": {"b": "x"}, "l": [{"b": 1}, {"b": "y"}]}"#,
+        ]));
         let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
-        let item_field = Arc::new(Field::new("item", Int64, true));
-        for safe in [true, false] {
-            let options = GetOptions::new()
-                .with_as_type(Some(FieldRef::from(Field::new(
-                    "result",
-                    DataType::FixedSizeList(item_field.clone(), 2),
-                    true,
-                ))))
-                .with_cast_options(CastOptions {
-                    safe,
-                    ..Default::default()
-                });
 
-            let err = variant_get(&variant_array, options).unwrap_err();
-            assert!(
-                err.to_string()
-                    .contains("Converting unshredded variant arrays to arrow fixed-size lists")
-            );
-        }
+        // Traversing into a non-object fails on the row that holds it
+        let err = get(&variant_array, "a.b", Some(Int64), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: row 1: Expected an object to access .b but found Int8(2) at path $.a.b"
+        );
+
+        // Wildcard errors report the full path, including the wildcard
+        let err = get(&variant_array, "l[*].b", Some(Int64), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: row 2: Failed to extract primitive of type Int64 from variant ShortString(ShortString(\"y\")) at path $.l[*].b"
+        );
     }
 }
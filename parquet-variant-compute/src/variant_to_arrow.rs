@@ -37,7 +37,7 @@ use arrow::compute::{CastOptions, DecimalCast};
 use arrow::datatypes::{self, DataType, DecimalType};
 use arrow::error::{ArrowError, Result};
 use arrow_schema::{FieldRef, Fields, TimeUnit};
-use parquet_variant::{Variant, VariantPath};
+use parquet_variant::{Variant, VariantMetadata, VariantPath, VariantPathElement};
 use std::sync::Arc;
 
 /// Builder for converting variant values into strongly typed Arrow arrays.
@@ -143,6 +143,8 @@ pub(crate) fn make_variant_to_arrow_row_builder<'a>(
         builder = WithPath(VariantPathRowBuilder {
             builder: Box::new(builder),
             path,
+            cast_options,
+            field_ids: FieldIdCache::default(),
         })
     };
 
@@ -666,6 +668,95 @@ impl<'a> ArrayVariantToArrowRowBuilder<'a> {
 pub(crate) struct VariantPathRowBuilder<'a> {
     builder: Box<VariantToArrowRowBuilder<'a>>,
     path: VariantPath<'a>,
+    cast_options: &'a CastOptions<'a>,
+    field_ids: FieldIdCache,
+}
+
+/// The field ids that the field names of a path resolve to in one metadata dictionary.
+///
+/// Consecutive rows commonly share identical metadata (e.g. rows built from the same JSON schema),
+/// so the ids are only resolved again when the metadata changes.
+#[derive(Default)]
+pub(crate) struct FieldIdCache {
+    /// The metadata the ids were resolved in
+    metadata: Vec<u8>,
+    /// The id of each path element's field name, or `None` if the element is not a field or its
+    /// name is not in the dictionary
+    field_ids: Vec<Option<u32>>,
+}
+
+impl FieldIdCache {
+    /// Returns the field ids of `path` in `metadata`, resolving them if `metadata` changed.
+    fn get(
+        &mut self,
+        metadata: &VariantMetadata<'_>,
+        path: &[VariantPathElement<'_>],
+    ) -> &[Option<u32>] {
+        let bytes = metadata.as_bytes();
+        if self.metadata != bytes {
+            self.metadata.clear();
+            self.metadata.extend_from_slice(bytes);
+            let field_ids = path.iter().map(|element| match element {
+                VariantPathElement::Field { name } => metadata.get_entry(name).map(|(id, _)| id),
+                VariantPathElement::FieldId { id } => Some(*id),
+                _ => None,
+            });
+            self.field_ids = field_ids.collect();
+        }
+        &self.field_ids
+    }
+
+    /// Follows the path into `value`, returning `None` if any step is missing or NULL.
+    ///
+    /// Stepping into a value of the wrong kind (e.g. a field of a list) also returns `None` with
+    /// safe casting, but is an error with strict casting.
+    pub(crate) fn follow_path<'m, 'v>(
+        &mut self,
+        value: Variant<'m, 'v>,
+        path: &[VariantPathElement<'_>],
+        cast_options: &CastOptions,
+    ) -> Result<Option<Variant<'m, 'v>>> {
+        // All objects nested in a value share its metadata, so one lookup serves the whole path
+        let field_ids = match &value {
+            Variant::Object(_) | Variant::List(_) => self.get(value.metadata(), path),
+            _ => &[],
+        };
+        let mut current = value;
+        for (i, element) in path.iter().enumerate() {
+            let next = match (element, &current) {
+                (
+                    VariantPathElement::Field { .. } | VariantPathElement::FieldId { .. },
+                    Variant::Object(object),
+                ) => field_ids[i].and_then(|field_id| object.get_by_field_id(field_id)),
+                (VariantPathElement::Index { index }, Variant::List(list)) => list.get(*index),
+                (VariantPathElement::IndexFromEnd { offset }, Variant::List(list)) => {
+                    list.len().checked_sub(*offset).and_then(|i| list.get(i))
+                }
+                (_, Variant::Null) => None,
+                (VariantPathElement::Field { .. } | VariantPathElement::FieldId { .. }, other)
+                    if !cast_options.safe =>
+                {
+                    return Err(ArrowError::CastError(format!(
+                        "Expected an object to access {element} but found {other:?}"
+                    )));
+                }
+                (
+                    VariantPathElement::Index { .. } | VariantPathElement::IndexFromEnd { .. },
+                    other,
+                ) if !cast_options.safe => {
+                    return Err(ArrowError::CastError(format!(
+                        "Expected a list to access {element} but found {other:?}"
+                    )));
+                }
+                _ => None,
+            };
+            let Some(next) = next else {
+                return Ok(None);
+            };
+            current = next;
+        }
+        Ok(Some(current))
+    }
 }
 
 impl<'a> VariantPathRowBuilder<'a> {
@@ -674,7 +765,10 @@ impl<'a> VariantPathRowBuilder<'a> {
     }
 
     fn append_value(&mut self, value: Variant<'_, '_>) -> Result<bool> {
-        if let Some(v) = value.get_path(&self.path) {
+        let value = self
+            .field_ids
+            .follow_path(value, &self.path, self.cast_options)?;
+        if let Some(v) = value {
             self.builder.append_value(v)
         } else {
             self.builder.append_null()?;
@@ -731,7 +825,7 @@ macro_rules! define_variant_to_primitive_builder {
                         Ok(false)
                     }
                     Err(_) => Err(ArrowError::CastError(format!(
-                        "Failed to extract primitive of type {type_name} from variant {value:?} at path VariantPath([])",
+                        "Failed to extract primitive of type {type_name} from variant {value:?}",
                         type_name = $type_name,
                         value = $value
                     ))),
@@ -0,0 +1,182 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for reporting the top-level type of each row of a VariantArray.
+
+use crate::VariantArray;
+use arrow::array::{Array, StringArray, StringBuilder};
+use arrow::datatypes::{DataType, TimeUnit};
+use arrow::error::Result;
+use parquet_variant::Variant;
+
+/// Returns the name of the top-level type of each row of `array`, e.g. `"object"`, `"array"`,
+/// `"int64"`, `"string"` or `"null"`. Null rows produce null entries.
+///
+/// The names follow the variant [encoding specification], except that short strings are
+/// reported as `"string"` and both boolean values as `"boolean"`. Only the top level of each row
+/// is inspected: the type of shredded rows is read from the data type of the `typed_value`
+/// column, and only the header of other values is decoded, so for example the fields of objects
+/// are never decoded.
+///
+/// [encoding specification]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
+pub fn variant_type_of(array: &VariantArray) -> Result<StringArray> {
+    let typed_value = array
+        .typed_value_field()
+        .and_then(|typed_value| Some((typed_value, shredded_type_name(typed_value.data_type())?)));
+
+    let mut builder = StringBuilder::with_capacity(array.len(), array.len() * 8);
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            builder.append_null();
+        } else if let Some((_, name)) =
+            typed_value.filter(|(typed_value, _)| typed_value.is_valid(i))
+        {
+            builder.append_value(name);
+        } else {
+            builder.append_value(type_name(&array.try_value(i)?));
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// The name of the variant type that values shredded as `data_type` have, or `None` if
+/// `data_type` is not a valid shredding type
+fn shredded_type_name(data_type: &DataType) -> Option<&'static str> {
+    Some(match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int8 => "int8",
+        DataType::Int16 => "int16",
+        DataType::Int32 => "int32",
+        DataType::Int64 => "int64",
+        DataType::Float16 | DataType::Float32 => "float",
+        DataType::Float64 => "double",
+        DataType::Decimal32(..) => "decimal4",
+        DataType::Decimal64(..) => "decimal8",
+        DataType::Decimal128(..) => "decimal16",
+        DataType::Date32 => "date",
+        DataType::Timestamp(TimeUnit::Microsecond, Some(_)) => "timestamp",
+        DataType::Timestamp(TimeUnit::Microsecond, None) => "timestamp_ntz",
+        DataType::Timestamp(TimeUnit::Nanosecond, Some(_)) => "timestamp_nanos",
+        DataType::Timestamp(TimeUnit::Nanosecond, None) => "timestamp_ntz_nanos",
+        DataType::Time64(TimeUnit::Microsecond) => "time",
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => "binary",
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => "string",
+        DataType::FixedSizeBinary(16) => "uuid",
+        DataType::Struct(_) => "object",
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::ListView(_)
+        | DataType::LargeListView(_)
+        | DataType::FixedSizeList(..) => "array",
+        _ => return None,
+    })
+}
+
+pub(crate) fn type_name(variant: &Variant<'_, '_>) -> &'static str {
+    match variant {
+        Variant::Null => "null",
+        Variant::BooleanTrue | Variant::BooleanFalse => "boolean",
+        Variant::Int8(_) => "int8",
+        Variant::Int16(_) => "int16",
+        Variant::Int32(_) => "int32",
+        Variant::Int64(_) => "int64",
+        Variant::Float(_) => "float",
+        Variant::Double(_) => "double",
+        Variant::Decimal4(_) => "decimal4",
+        Variant::Decimal8(_) => "decimal8",
+        Variant::Decimal16(_) => "decimal16",
+        Variant::Date(_) => "date",
+        Variant::TimestampMicros(_) => "timestamp",
+        Variant::TimestampNtzMicros(_) => "timestamp_ntz",
+        Variant::TimestampNanos(_) => "timestamp_nanos",
+        Variant::TimestampNtzNanos(_) => "timestamp_ntz_nanos",
+        Variant::Time(_) => "time",
+        Variant::Binary(_) => "binary",
+        Variant::String(_) | Variant::ShortString(_) => "string",
+        Variant::Uuid(_) => "uuid",
+        Variant::Object(_) => "object",
+        Variant::List(_) => "array",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{json_to_variant, shred_variant};
+    use arrow::array::ArrayRef;
+    use arrow::datatypes::Field;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_variant_type_of() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": 1}"#),
+            Some("[1, 2]"),
+            Some("10000000000"),
+            Some("1.5"),
+            Some(r#""a string that is too long to be a short string, for sure""#),
+            Some(r#""short""#),
+            Some("true"),
+            Some("null"),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+
+        let expected = StringArray::from(vec![
+            Some("object"),
+            Some("array"),
+            Some("int64"),
+            Some("double"),
+            Some("string"),
+            Some("string"),
+            Some("boolean"),
+            Some("null"),
+            None,
+        ]);
+        assert_eq!(variant_type_of(&array).unwrap(), expected);
+
+        // Shredded values report the type of the value, not of the shredding
+        let shredded = shred_variant(&array, &DataType::Int64).unwrap();
+        let result = variant_type_of(&shredded).unwrap();
+        assert_eq!(result.value(2), "int64");
+        assert_eq!(result.value(0), "object");
+        assert!(result.is_null(8));
+
+        // Shredded objects and lists are reported without being rebuilt
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": 1, "b": "x"}"#),
+            Some(r#"{"b": "y"}"#),
+            Some("[1, 2]"),
+            Some("2.5"),
+            None,
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let object = DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into());
+        let shredded = shred_variant(&array, &object).unwrap();
+        let expected = StringArray::from(vec![
+            Some("object"),
+            Some("object"),
+            Some("array"),
+            Some("double"),
+            None,
+        ]);
+        assert_eq!(variant_type_of(&shredded).unwrap(), expected);
+        let list = DataType::List(Arc::new(Field::new_list_field(DataType::Int64, true)));
+        let shredded = shred_variant(&array, &list).unwrap();
+        assert_eq!(variant_type_of(&shredded).unwrap(), expected);
+    }
+}
@@ -66,6 +66,22 @@ use serde_json::{Number, Value};
 pub trait JsonToVariant {
     /// Create a Variant from a JSON string
     fn append_json(&mut self, json: &str) -> Result<(), ArrowError>;
+
+    /// Create a Variant from an already-parsed JSON [`Value`], as [`append_json`] does.
+    ///
+    /// ```rust
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// # use parquet_variant_json::JsonToVariant;
+    /// let mut builder = VariantBuilder::new();
+    /// builder.append_json_value(&serde_json::json!({"a": [1, 300]}))?;
+    /// let (metadata, value) = builder.finish();
+    ///
+    /// let variant = Variant::try_new(&metadata, &value)?;
+    /// let list = variant.get_object_field("a").unwrap();
+    /// assert_eq!(list.get_list_element(1), Some(Variant::Int16(300)));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn append_json_value(&mut self, json: &Value) -> Result<(), ArrowError>;
 }
 
 impl<T: VariantBuilderExt> JsonToVariant for T {
@@ -76,6 +92,10 @@ impl<T: VariantBuilderExt> JsonToVariant for T {
         append_json(&json, self)?;
         Ok(())
     }
+
+    fn append_json_value(&mut self, json: &Value) -> Result<(), ArrowError> {
+        append_json(json, self)
+    }
 }
 
 fn variant_from_number<'m, 'v>(n: &Number) -> Result<Variant<'m, 'v>, ArrowError> {
@@ -102,6 +122,23 @@ fn variant_from_number<'m, 'v>(n: &Number) -> Result<Variant<'m, 'v>, ArrowError
     }
 }
 
+/// Appends an already-parsed JSON [`Value`] to `builder`, the same way [`JsonToVariant`] appends
+/// JSON text.
+///
+/// Integers are encoded with the narrowest integer type that fits, and other numbers as doubles.
+///
+/// ```rust
+/// # use parquet_variant::{Variant, VariantBuilder};
+/// # use parquet_variant_json::append_json;
+/// let mut builder = VariantBuilder::new();
+/// append_json(&serde_json::json!({"a": [1, 300]}), &mut builder)?;
+/// let (metadata, value) = builder.finish();
+///
+/// let variant = Variant::try_new(&metadata, &value)?;
+/// let list = variant.get_object_field("a").unwrap();
+/// assert_eq!(list.get_list_element(1), Some(Variant::Int16(300)));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn append_json(json: &Value, builder: &mut impl VariantBuilderExt) -> Result<(), ArrowError> {
     match json {
         Value::Null => builder.append_value(Variant::Null),
@@ -136,6 +173,7 @@ mod test {
     use arrow_schema::ArrowError;
     use parquet_variant::{
         ShortString, Variant, VariantBuilder, VariantDecimal4, VariantDecimal8, VariantDecimal16,
+        VariantPath,
     };
 
     struct JsonToVariantTest<'a> {
@@ -667,4 +705,34 @@ mod test {
         }
         .run()
     }
+
+    #[test]
+    fn test_append_json_value() -> Result<(), ArrowError> {
+        let json = serde_json::json!({
+            "name": "Alice",
+            "tags": ["a", null, true],
+            "stats": {"small": 1, "medium": 70000, "large": 5000000000i64, "ratio": 0.5},
+        });
+        let mut variant_builder = VariantBuilder::new();
+        variant_builder.append_json_value(&json)?;
+        let (metadata, value) = variant_builder.finish();
+        let variant = Variant::try_new(&metadata, &value)?;
+
+        let get = |path: &str| variant.get_path(&VariantPath::try_from(path).unwrap());
+        assert_eq!(get("name"), Some(Variant::from("Alice")));
+        assert_eq!(get("tags[0]"), Some(Variant::from("a")));
+        assert_eq!(get("tags[1]"), Some(Variant::Null));
+        assert_eq!(get("tags[-1]"), Some(Variant::BooleanTrue));
+        assert_eq!(get("stats.small"), Some(Variant::Int8(1)));
+        assert_eq!(get("stats.medium"), Some(Variant::Int32(70000)));
+        assert_eq!(get("stats.large"), Some(Variant::Int64(5000000000)));
+        assert_eq!(get("stats.ratio"), Some(Variant::Double(0.5)));
+        assert_eq!(variant.to_json_value()?, json);
+
+        // Same encoding as parsing the JSON text
+        let mut variant_builder = VariantBuilder::new();
+        variant_builder.append_json(&json.to_string())?;
+        assert_eq!(variant_builder.finish(), (metadata, value));
+        Ok(())
+    }
 }
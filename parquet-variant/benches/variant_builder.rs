@@ -507,6 +507,29 @@ fn bench_extend_metadata_builder(c: &mut Criterion) {
     });
 }
 
+// Appends many small values, with and without reserving capacity up front
+fn bench_reserve_capacity(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reserve_capacity");
+    let append = |mut variant: VariantBuilder| {
+        let mut list_builder = variant.new_list();
+        for i in 0..100_000 {
+            list_builder.append_value(i);
+        }
+        list_builder.finish();
+        hint::black_box(variant.finish());
+    };
+
+    group.bench_function("without_reservation", |b| {
+        b.iter(|| append(VariantBuilder::new()))
+    });
+    group.bench_function("with_reservation", |b| {
+        // Each Int32 takes 5 bytes, plus up to 4 bytes for its offset in the list
+        b.iter(|| append(VariantBuilder::with_capacity(100_000 * 9, 0)))
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_object_field_names_reverse_order,
@@ -518,7 +541,8 @@ criterion_group!(
     bench_object_list_partially_same_schema,
     bench_validation_validated_vs_unvalidated,
     bench_iteration_performance,
-    bench_extend_metadata_builder
+    bench_extend_metadata_builder,
+    bench_reserve_capacity
 );
 
 criterion_main!(benches);
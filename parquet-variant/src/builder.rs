@@ -294,6 +294,12 @@ impl ValueBuilder {
         self.0.len()
     }
 
+    /// Appends an already serialized variant value to the underlying buffer. The bytes are copied
+    /// as-is, without any validation.
+    pub fn append_encoded(&mut self, value: &[u8]) {
+        self.append_slice(value);
+    }
+
     /// Appends a variant to the builder.
     ///
     /// # Panics
@@ -545,6 +551,22 @@ impl<S: BuilderSpecificState> Drop for ParentState<'_, S> {
 ///   Some(Variant::from("Li"))
 /// );
 /// ```
+///
+/// Nested objects and lists can be chained too, using [`ObjectBuilder::with_object`] and
+/// [`ObjectBuilder::with_list`]
+/// ```
+/// # use parquet_variant::{Variant, VariantBuilder};
+/// let mut builder = VariantBuilder::new();
+/// builder.new_object()
+///   .with_field("name", "Jiaying")
+///   .with_object("address", |a| a.with_field("city", "Beijing"))
+///   .with_list("tags", |l| l.with_value("a").with_value("b"))
+///   .finish();
+/// let (metadata, value) = builder.finish();
+/// let variant = Variant::try_new(&metadata, &value).unwrap();
+/// let address = variant.get_object_field("address").unwrap();
+/// assert_eq!(address.get_object_field("city"), Some(Variant::from("Beijing")));
+/// ```
 /// # Example: Create a [`Variant::List`] (an Array)
 ///
 /// This example shows how to create an array of integers: `[1, 2, 3]`.
@@ -710,11 +732,25 @@ impl<S: BuilderSpecificState> Drop for ParentState<'_, S> {
 /// let (metadata, value) = builder.finish();
 /// let variant = Variant::try_new(&metadata, &value).unwrap();
 /// ```
+///
+/// # Repeated string values
+///
+/// Only field names are deduplicated, through the metadata dictionary. The [Variant spec] has no
+/// way for one value to refer to another: list elements must be stored back to back, and each
+/// variant is encoded independently of the others. So repeated string values (e.g. enum-like
+/// fields) are always stored inline, and this builder does not offer to intern them.
+///
+/// To store such values compactly, shred the field into a string column instead, e.g. with
+/// `shred_variant` from the `parquet-variant-compute` crate, so that Parquet can
+/// dictionary-encode the strings.
+///
+/// [Variant spec]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
 #[derive(Default, Debug)]
 pub struct VariantBuilder {
     value_builder: ValueBuilder,
     metadata_builder: WritableMetadataBuilder,
     validate_unique_fields: bool,
+    sorted_keys: bool,
 }
 
 impl VariantBuilder {
@@ -724,9 +760,21 @@ impl VariantBuilder {
             value_builder: ValueBuilder::new(),
             metadata_builder: WritableMetadataBuilder::default(),
             validate_unique_fields: false,
+            sorted_keys: false,
         }
     }
 
+    /// Create a new VariantBuilder with room for `value_bytes` bytes of encoded values and
+    /// `metadata_keys` unique field names, to avoid reallocating as values are appended.
+    ///
+    /// See also [`VariantBuilder::reserve`] and [`VariantBuilder::reserve_value_bytes`].
+    pub fn with_capacity(value_bytes: usize, metadata_keys: usize) -> Self {
+        let mut builder = Self::new();
+        builder.reserve_value_bytes(value_bytes);
+        builder.reserve(metadata_keys);
+        builder
+    }
+
     /// Create a new VariantBuilder with pre-existing [`VariantMetadata`].
     pub fn with_metadata(mut self, metadata: VariantMetadata) -> Self {
         self.metadata_builder.extend(metadata.iter());
@@ -744,6 +792,19 @@ impl VariantBuilder {
         self
     }
 
+    /// Guarantees that [`Self::finish`] produces a sorted metadata dictionary.
+    ///
+    /// Field names are normally stored in the order they are first inserted, and the dictionary is
+    /// only flagged as sorted if that order happens to be sorted (see [`Self::with_field_names`]).
+    /// With this option enabled, an unsorted dictionary is sorted when the builder is finished and
+    /// the value is re-encoded to refer to the new field ids. Readers can then look up field
+    /// names with a binary search (see [`VariantMetadata::get_entry`]), and engines that expect
+    /// the sorted flag can consume the result.
+    pub fn with_sorted_keys(mut self, sorted_keys: bool) -> Self {
+        self.sorted_keys = sorted_keys;
+        self
+    }
+
     /// This method pre-populates the field name directory in the Variant metadata with
     /// the specific field names, in order.
     ///
@@ -785,6 +846,13 @@ impl VariantBuilder {
         self.metadata_builder.field_names.reserve(capacity);
     }
 
+    /// This method reserves capacity for at least `additional` more bytes of encoded values,
+    /// which can improve performance when you know the approximate size of the values that will
+    /// be appended.
+    pub fn reserve_value_bytes(&mut self, additional: usize) {
+        self.value_builder.0.reserve(additional);
+    }
+
     /// Adds a single field name to the field name directory in the Variant metadata.
     ///
     /// This method does the same thing as [`VariantBuilder::with_field_names`] but adds one field name at a time.
@@ -853,11 +921,29 @@ impl VariantBuilder {
 
     /// Finish the builder and return the metadata and value buffers.
     pub fn finish(mut self) -> (Vec<u8>, Vec<u8>) {
+        let field_names = &self.metadata_builder.field_names;
+        let needs_sort =
+            self.sorted_keys && !field_names.is_empty() && !self.metadata_builder.is_sorted;
+        let sorted_names = needs_sort.then(|| {
+            let mut names: Vec<String> = field_names.iter().cloned().collect();
+            names.sort_unstable();
+            names
+        });
+
         self.metadata_builder.finish();
-        (
-            self.metadata_builder.into_inner(),
-            self.value_builder.into_inner(),
-        )
+        let metadata = self.metadata_builder.into_inner();
+        let value = self.value_builder.into_inner();
+        let Some(sorted_names) = sorted_names else {
+            return (metadata, value);
+        };
+
+        // Field ids of nested objects refer to the unsorted dictionary, so re-encode the value
+        let mut builder =
+            VariantBuilder::new().with_field_names(sorted_names.iter().map(|s| s.as_str()));
+        if !value.is_empty() {
+            builder.append_value(Variant::new(&metadata, &value));
+        }
+        builder.finish()
     }
 }
 
@@ -897,6 +983,137 @@ pub trait VariantBuilderExt {
     /// Creates a nested object builder. See e.g. [`VariantBuilder::new_object`]. Returns an error
     /// if the nested builder cannot be created, see e.g. [`ObjectBuilder::try_new_object`].
     fn try_new_object(&mut self) -> Result<ObjectBuilder<'_, Self::State<'_>>, ArrowError>;
+
+    /// Appends the decimal `integer * 10^-scale`, using the narrowest of [`Variant::Decimal4`],
+    /// [`Variant::Decimal8`] and [`Variant::Decimal16`] that can hold it.
+    ///
+    /// Returns an error if the value needs more than 38 digits, or `scale` exceeds 38.
+    ///
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder, VariantBuilderExt, VariantDecimal4};
+    /// let mut builder = VariantBuilder::new();
+    /// builder.append_decimal(12345, 2)?; // 123.45
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::try_new(&metadata, &value)?;
+    /// assert_eq!(variant, Variant::from(VariantDecimal4::try_new(12345, 2)?));
+    /// # Ok::<(), arrow_schema::ArrowError>(())
+    /// ```
+    fn append_decimal(&mut self, integer: i128, scale: u8) -> Result<(), ArrowError> {
+        let decimal = VariantDecimal16::try_new(integer, scale)?;
+        if let Ok(decimal) = VariantDecimal4::try_from(decimal) {
+            self.append_value(decimal);
+        } else if let Ok(decimal) = VariantDecimal8::try_from(decimal) {
+            self.append_value(decimal);
+        } else {
+            self.append_value(decimal);
+        }
+        Ok(())
+    }
+
+    /// Appends `overrides` deep-merged into `base`: if both are objects, the result is an object
+    /// with the fields of both, where fields present in both are merged recursively. Otherwise,
+    /// `overrides` wins and is appended as is.
+    ///
+    /// This is useful to apply overrides to a variant, or defaults (by passing them as `base`).
+    ///
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder, VariantBuilderExt};
+    /// let mut builder = VariantBuilder::new();
+    /// builder.new_object().with_field("x", 1).with_field("y", 2).finish();
+    /// let (base_metadata, base_value) = builder.finish();
+    /// let mut builder = VariantBuilder::new();
+    /// builder.new_object().with_field("y", "two").finish();
+    /// let (overrides_metadata, overrides_value) = builder.finish();
+    ///
+    /// let mut builder = VariantBuilder::new();
+    /// builder.append_merged_objects(
+    ///     &Variant::new(&base_metadata, &base_value),
+    ///     &Variant::new(&overrides_metadata, &overrides_value),
+    /// )?;
+    /// let (metadata, value) = builder.finish();
+    /// let merged = Variant::new(&metadata, &value);
+    /// let merged = merged.as_object().unwrap();
+    /// assert_eq!(merged.get("x"), Some(Variant::from(1)));
+    /// assert_eq!(merged.get("y"), Some(Variant::from("two")));
+    /// # Ok::<(), arrow_schema::ArrowError>(())
+    /// ```
+    fn append_merged_objects(
+        &mut self,
+        base: &Variant<'_, '_>,
+        overrides: &Variant<'_, '_>,
+    ) -> Result<(), ArrowError> {
+        let (Variant::Object(base), Variant::Object(overrides)) = (base, overrides) else {
+            self.append_value(overrides.clone());
+            return Ok(());
+        };
+        let mut object = self.try_new_object()?;
+        for (name, value) in base.iter() {
+            match overrides.get(name) {
+                Some(value_override) => ObjectFieldBuilder::new(name, &mut object)
+                    .append_merged_objects(&value, &value_override)?,
+                None => object.insert(name, value),
+            }
+        }
+        for (name, value) in overrides.iter() {
+            if base.get(name).is_none() {
+                object.insert(name, value);
+            }
+        }
+        object.finish();
+        Ok(())
+    }
+
+    /// Appends a [`Variant::TimestampMicros`] for the instant `micros` microseconds after the
+    /// Unix epoch.
+    ///
+    /// Returns an error if the timestamp is out of range.
+    fn append_timestamp_micros(&mut self, micros: i64) -> Result<(), ArrowError> {
+        let timestamp = timestamp_from_micros(micros)?;
+        self.append_value(Variant::TimestampMicros(timestamp));
+        Ok(())
+    }
+
+    /// Appends a [`Variant::TimestampMicros`] for the instant `millis` milliseconds after the
+    /// Unix epoch. The variant format has no millisecond timestamps, so this is stored in
+    /// microseconds.
+    ///
+    /// Returns an error if the timestamp is out of range.
+    fn append_timestamp_millis(&mut self, millis: i64) -> Result<(), ArrowError> {
+        let micros = millis
+            .checked_mul(1000)
+            .ok_or_else(|| timestamp_error(millis, "ms"))?;
+        self.append_timestamp_micros(micros)
+    }
+
+    /// Appends a [`Variant::TimestampNtzMicros`] for the wall clock time `micros` microseconds
+    /// after `1970-01-01T00:00:00`, without any time zone.
+    ///
+    /// Returns an error if the timestamp is out of range.
+    fn append_timestamp_ntz_micros(&mut self, micros: i64) -> Result<(), ArrowError> {
+        let timestamp = timestamp_from_micros(micros)?;
+        self.append_value(Variant::TimestampNtzMicros(timestamp.naive_utc()));
+        Ok(())
+    }
+
+    /// Appends a [`Variant::TimestampNtzMicros`] for the wall clock time `millis` milliseconds
+    /// after `1970-01-01T00:00:00`, without any time zone. The variant format has no millisecond
+    /// timestamps, so this is stored in microseconds.
+    ///
+    /// Returns an error if the timestamp is out of range.
+    fn append_timestamp_ntz_millis(&mut self, millis: i64) -> Result<(), ArrowError> {
+        let micros = millis
+            .checked_mul(1000)
+            .ok_or_else(|| timestamp_error(millis, "ms"))?;
+        self.append_timestamp_ntz_micros(micros)
+    }
+}
+
+fn timestamp_from_micros(micros: i64) -> Result<chrono::DateTime<chrono::Utc>, ArrowError> {
+    chrono::DateTime::from_timestamp_micros(micros).ok_or_else(|| timestamp_error(micros, "us"))
+}
+
+fn timestamp_error(value: i64, unit: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("Timestamp {value}{unit} is out of range"))
 }
 
 impl VariantBuilderExt for VariantBuilder {
@@ -1015,6 +1232,315 @@ mod tests {
         assert_eq!(items_list.get(1).unwrap(), Variant::from(false));
     }
 
+    #[test]
+    fn test_append_decimal() {
+        let cases = [
+            (
+                12345,
+                2,
+                Variant::from(VariantDecimal4::try_new(12345, 2).unwrap()),
+            ),
+            (
+                -999_999_999,
+                0,
+                VariantDecimal4::try_new(-999_999_999, 0).unwrap().into(),
+            ),
+            (
+                1_000_000_000,
+                0,
+                VariantDecimal8::try_new(1_000_000_000, 0).unwrap().into(),
+            ),
+            // Too much scale for a Decimal4, even though the value itself is small
+            (1, 10, VariantDecimal8::try_new(1, 10).unwrap().into()),
+            (1, 19, VariantDecimal16::try_new(1, 19).unwrap().into()),
+            (
+                10_i128.pow(20),
+                3,
+                VariantDecimal16::try_new(10_i128.pow(20), 3)
+                    .unwrap()
+                    .into(),
+            ),
+        ];
+        for (integer, scale, expected) in cases {
+            let mut builder = VariantBuilder::new();
+            builder.append_decimal(integer, scale).unwrap();
+            let (metadata, value) = builder.finish();
+            let variant = Variant::try_new(&metadata, &value).unwrap();
+            assert_eq!(variant, expected, "{integer} scale {scale}");
+        }
+
+        let mut builder = VariantBuilder::new();
+        builder.append_decimal(10_i128.pow(38), 0).unwrap_err();
+        builder.append_decimal(1, 39).unwrap_err();
+    }
+
+    #[test]
+    fn test_append_merged_objects() {
+        // {"x": 1, "y": {"a": 1}, "w": {"c": 1}}
+        let mut builder = VariantBuilder::new();
+        let mut object = builder.new_object();
+        object.insert("x", 1);
+        object.new_object("y").with_field("a", 1).finish();
+        object.new_object("w").with_field("c", 1).finish();
+        object.finish();
+        let (base_metadata, base_value) = builder.finish();
+        let base = Variant::new(&base_metadata, &base_value);
+
+        // {"y": {"b": 2}, "z": 3, "w": "replaced"}
+        let mut builder = VariantBuilder::new();
+        let mut object = builder.new_object();
+        object.new_object("y").with_field("b", 2).finish();
+        object.insert("z", 3);
+        object.insert("w", "replaced");
+        object.finish();
+        let (overrides_metadata, overrides_value) = builder.finish();
+        let overrides = Variant::new(&overrides_metadata, &overrides_value);
+
+        let mut builder = VariantBuilder::new();
+        builder.append_merged_objects(&base, &overrides).unwrap();
+        let (metadata, value) = builder.finish();
+        let merged = Variant::try_new(&metadata, &value).unwrap();
+        let merged = merged.as_object().unwrap();
+        let fields: Vec<_> = merged.iter().map(|(name, _)| name).collect();
+        assert_eq!(fields, ["w", "x", "y", "z"]);
+        assert_eq!(merged.get("x"), Some(Variant::from(1)));
+        assert_eq!(merged.get("z"), Some(Variant::from(3)));
+        assert_eq!(merged.get("w"), Some(Variant::from("replaced")));
+        let y = merged.get("y").unwrap();
+        let y = y.as_object().unwrap();
+        assert_eq!(y.len(), 2);
+        assert_eq!(y.get("a"), Some(Variant::from(1)));
+        assert_eq!(y.get("b"), Some(Variant::from(2)));
+
+        // Non-objects are replaced by the overrides, in any position
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.append_merged_objects(&base, &Variant::from(5))
+            .unwrap();
+        list.append_merged_objects(&Variant::from(5), &overrides)
+            .unwrap();
+        list.finish();
+        let (metadata, value) = builder.finish();
+        let list = Variant::try_new(&metadata, &value).unwrap();
+        assert_eq!(list.get_list_element(0), Some(Variant::from(5)));
+        let second = list.get_list_element(1).unwrap();
+        assert_eq!(second.as_object().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_append_timestamp() {
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.append_timestamp_micros(1_700_000_000_123_456).unwrap();
+        list.append_timestamp_millis(1_700_000_000_123).unwrap();
+        list.append_timestamp_ntz_micros(-1).unwrap();
+        list.append_timestamp_ntz_millis(86_400_000).unwrap();
+        list.append_timestamp_millis(i64::MAX).unwrap_err();
+        list.append_timestamp_micros(i64::MIN).unwrap_err();
+        list.finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let list = variant.as_list().unwrap();
+        assert_eq!(list.len(), 4);
+
+        let utc = |micros| chrono::DateTime::from_timestamp_micros(micros).unwrap();
+        assert_eq!(
+            list.get(0),
+            Some(Variant::TimestampMicros(utc(1_700_000_000_123_456)))
+        );
+        assert_eq!(
+            list.get(1),
+            Some(Variant::TimestampMicros(utc(1_700_000_000_123_000)))
+        );
+        assert_eq!(
+            list.get(2),
+            Some(Variant::TimestampNtzMicros(utc(-1).naive_utc()))
+        );
+        let ntz = chrono::NaiveDate::from_ymd_opt(1970, 1, 2)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(list.get(3), Some(Variant::TimestampNtzMicros(ntz)));
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let mut builder = VariantBuilder::with_capacity(1024, 16);
+        assert!(builder.value_builder.0.capacity() >= 1024);
+        assert!(builder.metadata_builder.field_names.capacity() >= 16);
+
+        // Appending within the reserved capacity does not reallocate
+        let ptr = builder.value_builder.0.as_ptr();
+        let mut obj = builder.new_object();
+        (0..16).for_each(|i| obj.insert(&format!("field_{i}"), i));
+        obj.finish();
+        assert_eq!(builder.value_builder.0.as_ptr(), ptr);
+
+        builder.reserve_value_bytes(4096);
+        assert!(builder.value_builder.0.capacity() >= builder.value_builder.0.len() + 4096);
+
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert_eq!(variant.get_object_field("field_3"), Some(Variant::Int32(3)));
+    }
+
+    #[test]
+    fn test_nested_builder_style() {
+        let mut builder = VariantBuilder::new();
+        builder
+            .new_object()
+            .with_field("z", 1i64)
+            .with_object("inner", |inner| {
+                inner
+                    .with_field("b", "x")
+                    .with_list("l", |l| l.with_value(1i8).with_list(|l| l.with_value(2i8)))
+            })
+            .with_list("items", |items| {
+                items
+                    .with_object(|o| o.with_field("a", true))
+                    .with_value(())
+            })
+            .finish();
+
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+
+        // Equivalent to building the same value with the non-chaining API
+        let mut expected = VariantBuilder::new();
+        {
+            let mut obj = expected.new_object();
+            obj.insert("z", 1i64);
+            let mut inner = obj.new_object("inner");
+            inner.insert("b", "x");
+            let mut l = inner.new_list("l");
+            l.append_value(1i8);
+            l.new_list().with_value(2i8).finish();
+            l.finish();
+            inner.finish();
+            let mut items = obj.new_list("items");
+            items.new_object().with_field("a", true).finish();
+            items.append_value(());
+            items.finish();
+            obj.finish();
+        }
+        let (expected_metadata, expected_value) = expected.finish();
+        assert_eq!(
+            variant,
+            Variant::try_new(&expected_metadata, &expected_value).unwrap()
+        );
+
+        let obj = variant.as_object().unwrap();
+        assert_eq!(obj.get("z"), Some(Variant::Int64(1)));
+        let inner = obj.get("inner").unwrap();
+        assert_eq!(inner.get_object_field("b"), Some(Variant::from("x")));
+        let l = inner.get_object_field("l").unwrap();
+        assert_eq!(l.get_list_element(0), Some(Variant::Int8(1)));
+        let nested = l.get_list_element(1).unwrap();
+        assert_eq!(nested.get_list_element(0), Some(Variant::Int8(2)));
+        let items = obj.get("items").unwrap();
+        let first = items.get_list_element(0).unwrap();
+        assert_eq!(first.get_object_field("a"), Some(Variant::BooleanTrue));
+        assert_eq!(items.get_list_element(1), Some(Variant::Null));
+    }
+
+    #[test]
+    fn test_repeated_field_names_are_interned() {
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        for i in 0..1000 {
+            list.new_object()
+                .with_field("a", i)
+                .with_field("b", i % 2 == 0)
+                .finish();
+        }
+        list.finish();
+        let (metadata, value) = builder.finish();
+
+        let metadata = VariantMetadata::try_new(&metadata).unwrap();
+        assert_eq!(metadata.len(), 2);
+        assert_eq!(metadata.iter().collect::<Vec<_>>(), vec!["a", "b"]);
+
+        let variant = Variant::try_new_with_metadata(metadata, &value).unwrap();
+        let last = variant.get_list_element(999).unwrap();
+        assert_eq!(last.get_object_field("a"), Some(Variant::Int32(999)));
+        assert_eq!(last.get_object_field("b"), Some(Variant::BooleanFalse));
+    }
+
+    #[test]
+    fn test_with_sorted_keys() {
+        let mut builder = VariantBuilder::new().with_sorted_keys(true);
+        let mut obj = builder.new_object();
+        obj.insert("zebra", 1);
+        obj.insert("apple", "fruit");
+        obj.new_object("mango")
+            .with_field("kiwi", true)
+            .with_field("banana", 2.5)
+            .finish();
+        obj.finish();
+        let (metadata, value) = builder.finish();
+
+        let metadata = VariantMetadata::try_new(&metadata).unwrap();
+        assert!(metadata.is_sorted());
+        assert_eq!(
+            metadata.iter().collect::<Vec<_>>(),
+            vec!["apple", "banana", "kiwi", "mango", "zebra"]
+        );
+        assert_eq!(metadata.get_entry("kiwi"), Some((2, "kiwi")));
+
+        let variant = Variant::try_new_with_metadata(metadata, &value).unwrap();
+        assert_eq!(variant.get_object_field("zebra"), Some(Variant::Int32(1)));
+        assert_eq!(
+            variant.get_object_field("apple"),
+            Some(Variant::from("fruit"))
+        );
+        let mango = variant.get_object_field("mango").unwrap();
+        assert_eq!(mango.get_object_field("kiwi"), Some(Variant::BooleanTrue));
+        assert_eq!(mango.get_object_field("banana"), Some(Variant::Double(2.5)));
+
+        // The same value built without the option has an unsorted dictionary
+        let mut builder = VariantBuilder::new();
+        builder
+            .new_object()
+            .with_field("b", 1)
+            .with_field("a", 2)
+            .finish();
+        let (metadata, _) = builder.finish();
+        assert!(!VariantMetadata::try_new(&metadata).unwrap().is_sorted());
+
+        // Field names added without a value are sorted too
+        let mut builder = VariantBuilder::new().with_sorted_keys(true);
+        builder.add_field_name("b");
+        builder.add_field_name("a");
+        let (metadata, value) = builder.finish();
+        assert!(value.is_empty());
+        let metadata = VariantMetadata::try_new(&metadata).unwrap();
+        assert!(metadata.is_sorted());
+        assert_eq!(metadata.iter().collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_with_sorted_keys_wide_field_ids() {
+        // Field ids of the inner objects grow from one to two bytes once the dictionary is sorted
+        let mut builder = VariantBuilder::new().with_sorted_keys(true);
+        let mut list = builder.new_list();
+        for i in (0..300).rev() {
+            list.new_object()
+                .with_field(&format!("key_{i:03}"), i)
+                .finish();
+        }
+        list.finish();
+        let (metadata, value) = builder.finish();
+
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert!(variant.metadata().is_sorted());
+        let list = variant.as_list().unwrap();
+        for (i, element) in list.iter().enumerate() {
+            let key = 299 - i as i32;
+            let field = element.get_object_field(&format!("key_{key:03}"));
+            assert_eq!(field, Some(Variant::Int32(key)));
+        }
+    }
+
     #[test]
     fn test_sorted_dictionary() {
         // check if variant metadatabuilders are equivalent from different ways of constructing them
@@ -150,6 +150,32 @@ impl<'a, S: BuilderSpecificState> ListBuilder<'a, S> {
         Ok(self)
     }
 
+    /// Builder-style API for appending a nested object to the list and returning self to enable
+    /// method chaining.
+    ///
+    /// `build` receives the builder returned by [`ListBuilder::new_object`] and returns it once
+    /// the object fields have been added; it is finished automatically.
+    pub fn with_object(
+        mut self,
+        build: impl for<'b> FnOnce(ObjectBuilder<'b, ListState<'b>>) -> ObjectBuilder<'b, ListState<'b>>,
+    ) -> Self {
+        build(self.new_object()).finish();
+        self
+    }
+
+    /// Builder-style API for appending a nested list to the list and returning self to enable
+    /// method chaining.
+    ///
+    /// `build` receives the builder returned by [`ListBuilder::new_list`] and returns it once the
+    /// nested elements have been added; it is finished automatically.
+    pub fn with_list(
+        mut self,
+        build: impl for<'b> FnOnce(ListBuilder<'b, ListState<'b>>) -> ListBuilder<'b, ListState<'b>>,
+    ) -> Self {
+        build(self.new_list()).finish();
+        self
+    }
+
     /// Finalizes this list and appends it to its parent, which otherwise remains unmodified.
     pub fn finish(mut self) {
         let starting_offset = self.parent_state.saved_value_builder_offset;
@@ -148,6 +148,9 @@ pub struct WritableMetadataBuilder {
 
 impl WritableMetadataBuilder {
     /// Upsert field name to dictionary, return its ID
+    ///
+    /// Field names are interned: inserting a name that is already in the dictionary returns the
+    /// existing ID, so building many objects with the same keys does not grow the dictionary.
     pub fn upsert_field_name(&mut self, field_name: &str) -> u32 {
         let (id, new_entry) = self.field_names.insert_full(field_name.to_string());
 
@@ -237,6 +240,22 @@ impl WritableMetadataBuilder {
         metadata_buffer.len()
     }
 
+    /// Appends an already serialized metadata dictionary to the underlying buffer, returning the
+    /// resulting [`Self::offset`]. The bytes are copied as-is, without any validation.
+    ///
+    /// # Panics
+    ///
+    /// If a metadata dictionary is in progress, i.e. field names were added since the last call to
+    /// [`Self::finish`].
+    pub fn append_encoded(&mut self, metadata: &[u8]) -> usize {
+        assert!(
+            self.field_names.is_empty(),
+            "cannot append encoded metadata while a metadata dictionary is in progress"
+        );
+        self.metadata_buffer.extend_from_slice(metadata);
+        self.metadata_buffer.len()
+    }
+
     /// Returns the inner buffer, consuming self without finalizing any in progress metadata.
     pub fn into_inner(self) -> Vec<u8> {
         self.metadata_buffer
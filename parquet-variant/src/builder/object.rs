@@ -183,6 +183,38 @@ impl<'a, S: BuilderSpecificState> ObjectBuilder<'a, S> {
         Ok(self)
     }
 
+    /// Builder style API for adding a nested object field to the object
+    ///
+    /// `build` receives the builder returned by [`ObjectBuilder::new_object`] and returns it once
+    /// the nested fields have been added; it is finished automatically.
+    ///
+    /// Panics if the proposed key was a duplicate
+    pub fn with_object(
+        mut self,
+        key: &str,
+        build: impl for<'b> FnOnce(
+            ObjectBuilder<'b, ObjectState<'b>>,
+        ) -> ObjectBuilder<'b, ObjectState<'b>>,
+    ) -> Self {
+        build(self.new_object(key)).finish();
+        self
+    }
+
+    /// Builder style API for adding a nested list field to the object
+    ///
+    /// `build` receives the builder returned by [`ObjectBuilder::new_list`] and returns it once
+    /// the list elements have been added; it is finished automatically.
+    ///
+    /// Panics if the proposed key was a duplicate
+    pub fn with_list(
+        mut self,
+        key: &str,
+        build: impl for<'b> FnOnce(ListBuilder<'b, ObjectState<'b>>) -> ListBuilder<'b, ObjectState<'b>>,
+    ) -> Self {
+        build(self.new_list(key)).finish();
+        self
+    }
+
     /// Enables validation for unique field keys when inserting into this object.
     ///
     /// When this is enabled, calling [`ObjectBuilder::finish`] will return an error
@@ -14,9 +14,10 @@
 // KIND, either express or implied.  See the License for the
 // specific language governing permissions and limitations
 // under the License.
+use crate::VariantMetadata;
 use crate::utils::parse_path;
 use arrow_schema::ArrowError;
-use std::{borrow::Cow, ops::Deref};
+use std::{borrow::Cow, fmt, ops::Deref, str::FromStr};
 
 /// Represents a qualified path to a potential subfield or index of a variant
 /// value.
@@ -64,6 +65,23 @@ use std::{borrow::Cow, ops::Deref};
 /// assert_eq!(path, expected);
 /// ```
 ///
+/// # Example: From JSONPath-like strings
+/// ```
+/// # use parquet_variant::{VariantPath, VariantPathElement};
+/// /// A leading `$` (the JSONPath root) is accepted and ignored
+/// let path = VariantPath::try_from("$.user.addresses[0][\"zip.code\"]").unwrap();
+/// let expected = VariantPath::try_from("user")
+///     .unwrap()
+///     .join("addresses")
+///     .join(0)
+///     .join("zip.code");
+/// assert_eq!(path, expected);
+///
+/// /// Paths can also be parsed into an owned `VariantPath<'static>`
+/// let owned: VariantPath<'static> = "$.user.addresses[0]".parse().unwrap();
+/// assert_eq!(owned[2], VariantPathElement::index(0));
+/// ```
+///
 /// # Example: Accessing Compound paths
 /// ```
 /// # use parquet_variant::{VariantPath, VariantPathElement};
@@ -85,7 +103,7 @@ use std::{borrow::Cow, ops::Deref};
 ///     VariantPathElement::index(2),
 ///     VariantPathElement::field("3")]);
 /// assert_eq!(path, expected)
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
 pub struct VariantPath<'a>(Vec<VariantPathElement<'a>>);
 
 impl<'a> VariantPath<'a> {
@@ -110,10 +128,80 @@ impl<'a> VariantPath<'a> {
         self.0.push(element.into());
     }
 
+    /// Append a field access to the path
+    pub fn push_field(&mut self, name: impl Into<Cow<'a, str>>) {
+        self.push(VariantPathElement::field(name));
+    }
+
+    /// Append a list index access to the path
+    pub fn push_index(&mut self, index: usize) {
+        self.push(VariantPathElement::index(index));
+    }
+
+    /// Return a new `VariantPath` with a field access appended
+    pub fn with_field(self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.join(VariantPathElement::field(name))
+    }
+
+    /// Return a new `VariantPath` with a list index access appended
+    pub fn with_index(self, index: usize) -> Self {
+        self.join(VariantPathElement::index(index))
+    }
+
+    /// Return a new `VariantPath` with all elements of `other` appended after the
+    /// elements of this path
+    ///
+    /// ```
+    /// # use parquet_variant::VariantPath;
+    /// let prefix = VariantPath::try_from("a.b").unwrap();
+    /// let suffix = VariantPath::try_from("[0].c").unwrap();
+    /// let path = prefix.concat(&suffix);
+    /// assert_eq!(path, VariantPath::try_from("a.b[0].c").unwrap());
+    /// ```
+    pub fn concat(&self, other: &VariantPath<'a>) -> Self {
+        let mut path = self.clone();
+        path.extend(other.iter().cloned());
+        path
+    }
+
     /// Returns whether [`VariantPath`] has no path elements
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Return a new `VariantPath` with every field name that is defined in `metadata` replaced by
+    /// its [`VariantPathElement::FieldId`], so that it can be followed without looking up names.
+    ///
+    /// The result is only valid for variants that use the same metadata dictionary. Names that
+    /// `metadata` does not define are kept as is, since they cannot exist in such variants anyway.
+    ///
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder, VariantPath, VariantPathElement};
+    /// let mut builder = VariantBuilder::new();
+    /// builder.new_object().with_field("a", 1).with_field("b", 2).finish();
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::new(&metadata, &value);
+    ///
+    /// let path = VariantPath::try_from("b").unwrap();
+    /// let resolved = path.resolve_field_ids(variant.metadata());
+    /// assert_eq!(resolved[0], VariantPathElement::field_id(1));
+    /// assert_eq!(variant.get_path(&resolved), Some(Variant::from(2)));
+    ///
+    /// // "z" is not in the dictionary, so it is kept as a name
+    /// let path = VariantPath::try_from("z").unwrap();
+    /// assert_eq!(path.resolve_field_ids(variant.metadata()), path);
+    /// ```
+    pub fn resolve_field_ids(&self, metadata: &VariantMetadata<'_>) -> Self {
+        self.iter()
+            .map(|element| match element {
+                VariantPathElement::Field { name } => match metadata.get_entry(name) {
+                    Some((id, _)) => VariantPathElement::field_id(id),
+                    None => element.clone(),
+                },
+                _ => element.clone(),
+            })
+            .collect()
+    }
 }
 
 impl<'a> From<Vec<VariantPathElement<'a>>> for VariantPath<'a> {
@@ -131,6 +219,19 @@ impl<'a> TryFrom<&'a str> for VariantPath<'a> {
     }
 }
 
+/// Parse an owned path, with the same syntax as the `TryFrom<&str>` conversion
+impl FromStr for VariantPath<'static> {
+    type Err = ArrowError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let elements = parse_path(path)?;
+        Ok(elements
+            .into_iter()
+            .map(VariantPathElement::into_owned)
+            .collect())
+    }
+}
+
 /// Create from usize
 impl<'a> From<usize> for VariantPath<'a> {
     fn from(index: usize) -> Self {
@@ -151,6 +252,12 @@ impl<'a> FromIterator<VariantPathElement<'a>> for VariantPath<'a> {
     }
 }
 
+impl<'a> Extend<VariantPathElement<'a>> for VariantPath<'a> {
+    fn extend<T: IntoIterator<Item = VariantPathElement<'a>>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
 impl<'a> Deref for VariantPath<'a> {
     type Target = [VariantPathElement<'a>];
 
@@ -159,26 +266,96 @@ impl<'a> Deref for VariantPath<'a> {
     }
 }
 
+/// Formats the path with JSONPath-like syntax, e.g. `$.a.b[2]["c.d"]`.
+///
+/// The output can be parsed back into an equal path.
+impl fmt::Display for VariantPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "$")?;
+        for element in self.iter() {
+            write!(f, "{element}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes the path as its string form, see the [`Display`](fmt::Display) impl.
+#[cfg(feature = "serde")]
+impl serde_core::Serialize for VariantPath<'_> {
+    fn serialize<S: serde_core::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Deserializes a path from its string form, as parsed by [`VariantPath::from_str`].
+#[cfg(feature = "serde")]
+impl<'de> serde_core::Deserialize<'de> for VariantPath<'_> {
+    fn deserialize<D: serde_core::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path = String::deserialize(deserializer)?;
+        path.parse().map_err(serde_core::de::Error::custom)
+    }
+}
+
 /// Element of a [`VariantPath`] that can be a field name or an index.
 ///
 /// See [`VariantPath`] for more details and examples.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum VariantPathElement<'a> {
     /// Access field with name `name`
     Field { name: Cow<'a, str> },
+    /// Access the field whose name has id `id` in the metadata dictionary, skipping the name
+    /// lookup. Ids are only meaningful for variants that share the dictionary they were resolved
+    /// against, see [`VariantPath::resolve_field_ids`]. Written as `[#id]` in path strings.
+    FieldId { id: u32 },
     /// Access the list element at `index`
     Index { index: usize },
+    /// Access the list element `offset` positions from the end of the list, so that an `offset`
+    /// of 1 is the last element. Written as `[-offset]` in path strings.
+    IndexFromEnd { offset: usize },
+    /// Access every element of a list. Written as `[*]` in path strings.
+    ///
+    /// [`Variant::get_path`](crate::Variant::get_path) only returns a single value, so it does
+    /// not resolve paths containing wildcards.
+    Wildcard,
 }
 
 impl<'a> VariantPathElement<'a> {
+    /// Create an element that accesses the field with name `name`
     pub fn field(name: impl Into<Cow<'a, str>>) -> VariantPathElement<'a> {
         let name = name.into();
         VariantPathElement::Field { name }
     }
 
+    /// Create an element that accesses the field whose name has id `id` in the metadata
+    /// dictionary
+    pub fn field_id(id: u32) -> VariantPathElement<'a> {
+        VariantPathElement::FieldId { id }
+    }
+
+    /// Create an element that accesses the list element at `index`
     pub fn index(index: usize) -> VariantPathElement<'a> {
         VariantPathElement::Index { index }
     }
+
+    /// Create an element that accesses the list element `offset` positions from the end of the
+    /// list, so that an `offset` of 1 is the last element
+    pub fn index_from_end(offset: usize) -> VariantPathElement<'a> {
+        VariantPathElement::IndexFromEnd { offset }
+    }
+
+    /// Convert into an element that owns its field name, if any
+    pub fn into_owned(self) -> VariantPathElement<'static> {
+        match self {
+            VariantPathElement::Field { name } => VariantPathElement::field(name.into_owned()),
+            VariantPathElement::FieldId { id } => VariantPathElement::field_id(id),
+            VariantPathElement::Index { index } => VariantPathElement::index(index),
+            VariantPathElement::IndexFromEnd { offset } => {
+                VariantPathElement::index_from_end(offset)
+            }
+            VariantPathElement::Wildcard => VariantPathElement::Wildcard,
+        }
+    }
 }
 
 // Conversion utilities for `VariantPathElement` from string types
@@ -212,6 +389,37 @@ impl<'a> From<usize> for VariantPathElement<'a> {
     }
 }
 
+/// Formats the element as it would appear in a [`VariantPath`]: `.name` for simple field names,
+/// `["name"]` for field names that need escaping, `[#id]` for field ids, and `[index]` for indexes.
+impl fmt::Display for VariantPathElement<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VariantPathElement::Field { name } if is_simple_field_name(name) => {
+                write!(f, ".{name}")
+            }
+            VariantPathElement::Field { name } => {
+                write!(f, "[\"")?;
+                for c in name.chars() {
+                    if matches!(c, '\\' | ']') {
+                        write!(f, "\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+                write!(f, "\"]")
+            }
+            VariantPathElement::FieldId { id } => write!(f, "[#{id}]"),
+            VariantPathElement::Index { index } => write!(f, "[{index}]"),
+            VariantPathElement::IndexFromEnd { offset } => write!(f, "[-{offset}]"),
+            VariantPathElement::Wildcard => write!(f, "[*]"),
+        }
+    }
+}
+
+/// Field names that can be written in dot notation without escaping
+fn is_simple_field_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(['.', '[', ']'])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +516,263 @@ mod tests {
         assert_eq!(path, expected);
     }
 
+    #[test]
+    fn test_variant_path_json_path_root() {
+        let path = VariantPath::try_from("$.user.addresses[0].zip").unwrap();
+        let expected = VariantPath::from_iter([
+            VariantPathElement::field("user"),
+            VariantPathElement::field("addresses"),
+            VariantPathElement::index(0),
+            VariantPathElement::field("zip"),
+        ]);
+        assert_eq!(path, expected);
+
+        let path = VariantPath::try_from("$[\"weird.key\"][1]").unwrap();
+        let expected = VariantPath::from_iter([
+            VariantPathElement::field("weird.key"),
+            VariantPathElement::index(1),
+        ]);
+        assert_eq!(path, expected);
+
+        assert!(VariantPath::try_from("$").unwrap().is_empty());
+
+        // `$` is only the root marker when followed by `.`, `[` or nothing
+        let path = VariantPath::try_from("$foo.$").unwrap();
+        let expected = VariantPath::from_iter([
+            VariantPathElement::field("$foo"),
+            VariantPathElement::field("$"),
+        ]);
+        assert_eq!(path, expected);
+
+        let err = VariantPath::try_from("$.").unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: Unexpected trailing '.'");
+        let err = VariantPath::try_from("$..foo").unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: Unexpected '.' at byte 2");
+    }
+
+    #[test]
+    fn test_variant_path_from_str() {
+        let path = "$.a['b.c'][2]".parse::<VariantPath<'static>>().unwrap();
+        let expected = VariantPath::try_from("a['b.c'][2]").unwrap();
+        assert_eq!(path, expected);
+
+        let err = "a[b]".parse::<VariantPath<'static>>().unwrap_err();
+        assert!(err.to_string().contains("Invalid token in bracket request"));
+    }
+
+    #[test]
+    fn test_variant_path_push_and_with() {
+        let mut path = VariantPath::default();
+        path.push_field("a");
+        path.push_index(1);
+        path.push_field(String::from("b"));
+        assert_eq!(
+            path.path(),
+            &vec![
+                VariantPathElement::field("a"),
+                VariantPathElement::index(1),
+                VariantPathElement::field("b"),
+            ]
+        );
+
+        let chained = VariantPath::default()
+            .with_field("a")
+            .with_index(1)
+            .with_field("b");
+        assert_eq!(chained, path);
+    }
+
+    #[test]
+    fn test_variant_path_concat() {
+        let prefix = VariantPath::default().with_field("a").with_index(0);
+        let suffix = VariantPath::default().with_field("b").with_index(2);
+        let joined = prefix.concat(&suffix);
+        assert_eq!(
+            joined.path(),
+            &vec![
+                VariantPathElement::field("a"),
+                VariantPathElement::index(0),
+                VariantPathElement::field("b"),
+                VariantPathElement::index(2),
+            ]
+        );
+        // inputs are left untouched
+        assert_eq!(prefix.len(), 2);
+        assert_eq!(suffix.len(), 2);
+
+        // concatenating with empty paths is the identity
+        assert_eq!(prefix.concat(&VariantPath::default()), prefix);
+        assert_eq!(VariantPath::default().concat(&suffix), suffix);
+
+        let mut extended = prefix.clone();
+        extended.extend(suffix.iter().cloned());
+        assert_eq!(extended, joined);
+    }
+
+    #[test]
+    fn test_variant_path_display() {
+        assert_eq!(VariantPath::default().to_string(), "$");
+
+        let path = VariantPath::try_from("a.b[2]").unwrap();
+        assert_eq!(path.to_string(), "$.a.b[2]");
+
+        let path = VariantPath::from_iter([
+            VariantPathElement::field("weird.key"),
+            VariantPathElement::field("a]b\\c"),
+            VariantPathElement::field(""),
+            VariantPathElement::field("\"quoted\""),
+            VariantPathElement::field("0"),
+        ]);
+        assert_eq!(
+            path.to_string(),
+            r#"$["weird.key"]["a\]b\\c"][""]."quoted".0"#
+        );
+
+        assert_eq!(VariantPathElement::field("x").to_string(), ".x");
+        assert_eq!(VariantPathElement::index(7).to_string(), "[7]");
+        assert_eq!(VariantPathElement::index_from_end(1).to_string(), "[-1]");
+        assert_eq!(VariantPathElement::Wildcard.to_string(), "[*]");
+        assert_eq!(VariantPathElement::field_id(3).to_string(), "[#3]");
+    }
+
+    #[test]
+    fn test_variant_path_display_round_trip() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        const CHARS: &[char] = &['a', 'Z', '0', '.', '[', ']', '\'', '"', '\\', '$', ' ', 'é'];
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let path: VariantPath = (0..rng.random_range(0..5))
+                .map(|_| {
+                    if rng.random_bool(0.05) {
+                        VariantPathElement::Wildcard
+                    } else if rng.random_bool(0.1) {
+                        VariantPathElement::index_from_end(rng.random_range(1..1000))
+                    } else if rng.random_bool(0.1) {
+                        VariantPathElement::field_id(rng.random())
+                    } else if rng.random_bool(0.3) {
+                        VariantPathElement::index(rng.random_range(0..1000))
+                    } else {
+                        let name: String = (0..rng.random_range(0..6))
+                            .map(|_| CHARS[rng.random_range(0..CHARS.len())])
+                            .collect();
+                        VariantPathElement::field(name)
+                    }
+                })
+                .collect();
+
+            let rendered = path.to_string();
+            let parsed = VariantPath::try_from(rendered.as_str()).unwrap();
+            assert_eq!(parsed, path, "round trip of {rendered}");
+        }
+    }
+
+    #[test]
+    fn test_variant_path_negative_index() {
+        let path = VariantPath::try_from("$.items[-1]").unwrap();
+        let expected = VariantPath::from_iter([
+            VariantPathElement::field("items"),
+            VariantPathElement::index_from_end(1),
+        ]);
+        assert_eq!(path, expected);
+
+        let path = VariantPath::try_from("[-3][0]['-1']").unwrap();
+        let expected = VariantPath::from_iter([
+            VariantPathElement::index_from_end(3),
+            VariantPathElement::index(0),
+            VariantPathElement::field("-1"),
+        ]);
+        assert_eq!(path, expected);
+    }
+
+    #[test]
+    fn test_variant_path_field_id() {
+        let path = VariantPath::try_from("$[#3].a['#4']").unwrap();
+        let expected = VariantPath::from_iter([
+            VariantPathElement::field_id(3),
+            VariantPathElement::field("a"),
+            VariantPathElement::field("#4"),
+        ]);
+        assert_eq!(path, expected);
+        assert_eq!(path.to_string(), "$[#3].a.#4");
+        assert_eq!(
+            VariantPath::try_from(path.to_string().as_str()).unwrap(),
+            path
+        );
+
+        for invalid in ["a[#]", "a[#x]", "a[#-1]", "a[# 1]"] {
+            let err = VariantPath::try_from(invalid).unwrap_err();
+            assert!(
+                err.to_string().contains("Invalid field id"),
+                "{invalid}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_variant_path_wildcard() {
+        let path = VariantPath::try_from("$.tags[*].name").unwrap();
+        let expected = VariantPath::from_iter([
+            VariantPathElement::field("tags"),
+            VariantPathElement::Wildcard,
+            VariantPathElement::field("name"),
+        ]);
+        assert_eq!(path, expected);
+
+        // a quoted `*` is a field name
+        let path = VariantPath::try_from("['*']").unwrap();
+        assert_eq!(path[0], VariantPathElement::field("*"));
+    }
+
+    #[test]
+    fn test_variant_path_hash_and_ord() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let paths = [
+            VariantPath::try_from("a.b[1]").unwrap(),
+            VariantPath::from_iter([
+                VariantPathElement::field("a".to_string()),
+                VariantPathElement::field("b"),
+                VariantPathElement::index(1),
+            ]),
+            VariantPath::try_from("a.b[2]").unwrap(),
+        ];
+        let set: HashSet<_> = paths.iter().cloned().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&VariantPath::try_from("$.a.b[1]").unwrap()));
+
+        // Paths order element by element, and a prefix sorts before longer paths
+        let sorted: Vec<_> = ["a.c", "a.b[2]", "a", "a.b[1]"]
+            .into_iter()
+            .map(|path| VariantPath::try_from(path).unwrap())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|path| path.to_string())
+            .collect();
+        assert_eq!(sorted, ["$.a", "$.a.b[1]", "$.a.b[2]", "$.a.c"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_variant_path_serde() {
+        let path = VariantPath::try_from("a['b.c'][2][-1][*]").unwrap();
+        let serialized = serde_json::to_string(&path).unwrap();
+        assert_eq!(serialized, r#""$.a[\"b.c\"][2][-1][*]""#);
+        let deserialized: VariantPath = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, path);
+
+        let err = serde_json::from_str::<VariantPath>(r#""a[""#).unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: Unclosed '[' at byte 1");
+
+        let path = VariantPath::from_iter([VariantPathElement::field_id(3), "a".into()]);
+        let serialized = serde_json::to_string(&path).unwrap();
+        assert_eq!(serialized, r#""$[#3].a""#);
+        let deserialized: VariantPath = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, path);
+    }
+
     #[test]
     fn test_invalid_path_parse() {
         // Leading dot
@@ -346,5 +811,13 @@ mod tests {
             err.to_string(),
             "Parser error: Invalid token in bracket request: `abc`. Expected a quoted string or a number(e.g., `['field']` or `[123]`)"
         );
+
+        for invalid in ["foo[-0]", "foo[-]", "foo[-x]", "foo[--1]"] {
+            let err = VariantPath::try_from(invalid).unwrap_err();
+            assert!(
+                err.to_string().contains("Invalid negative index"),
+                "{invalid}: {err}"
+            );
+        }
     }
 }
@@ -151,7 +151,10 @@ pub(crate) const fn expect_size_of<T>(expected: usize) {
 /// # Syntax
 /// - `.field` or `field` - access object field (do not support special char)
 /// - `[index]` - access array element by index
+/// - `[-offset]` - access array element counting from the end (`[-1]` is the last element)
+/// - `[*]` - access every array element
 /// - `[field]` - access object field (support special char with escape `\`)
+/// - An optional leading `$` denotes the root value, as in JSONPath (e.g. `$.foo[0]`)
 ///
 /// # Escape Rules
 /// Inside brackets `[...]`:
@@ -168,8 +171,12 @@ pub(crate) const fn expect_size_of<T>(expected: usize) {
 /// - `"[1]"` -> array index 1
 /// - `"['1']"` or `"["1"]"`-> field `1`
 /// - `"foo[1].bar"` -> field `foo`, index 1, field `bar`
+/// - `"foo[-1]"` -> field `foo`, last element
+/// - `"foo[*]"` -> field `foo`, every element
 /// - `"['a.b']"` -> field `a.b` (dot is literal inside bracket)
 /// - `"['a\]b']"` -> field `a]b` (escaped `]`
+/// - `"$.foo[1]"` -> field `foo`, index 1 (same as `"foo[1]"`)
+/// - `"$"` -> empty path
 /// - etc.
 ///
 /// # Errors
@@ -177,6 +184,7 @@ pub(crate) const fn expect_size_of<T>(expected: usize) {
 /// - Trailing `.` (e.g., `"foo."`)
 /// - Unclosed '[' (e.g., `"foo[1"`)
 /// - Unexpected ']' (e.g., `"foo]"`)
+/// - Zero negative index (e.g., `"foo[-0]"`)
 /// - Trailing '`' inside bracket (treated as unclosed bracket)
 #[inline]
 pub(crate) fn parse_path(s: &str) -> Result<Vec<VariantPathElement<'_>>, ArrowError> {
@@ -187,12 +195,16 @@ pub(crate) fn parse_path(s: &str) -> Result<Vec<VariantPathElement<'_>>, ArrowEr
     };
 
     let bytes = s.as_bytes();
-    if let Some(b'.') = bytes.first() {
-        return Err(ArrowError::ParseError("Unexpected leading '.'".into()));
-    }
+
+    // Skip the JSONPath root marker, if present. A `$` followed by anything other than `.` or `[`
+    // is just the first character of a field name.
+    let mut i = match bytes {
+        [b'$'] | [b'$', b'.' | b'[', ..] => 1,
+        [b'.', ..] => return Err(ArrowError::ParseError("Unexpected leading '.'".into())),
+        _ => 0,
+    };
 
     let mut elements = Vec::new();
-    let mut i = 0;
 
     while i < bytes.len() {
         let (elem, end) = match bytes[i] {
@@ -228,7 +240,7 @@ pub(crate) fn parse_path(s: &str) -> Result<Vec<VariantPathElement<'_>>, ArrowEr
     Ok(elements)
 }
 
-/// Parse `[digits | field]` starting at `i` (which points to `[`).
+/// Parse `[digits | #digits | field]` starting at `i` (which points to `[`).
 /// Returns (VariantPathElement, position after `]`).
 fn parse_in_bracket(s: &str, i: usize) -> Result<(VariantPathElement<'_>, usize), ArrowError> {
     let start = i + 1; // skip '['
@@ -274,6 +286,26 @@ fn parse_in_bracket(s: &str, i: usize) -> Result<(VariantPathElement<'_>, usize)
         }) {
         // Quoted field name, e.g., ['field'] or ['123'] or ["123"]
         VariantPathElement::field(inner.to_string())
+    } else if unescaped == "*" {
+        VariantPathElement::Wildcard
+    } else if let Some(id) = unescaped.strip_prefix('#') {
+        // Field id, e.g. [#3]
+        let Ok(id) = id.parse() else {
+            return Err(ArrowError::ParseError(format!(
+                "Invalid field id in bracket request: `{unescaped}`. Expected a number (e.g., `[#3]`)"
+            )));
+        };
+        VariantPathElement::field_id(id)
+    } else if let Some(offset) = unescaped.strip_prefix('-') {
+        // Negative index, counting from the end of the list, e.g. [-1]
+        match offset.parse() {
+            Ok(offset) if offset > 0 => VariantPathElement::index_from_end(offset),
+            _ => {
+                return Err(ArrowError::ParseError(format!(
+                    "Invalid negative index in bracket request: `{unescaped}`. Expected a non-zero number (e.g., `[-1]`)"
+                )));
+            }
+        }
     } else {
         let Ok(idx) = unescaped.parse() else {
             return Err(ArrowError::ParseError(format!(
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub use self::compare::{variant_cmp, variant_partial_cmp};
 pub use self::decimal::{VariantDecimal4, VariantDecimal8, VariantDecimal16, VariantDecimalType};
 pub use self::list::VariantList;
 pub use self::metadata::{EMPTY_VARIANT_METADATA, EMPTY_VARIANT_METADATA_BYTES, VariantMetadata};
@@ -37,6 +38,7 @@ use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
 use num_traits::NumCast;
 use std::ops::Deref;
 
+mod compare;
 mod decimal;
 mod list;
 mod metadata;
@@ -294,6 +296,26 @@ pub enum Variant<'m, 'v> {
 // We don't want this to grow because it could hurt performance of a frequently-created type.
 const _: () = crate::utils::expect_size_of::<Variant>(80);
 
+/// Fully validates a metadata and value buffer pair, without keeping the resulting [`Variant`].
+///
+/// This checks the structure of the metadata dictionary, that every object field id is in range,
+/// that all offsets are monotonic and in bounds, and recursively validates every nested value.
+/// Externally produced bytes that pass validation can be accessed without risk of panics.
+///
+/// This is equivalent to calling [`Variant::try_new`] and discarding the result.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::validate;
+/// let metadata = [0x01, 0x00, 0x00];
+/// assert!(validate(&metadata, &[0x09, 0x48, 0x49]).is_ok());
+/// // A short string of length 2 that is missing its last byte
+/// assert!(validate(&metadata, &[0x09, 0x48]).is_err());
+/// ```
+pub fn validate(metadata: &[u8], value: &[u8]) -> Result<(), ArrowError> {
+    Variant::try_new(metadata, value).map(|_| ())
+}
+
 impl<'m, 'v> Variant<'m, 'v> {
     /// Attempts to interpret a metadata and value buffer pair as a new `Variant`.
     ///
@@ -1474,10 +1496,66 @@ impl<'m, 'v> Variant<'m, 'v> {
         }
     }
 
+    /// Iterates over every field name defined in this variant's metadata dictionary.
+    ///
+    /// Unlike [`VariantObject::iter`], this includes names that the value itself does not use,
+    /// e.g. the fields of sibling objects that share the same dictionary. Variants other than
+    /// objects and lists have no dictionary of their own and produce no names.
+    ///
+    /// This is shorthand for [`Self::metadata`] followed by [`VariantMetadata::iter`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// let mut builder = VariantBuilder::new().with_field_names(["a", "b", "c"]);
+    /// builder.new_object().with_field("b", 1).finish();
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::new(&metadata, &value);
+    /// assert_eq!(variant.metadata_keys().collect::<Vec<_>>(), ["a", "b", "c"]);
+    /// assert_eq!(variant.as_object().unwrap().len(), 1);
+    /// ```
+    pub fn metadata_keys(&self) -> impl Iterator<Item = &'m str> + '_ {
+        self.metadata().iter()
+    }
+
+    /// Returns the number of elements of a list or fields of an object, or `None` for any other
+    /// variant.
+    ///
+    /// # Examples
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// let mut builder = VariantBuilder::new();
+    /// builder.new_list().with_value(1).with_value(2).finish();
+    /// let (metadata, value) = builder.finish();
+    /// assert_eq!(Variant::new(&metadata, &value).len(), Some(2));
+    /// assert_eq!(Variant::from("abc").len(), None);
+    /// ```
+    pub fn len(&self) -> Option<usize> {
+        match self {
+            Variant::List(list) => Some(list.len()),
+            Variant::Object(object) => Some(object.len()),
+            _ => None,
+        }
+    }
+
+    /// Returns whether a list has no elements or an object has no fields, or `None` for any other
+    /// variant. See [`Self::len`].
+    pub fn is_empty(&self) -> Option<bool> {
+        self.len().map(|len| len == 0)
+    }
+
     /// Return a new Variant with the path followed.
     ///
     /// If the path is not found, `None` is returned.
     ///
+    /// A [`VariantPathElement::Wildcard`] selects any number of values, which cannot be returned
+    /// as a single `Variant`, so paths containing one always return `None`, even when the list
+    /// has exactly one element. Use `variant_get` from the `parquet-variant-compute` crate to
+    /// expand wildcards into a list.
+    ///
+    /// Nothing is copied: the result borrows the same metadata and value buffers as `self`, so it
+    /// can outlive `self`, e.g. a temporary returned by `VariantArray::value`.
+    ///
     /// # Example
     /// ```
     /// # use parquet_variant::{Variant, VariantBuilder, VariantObject, VariantPath};
@@ -1505,12 +1583,27 @@ impl<'m, 'v> Variant<'m, 'v> {
     /// // You can also access nested paths
     /// let path = VariantPath::try_from("foo").unwrap().join(0);
     /// assert_eq!(variant.get_path(&path).unwrap(), bar);
-    /// ```
-    pub fn get_path(&self, path: &VariantPath) -> Option<Variant<'_, '_>> {
+    /// // Negative indexes count from the end of the list
+    /// let path = VariantPath::try_from("foo[-1]").unwrap();
+    /// assert_eq!(variant.get_path(&path).unwrap().as_string(), Some("baz"));
+    /// // Wildcards are not resolved
+    /// let path = VariantPath::try_from("foo[*]").unwrap();
+    /// assert_eq!(variant.get_path(&path), None);
+    /// ```
+    pub fn get_path(&self, path: &VariantPath) -> Option<Variant<'m, 'v>> {
         path.iter()
             .try_fold(self.clone(), |output, element| match element {
                 VariantPathElement::Field { name } => output.get_object_field(name),
+                VariantPathElement::FieldId { id } => match &output {
+                    Variant::Object(object) => object.get_by_field_id(*id),
+                    _ => None,
+                },
                 VariantPathElement::Index { index } => output.get_list_element(*index),
+                VariantPathElement::IndexFromEnd { offset } => match &output {
+                    Variant::List(list) => list.get(list.len().checked_sub(*offset)?),
+                    _ => None,
+                },
+                VariantPathElement::Wildcard => None,
             })
     }
 }
@@ -1798,6 +1891,256 @@ mod tests {
 
     use super::*;
 
+    /// Visits every nested value of a fully validated variant through the infallible accessors
+    fn walk_validated(variant: &Variant) {
+        match variant {
+            Variant::Object(obj) => {
+                for (name, field) in obj.iter() {
+                    assert!(obj.get(name).is_some());
+                    walk_validated(&field);
+                }
+            }
+            Variant::List(list) => list.iter().for_each(|element| walk_validated(&element)),
+            _ => {}
+        }
+    }
+
+    /// Visits every nested value of an unvalidated variant through the fallible accessors
+    fn walk_unvalidated(variant: &Variant) -> Result<(), ArrowError> {
+        match variant {
+            Variant::Object(obj) => {
+                for i in 0..obj.len() {
+                    obj.try_field(i)?;
+                }
+                for field in obj.iter_try() {
+                    walk_unvalidated(&field?.1)?;
+                }
+            }
+            Variant::List(list) => {
+                for element in list.iter_try() {
+                    walk_unvalidated(&element?)?;
+                }
+            }
+            _ => {}
+        }
+        variant.clone().with_full_validation().map(|_| ())
+    }
+
+    #[test]
+    fn test_malformed_input_does_not_panic() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut builder = crate::VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("id", 12345678i64);
+        obj.insert(
+            "name",
+            "a string that does not fit in a short string, for sure",
+        );
+        obj.new_list("tags")
+            .with_value("x")
+            .with_value(Variant::Null)
+            .with_value(1.5f64)
+            .finish();
+        obj.new_object("nested")
+            .with_field("flag", true)
+            .with_field("day", NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+            .with_field("uuid", Uuid::nil())
+            .with_field("time", NaiveTime::from_hms_opt(12, 30, 0).unwrap())
+            .with_field("price", VariantDecimal8::try_new(1234, 2).unwrap())
+            .finish();
+        obj.finish();
+        let (metadata, value) = builder.finish();
+        walk_validated(&Variant::try_new(&metadata, &value).unwrap());
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20_000 {
+            let mut metadata = metadata.clone();
+            let mut value = value.clone();
+            // Corrupt a few random bytes of either buffer, and sometimes truncate them
+            for _ in 0..rng.random_range(1..4) {
+                let buffer = match rng.random_bool(0.2) {
+                    true => &mut metadata,
+                    false => &mut value,
+                };
+                let i = rng.random_range(0..buffer.len());
+                buffer[i] = rng.random();
+            }
+            if rng.random_bool(0.1) {
+                value.truncate(rng.random_range(0..value.len()));
+            } else if rng.random_bool(0.05) {
+                metadata.truncate(rng.random_range(0..metadata.len()));
+            }
+
+            if let Ok(variant) = Variant::try_new(&metadata, &value) {
+                walk_validated(&variant);
+            }
+            let Ok(metadata) = VariantMetadata::try_new_with_shallow_validation(&metadata) else {
+                continue;
+            };
+            if let Ok(variant) =
+                Variant::try_new_with_metadata_and_shallow_validation(metadata, &value)
+            {
+                let _ = walk_unvalidated(&variant);
+            }
+        }
+
+        // Entirely random bytes
+        for _ in 0..20_000 {
+            let metadata: Vec<u8> = (0..rng.random_range(0..16)).map(|_| rng.random()).collect();
+            let value: Vec<u8> = (0..rng.random_range(0..32)).map(|_| rng.random()).collect();
+            if let Ok(variant) = Variant::try_new(&metadata, &value) {
+                walk_validated(&variant);
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        // Dictionary with a single field name "a"
+        let metadata = [0x01, 0x01, 0x00, 0x01, b'a'];
+        // Object {"a": null}: header, num_elements, field id, field offsets, value
+        let value = [0x02, 0x01, 0x00, 0x00, 0x01, 0x00];
+        validate(&metadata, &value).unwrap();
+
+        // Truncated value buffer
+        for len in 0..value.len() {
+            let err = validate(&metadata, &value[..len]).unwrap_err();
+            assert!(matches!(err, ArrowError::InvalidArgumentError(_)), "{err}");
+        }
+
+        // Truncated metadata buffer
+        let err = validate(&metadata[..4], &value).unwrap_err();
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)), "{err}");
+
+        // Field id that is out of range for the dictionary
+        let value = [0x02, 0x01, 0x05, 0x00, 0x01, 0x00];
+        let err = validate(&metadata, &value).unwrap_err();
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)), "{err}");
+
+        // Field offset past the end of the value buffer
+        let value = [0x02, 0x01, 0x00, 0x00, 0x07, 0x00];
+        let err = validate(&metadata, &value).unwrap_err();
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)), "{err}");
+    }
+
+    #[test]
+    fn test_metadata_keys() {
+        let mut builder = crate::VariantBuilder::new().with_field_names(["unused", "a"]);
+        let mut list = builder.new_list();
+        list.new_object().with_field("a", 1).finish();
+        list.new_object().with_field("b", 2).finish();
+        list.finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::new(&metadata, &value);
+
+        let keys: Vec<_> = variant.metadata_keys().collect();
+        assert_eq!(keys, ["unused", "a", "b"]);
+
+        // Nested values share the whole dictionary, even names they do not use
+        let element = variant.get_list_element(0).unwrap();
+        assert_eq!(element.metadata_keys().collect::<Vec<_>>(), keys);
+        let fields: Vec<_> = element
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(fields, ["a"]);
+
+        assert_eq!(Variant::from(1).metadata_keys().count(), 0);
+    }
+
+    #[test]
+    fn test_get_path_borrows_buffers() {
+        let mut builder = crate::VariantBuilder::new();
+        let mut object = builder.new_object();
+        object.new_list("a").with_value("x").with_value(2).finish();
+        object.finish();
+        let (metadata, value) = builder.finish();
+
+        // The result of `get_path` does not borrow from the (temporary) variant it was called on
+        fn get<'m, 'v>(metadata: &'m [u8], value: &'v [u8], path: &str) -> Option<Variant<'m, 'v>> {
+            Variant::new(metadata, value).get_path(&VariantPath::try_from(path).unwrap())
+        }
+        let element = get(&metadata, &value, "a[1]").unwrap();
+        assert_eq!(element, Variant::from(2));
+
+        let list = get(&metadata, &value, "a").unwrap();
+        let Variant::List(list) = list else {
+            panic!("expected a list");
+        };
+        let variant = Variant::new(&metadata, &value);
+        assert_eq!(
+            Some(list.get(0).unwrap()),
+            variant.get_path(&VariantPath::try_from("a[0]").unwrap())
+        );
+        // The list is a view into the original value buffer
+        assert!(value.as_ptr_range().contains(&list.value.as_ptr()));
+    }
+
+    #[test]
+    fn test_get_path_mixed_segments() {
+        let mut builder = crate::VariantBuilder::new();
+        let mut object = builder.new_object();
+        let mut items = object.new_list("items");
+        items.new_object().with_field("name", "a").finish();
+        items
+            .new_object()
+            .with_field("id", 2)
+            .with_field("name", "b")
+            .finish();
+        items.finish();
+        let mut matrix = object.new_list("matrix");
+        matrix.new_list().with_value(1).with_value(2).finish();
+        matrix
+            .new_list()
+            .with_value(3)
+            .with_value(4)
+            .with_value(5)
+            .finish();
+        matrix.finish();
+        object.finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::new(&metadata, &value);
+
+        let get = |path| variant.get_path(&VariantPath::try_from(path).unwrap());
+        assert_eq!(get("items[0].name"), Some(Variant::from("a")));
+        assert_eq!(get("items[1].name"), Some(Variant::from("b")));
+        assert_eq!(get("items[-1].id"), Some(Variant::from(2)));
+        assert_eq!(get("items[0].id"), None);
+        assert_eq!(get("items[2].name"), None);
+        assert_eq!(get("matrix[1][2]"), Some(Variant::from(5)));
+        assert_eq!(get("matrix[0][1]"), Some(Variant::from(2)));
+        assert_eq!(get("matrix[0][2]"), None);
+        assert_eq!(get("matrix[1].name"), None);
+    }
+
+    #[test]
+    fn test_len() {
+        let mut builder = crate::VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.new_object()
+            .with_field("a", 1)
+            .with_field("b", 2)
+            .finish();
+        list.append_value(3);
+        list.new_list().finish();
+        list.finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::new(&metadata, &value);
+
+        assert_eq!(variant.len(), Some(3));
+        assert_eq!(variant.get_list_element(0).unwrap().len(), Some(2));
+        assert_eq!(variant.get_list_element(1).unwrap().len(), None);
+        assert_eq!(variant.get_list_element(2).unwrap().len(), Some(0));
+        assert_eq!(variant.get_list_element(2).unwrap().is_empty(), Some(true));
+        assert_eq!(variant.is_empty(), Some(false));
+        assert_eq!(Variant::from("abc").len(), None);
+        assert_eq!(Variant::Null.len(), None);
+    }
+
     #[test]
     fn test_empty_variant_will_fail() {
         let metadata = VariantMetadata::try_new(&[1, 0, 0]).unwrap();
@@ -0,0 +1,516 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::Variant;
+use chrono::NaiveDateTime;
+use std::cmp::Ordering;
+
+/// Compares two variants according to a total order over all variant values.
+///
+/// Values of different kinds are ordered by the following precedence, smallest first:
+///
+/// 1. `null`
+/// 2. booleans, with `false < true`
+/// 3. numbers (integers, decimals and floating point), compared by mathematical value, so for
+///    example `Int64(1)` and `Double(1.0)` are equal. `NaN` is greater than every other number and
+///    equal to itself, and `-0.0` equals `0.0`. Floating point values are compared with integers
+///    and decimals exactly, without rounding either side, so for example `Int64(2^53 + 1)` is
+///    greater than `Double(2^53)`, and the decimal `0.1` is less than `Double(0.1)`, whose binary
+///    value is slightly larger.
+/// 4. dates
+/// 5. timestamps, compared by their time since the epoch regardless of their precision. Timestamps
+///    without a time zone are treated as if they were UTC.
+/// 6. times of day
+/// 7. strings, compared lexicographically by their UTF-8 bytes
+/// 8. binary values, compared lexicographically
+/// 9. UUIDs, compared by their bytes
+/// 10. lists, compared element by element, with a list that is a prefix of another ordering first
+/// 11. objects, compared field by field in field name order: first by name, then by value, with an
+///     object whose fields are a prefix of another ordering first
+///
+/// Two variants compare as [`Ordering::Equal`] exactly when they are semantically equal, even if
+/// they are physically encoded differently (e.g. different integer widths, short vs. long strings,
+/// or different metadata dictionaries).
+///
+/// # Panics
+///
+/// If the variants are not [fully validated] and contain invalid data.
+///
+/// [fully validated]: Variant#Validation
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{Variant, variant_cmp};
+/// # use std::cmp::Ordering;
+/// assert_eq!(variant_cmp(&Variant::Int64(1), &Variant::Double(1.0)), Ordering::Equal);
+/// assert_eq!(variant_cmp(&Variant::Int8(2), &Variant::Float(1.5)), Ordering::Greater);
+/// // Numbers order before strings
+/// assert_eq!(variant_cmp(&Variant::Int64(100), &Variant::from("1")), Ordering::Less);
+/// assert_eq!(variant_cmp(&Variant::Null, &Variant::BooleanFalse), Ordering::Less);
+/// ```
+pub fn variant_cmp(a: &Variant<'_, '_>, b: &Variant<'_, '_>) -> Ordering {
+    let (a, b) = (Sortable::from(a), Sortable::from(b));
+    match (&a, &b) {
+        (Sortable::Boolean(a), Sortable::Boolean(b)) => a.cmp(b),
+        (Sortable::Number(a), Sortable::Number(b)) => a.cmp(b),
+        (Sortable::Date(a), Sortable::Date(b)) => a.cmp(b),
+        (Sortable::Timestamp(a), Sortable::Timestamp(b)) => a.cmp(b),
+        (Sortable::Time(a), Sortable::Time(b)) => a.cmp(b),
+        (Sortable::String(a), Sortable::String(b)) => a.cmp(b),
+        (Sortable::Binary(a), Sortable::Binary(b)) => a.cmp(b),
+        (Sortable::Uuid(a), Sortable::Uuid(b)) => a.cmp(b),
+        (Sortable::List(a), Sortable::List(b)) => {
+            let elements = a.iter().zip(b.iter());
+            elements
+                .map(|(a, b)| variant_cmp(&a, &b))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+        (Sortable::Object(a), Sortable::Object(b)) => {
+            let fields = a.iter().zip(b.iter());
+            fields
+                .map(|((a_name, a), (b_name, b))| {
+                    a_name.cmp(b_name).then_with(|| variant_cmp(&a, &b))
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+        _ => a.rank().cmp(&b.rank()),
+    }
+}
+
+/// Like [`variant_cmp`], but only orders values of the same kind (e.g. two numbers, or two
+/// strings), returning `None` for values of different kinds.
+///
+/// This suits predicates such as `v > 18`, where a string or an object is neither less nor greater
+/// than a number. Only the top-level kinds are checked: the elements of lists and the fields of
+/// objects are still compared by [`variant_cmp`].
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{Variant, variant_partial_cmp};
+/// # use std::cmp::Ordering;
+/// assert_eq!(variant_partial_cmp(&Variant::Int8(2), &Variant::Double(1.5)), Some(Ordering::Greater));
+/// assert_eq!(variant_partial_cmp(&Variant::Int8(2), &Variant::from("1")), None);
+/// assert_eq!(variant_partial_cmp(&Variant::Null, &Variant::Null), Some(Ordering::Equal));
+/// ```
+pub fn variant_partial_cmp(a: &Variant<'_, '_>, b: &Variant<'_, '_>) -> Option<Ordering> {
+    let same_kind = Sortable::from(a).rank() == Sortable::from(b).rank();
+    same_kind.then(|| variant_cmp(a, b))
+}
+
+/// A variant value, normalized so that semantically equal values have the same representation
+enum Sortable<'a, 'm, 'v> {
+    Null,
+    Boolean(bool),
+    Number(Number),
+    Date(chrono::NaiveDate),
+    Timestamp(NaiveDateTime),
+    Time(chrono::NaiveTime),
+    String(&'a str),
+    Binary(&'v [u8]),
+    Uuid(uuid::Uuid),
+    List(&'a crate::VariantList<'m, 'v>),
+    Object(&'a crate::VariantObject<'m, 'v>),
+}
+
+impl Sortable<'_, '_, '_> {
+    /// The position of this kind of value in the type precedence
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Null => 0,
+            Self::Boolean(_) => 1,
+            Self::Number(_) => 2,
+            Self::Date(_) => 3,
+            Self::Timestamp(_) => 4,
+            Self::Time(_) => 5,
+            Self::String(_) => 6,
+            Self::Binary(_) => 7,
+            Self::Uuid(_) => 8,
+            Self::List(_) => 9,
+            Self::Object(_) => 10,
+        }
+    }
+}
+
+impl<'a, 'm, 'v> From<&'a Variant<'m, 'v>> for Sortable<'a, 'm, 'v> {
+    fn from(variant: &'a Variant<'m, 'v>) -> Self {
+        match variant {
+            Variant::Null => Self::Null,
+            Variant::BooleanTrue => Self::Boolean(true),
+            Variant::BooleanFalse => Self::Boolean(false),
+            Variant::Int8(v) => Self::Number(Number::Exact(*v as i128, 0)),
+            Variant::Int16(v) => Self::Number(Number::Exact(*v as i128, 0)),
+            Variant::Int32(v) => Self::Number(Number::Exact(*v as i128, 0)),
+            Variant::Int64(v) => Self::Number(Number::Exact(*v as i128, 0)),
+            Variant::Decimal4(d) => Self::Number(Number::Exact(d.integer() as i128, d.scale())),
+            Variant::Decimal8(d) => Self::Number(Number::Exact(d.integer() as i128, d.scale())),
+            Variant::Decimal16(d) => Self::Number(Number::Exact(d.integer(), d.scale())),
+            Variant::Float(v) => Self::Number(Number::Float(*v as f64)),
+            Variant::Double(v) => Self::Number(Number::Float(*v)),
+            Variant::Date(v) => Self::Date(*v),
+            Variant::TimestampMicros(v) | Variant::TimestampNanos(v) => {
+                Self::Timestamp(v.naive_utc())
+            }
+            Variant::TimestampNtzMicros(v) | Variant::TimestampNtzNanos(v) => Self::Timestamp(*v),
+            Variant::Time(v) => Self::Time(*v),
+            Variant::String(s) => Self::String(s),
+            Variant::ShortString(s) => Self::String(s.as_str()),
+            Variant::Binary(v) => Self::Binary(v),
+            Variant::Uuid(v) => Self::Uuid(*v),
+            Variant::List(list) => Self::List(list),
+            Variant::Object(object) => Self::Object(object),
+        }
+    }
+}
+
+/// A numeric value: either an exact `(unscaled, scale)` decimal, or a floating point number
+#[derive(Debug, Clone, Copy)]
+enum Number {
+    Exact(i128, u8),
+    Float(f64),
+}
+
+impl Number {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (*self, *other) {
+            (Self::Exact(a, a_scale), Self::Exact(b, b_scale)) => {
+                cmp_decimals(a, a_scale, b, b_scale)
+            }
+            (Self::Exact(a, scale), Self::Float(b)) => cmp_decimal_float(a, scale, b),
+            (Self::Float(a), Self::Exact(b, scale)) => cmp_decimal_float(b, scale, a).reverse(),
+            (Self::Float(a), Self::Float(b)) => match (a.is_nan(), b.is_nan()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                // Neither is NaN, and -0.0 == 0.0
+                (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            },
+        }
+    }
+}
+
+/// Splits `value / 10^scale` into its integer part, rounded towards negative infinity, and the
+/// numerator of its non-negative fractional part.
+fn split_decimal(value: i128, scale: u8) -> (i128, i128) {
+    let divisor = 10i128.pow(scale as u32);
+    (value.div_euclid(divisor), value.rem_euclid(divisor))
+}
+
+/// Compares `a / 10^a_scale` with `b / 10^b_scale` exactly.
+///
+/// Variant decimals have at most 38 digits, so the fractional parts can always be rescaled to the
+/// larger scale without overflowing.
+fn cmp_decimals(a: i128, a_scale: u8, b: i128, b_scale: u8) -> Ordering {
+    let (a_int, a_frac) = split_decimal(a, a_scale);
+    let (b_int, b_frac) = split_decimal(b, b_scale);
+    let scale = a_scale.max(b_scale);
+    let rescale = |frac: i128, from: u8| frac * 10i128.pow((scale - from) as u32);
+    a_int
+        .cmp(&b_int)
+        .then_with(|| rescale(a_frac, a_scale).cmp(&rescale(b_frac, b_scale)))
+}
+
+/// Compares `a / 10^scale` with the float `b` exactly, ordering `NaN` after every number.
+fn cmp_decimal_float(a: i128, scale: u8, b: f64) -> Ordering {
+    // Variant decimals have at most 38 digits, so their magnitude is below 2^127. This also
+    // handles infinities.
+    const LIMIT: f64 = (1u128 << 127) as f64;
+    if b.is_nan() || b >= LIMIT {
+        return Ordering::Less;
+    }
+    if b < 0.0 {
+        return cmp_decimal_float(-a, scale, -b).reverse();
+    }
+    // The floor of `b` is an integer in `[0, 2^127)`, so it converts to i128 exactly. As `b` is
+    // not negative, the fractional part `b - floor` is also computed exactly.
+    let floor = b.floor();
+    let (a_int, a_frac) = split_decimal(a, scale);
+    a_int
+        .cmp(&(floor as i128))
+        .then_with(|| cmp_fraction(a_frac, scale, b - floor))
+}
+
+/// Compares `numerator / 10^scale` with the float `fraction`, both in `[0, 1)`, exactly.
+fn cmp_fraction(numerator: i128, scale: u8, fraction: f64) -> Ordering {
+    if fraction == 0.0 {
+        return numerator.cmp(&0);
+    }
+    // `fraction` is `mantissa / 2^shift`, with `shift > 0` since `fraction < 1`
+    let bits = fraction.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as u32;
+    let mantissa = bits & ((1 << 52) - 1);
+    let (mantissa, shift) = match exponent {
+        0 => (mantissa, 1074),
+        _ => (mantissa | (1 << 52), 1075 - exponent),
+    };
+
+    // Compute `fraction * 10^scale = digits + rest` exactly, where `digits` is an integer and
+    // `0 <= rest < 1`, from the 256-bit product `mantissa * 10^scale`
+    let power = 10u128.pow(scale as u32);
+    let low = mantissa as u128 * (power as u64 as u128);
+    let high = mantissa as u128 * (power >> 64);
+    let (low, carry) = low.overflowing_add(high << 64);
+    let high = (high >> 64) + carry as u128;
+    let (digits, has_rest) = match shift {
+        0..128 => (
+            (low >> shift) | (high << (128 - shift)),
+            low & ((1 << shift) - 1) != 0,
+        ),
+        128..256 => (
+            high >> (shift - 128),
+            low != 0 || high & ((1 << (shift - 128)) - 1) != 0,
+        ),
+        _ => (0, true),
+    };
+
+    // `digits < 10^scale`, so it fits in an i128
+    let rest = match has_rest {
+        true => Ordering::Less,
+        false => Ordering::Equal,
+    };
+    numerator.cmp(&(digits as i128)).then(rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{VariantBuilder, VariantDecimal4, VariantDecimal8, VariantDecimal16};
+    use chrono::{DateTime, NaiveDate, NaiveTime};
+
+    fn assert_order(values: &[Variant]) {
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                assert_eq!(variant_cmp(a, b), i.cmp(&j), "comparing {a:?} with {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_type_precedence() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let mut list = VariantBuilder::new();
+        list.new_list().with_value(1).finish();
+        let (list_metadata, list_value) = list.finish();
+        let mut object = VariantBuilder::new();
+        object.new_object().with_field("a", 1).finish();
+        let (object_metadata, object_value) = object.finish();
+
+        assert_order(&[
+            Variant::Null,
+            Variant::BooleanFalse,
+            Variant::BooleanTrue,
+            Variant::Int64(i64::MIN),
+            Variant::Double(f64::NAN),
+            Variant::Date(date),
+            Variant::TimestampNtzMicros(date.and_hms_opt(0, 0, 0).unwrap()),
+            Variant::Time(NaiveTime::from_hms_opt(1, 2, 3).unwrap()),
+            Variant::from(""),
+            Variant::from("a"),
+            Variant::Binary(b""),
+            Variant::Uuid(uuid::Uuid::nil()),
+            Variant::new(&list_metadata, &list_value),
+            Variant::new(&object_metadata, &object_value),
+        ]);
+    }
+
+    #[test]
+    fn test_partial_cmp() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let cmp = |a: Variant, b: Variant| variant_partial_cmp(&a, &b);
+        assert_eq!(
+            cmp(Variant::Int64(1), Variant::Double(1.0)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            cmp(Variant::from("b"), Variant::from("a")),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            cmp(Variant::BooleanFalse, Variant::BooleanTrue),
+            Some(Ordering::Less)
+        );
+        assert_eq!(cmp(Variant::Int64(1), Variant::from("1")), None);
+        assert_eq!(cmp(Variant::Int64(1), Variant::BooleanTrue), None);
+        assert_eq!(cmp(Variant::Null, Variant::Int64(1)), None);
+        assert_eq!(cmp(Variant::Date(date), Variant::Int32(0)), None);
+    }
+
+    #[test]
+    fn test_numbers() {
+        const MAX_DECIMAL16: i128 = 10i128.pow(38) - 1;
+        let eq = |a: Variant, b: Variant| assert_eq!(variant_cmp(&a, &b), Ordering::Equal);
+        eq(Variant::Int64(1), Variant::Double(1.0));
+        eq(Variant::Int8(-3), Variant::Int64(-3));
+        eq(Variant::Float(0.5), Variant::Double(0.5));
+        eq(Variant::Double(-0.0), Variant::Int32(0));
+        eq(Variant::Double(f64::NAN), Variant::Float(f32::NAN));
+        eq(
+            Variant::from(VariantDecimal4::try_new(150, 2).unwrap()),
+            Variant::from(VariantDecimal16::try_new(15000, 4).unwrap()),
+        );
+        eq(
+            Variant::from(VariantDecimal8::try_new(-25, 1).unwrap()),
+            Variant::Double(-2.5),
+        );
+        eq(
+            Variant::Int64(1 << 53),
+            Variant::Double((1u64 << 53) as f64),
+        );
+        eq(Variant::Int64(i64::MIN), Variant::Double(-(2f64.powi(63))));
+
+        assert_order(&[
+            Variant::Double(f64::NEG_INFINITY),
+            Variant::Int64(i64::MIN),
+            Variant::from(VariantDecimal8::try_new(-125, 2).unwrap()),
+            Variant::Int8(-1),
+            Variant::from(VariantDecimal4::try_new(-5, 1).unwrap()),
+            Variant::Int8(0),
+            Variant::Float(0.25),
+            Variant::from(VariantDecimal16::try_new(MAX_DECIMAL16, 38).unwrap()),
+            Variant::Int16(2),
+            Variant::from(VariantDecimal4::try_new(201, 2).unwrap()),
+            Variant::Int64(i64::MAX),
+            Variant::from(VariantDecimal16::try_new(MAX_DECIMAL16, 0).unwrap()),
+            Variant::Double(f64::INFINITY),
+            Variant::Double(f64::NAN),
+        ]);
+    }
+
+    #[test]
+    fn test_numbers_exact_against_floats() {
+        let decimal =
+            |unscaled, scale| Variant::from(VariantDecimal16::try_new(unscaled, scale).unwrap());
+        let two_pow_53 = (1u64 << 53) as f64;
+
+        // Integers that round to the same double are still ordered exactly
+        assert_order(&[
+            Variant::Int64((1 << 53) - 1),
+            Variant::Double(two_pow_53),
+            Variant::Int64((1 << 53) + 1),
+            Variant::Double(two_pow_53 + 2.0),
+        ]);
+        assert_order(&[
+            Variant::Int64(i64::MAX - 1),
+            Variant::Int64(i64::MAX),
+            Variant::Double(2f64.powi(63)),
+            Variant::from(VariantDecimal16::try_new(i64::MAX as i128 + 2, 0).unwrap()),
+        ]);
+
+        // Decimal fractions are compared with the exact binary value of floats
+        assert_order(&[
+            Variant::Double(-0.1),
+            decimal(-1, 1),
+            Variant::Double(0.0),
+            Variant::Double(5e-324),
+            decimal(1, 38),
+            decimal(1, 1),
+            Variant::Double(0.1),
+            Variant::Float(0.1),
+        ]);
+        assert_order(&[
+            Variant::Double(-4.946553099762881e-22),
+            decimal(-494655309976288, 36),
+            Variant::Double(-4.946553099762879e-22),
+        ]);
+        assert_order(&[
+            Variant::Double(1e38),
+            decimal(10i128.pow(38) - 1, 0),
+            Variant::Double(2f64.powi(127)),
+            Variant::Double(f64::MAX),
+        ]);
+        let eq = |a: Variant, b: Variant| assert_eq!(variant_cmp(&a, &b), Ordering::Equal);
+        eq(decimal(-125, 3), Variant::Double(-0.125));
+        eq(decimal(1, 0), Variant::Float(1.0));
+        eq(decimal(-(1 << 100), 0), Variant::Double(-(2f64.powi(100))));
+    }
+
+    #[test]
+    fn test_strings_and_timestamps() {
+        let long = "a string that is too long to be a short string, for sure";
+        assert_eq!(
+            variant_cmp(&Variant::String(long), &Variant::from(long)),
+            Ordering::Equal
+        );
+        assert_order(&[Variant::from("B"), Variant::from("a"), Variant::from("ab")]);
+
+        let micros = DateTime::from_timestamp_micros(1_000_001).unwrap();
+        let nanos = DateTime::from_timestamp_nanos(1_000_000_500);
+        assert_order(&[
+            Variant::TimestampMicros(DateTime::from_timestamp_micros(1_000_000).unwrap()),
+            Variant::TimestampNanos(nanos),
+            Variant::TimestampNtzMicros(micros.naive_utc()),
+        ]);
+        assert_eq!(
+            variant_cmp(
+                &Variant::TimestampMicros(micros),
+                &Variant::TimestampNtzNanos(micros.naive_utc())
+            ),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        let build = |f: &dyn Fn(&mut VariantBuilder)| {
+            let mut builder = VariantBuilder::new();
+            f(&mut builder);
+            builder.finish()
+        };
+
+        // Objects with the same fields compare equal, whatever order they were inserted in
+        let a = build(&|b| {
+            b.new_object()
+                .with_field("x", 1)
+                .with_field("y", "s")
+                .finish()
+        });
+        let b = build(&|b| {
+            b.new_object()
+                .with_field("y", "s")
+                .with_field("x", 1.0)
+                .finish()
+        });
+        let (a, b) = (Variant::new(&a.0, &a.1), Variant::new(&b.0, &b.1));
+        assert_eq!(variant_cmp(&a, &b), Ordering::Equal);
+
+        let objects = [
+            build(&|b| b.new_object().finish()),
+            build(&|b| b.new_object().with_field("x", 1).finish()),
+            build(&|b| {
+                b.new_object()
+                    .with_field("x", 1)
+                    .with_field("y", 0)
+                    .finish()
+            }),
+            build(&|b| b.new_object().with_field("x", 2).finish()),
+            build(&|b| b.new_object().with_field("y", 0).finish()),
+        ];
+        let objects: Vec<_> = objects.iter().map(|(m, v)| Variant::new(m, v)).collect();
+        assert_order(&objects);
+
+        let lists = [
+            build(&|b| b.new_list().finish()),
+            build(&|b| b.new_list().with_value(Variant::Null).finish()),
+            build(&|b| b.new_list().with_value(1).finish()),
+            build(&|b| b.new_list().with_value(1).with_value("a").finish()),
+            build(&|b| b.new_list().with_value(1.5).finish()),
+        ];
+        let lists: Vec<_> = lists.iter().map(|(m, v)| Variant::new(m, v)).collect();
+        assert_order(&lists);
+    }
+}
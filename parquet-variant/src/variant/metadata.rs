@@ -351,6 +351,11 @@ impl<'m> VariantMetadata<'m> {
         self.bytes.len()
     }
 
+    /// Returns the bytes that make up this metadata instance. See [`Self::size`].
+    pub fn as_bytes(&self) -> &'m [u8] {
+        self.bytes
+    }
+
     /// Attempts to retrieve a dictionary entry by index, failing if out of bounds or if the
     /// underlying bytes are [invalid].
     ///
@@ -353,12 +353,28 @@ impl<'m, 'v> VariantObject<'m, 'v> {
         })
     }
 
+    /// Get the metadata dictionary id of a field's name by index in `0..self.len()`
+    ///
+    /// # Panics
+    /// If the variant object is corrupted (e.g., invalid offsets or field IDs).
+    /// This should never happen since the constructor validates all data upfront.
+    pub fn field_id(&self, i: usize) -> Option<u32> {
+        (i < self.len()).then(|| {
+            self.try_field_id(i)
+                .expect("Invalid variant object field id")
+        })
+    }
+
     /// Fallible version of `field_name`. Returns field name by index, capturing validation errors
     fn try_field_name(&self, i: usize) -> Result<&'m str, ArrowError> {
+        self.metadata.get(self.try_field_id(i)? as _)
+    }
+
+    // Attempts to retrieve the metadata dictionary id of the ith field's name.
+    fn try_field_id(&self, i: usize) -> Result<u32, ArrowError> {
         let byte_range = self.header.field_ids_start_byte() as _..self.first_field_offset_byte as _;
         let field_id_bytes = slice_from_slice(self.value, byte_range)?;
-        let field_id = self.header.field_id_size.unpack_u32(field_id_bytes, i)?;
-        self.metadata.get(field_id as _)
+        self.header.field_id_size.unpack_u32(field_id_bytes, i)
     }
 
     /// Returns an iterator of (name, value) pairs over the fields of this object.
@@ -401,6 +417,27 @@ impl<'m, 'v> VariantObject<'m, 'v> {
         let i = try_binary_search_range_by(0..self.len(), cmp)?.ok()?;
         self.field(i)
     }
+
+    /// Returns the value of the field whose name has id `field_id` in the metadata dictionary, if
+    /// any. See [`VariantMetadata::get_entry`] to find the id of a field name.
+    ///
+    /// This lets callers that look up the same field in many objects sharing a metadata dictionary
+    /// resolve the name once, instead of comparing field names in every object. The search cost is
+    /// logarithmic if [`VariantMetadata::is_sorted`] and linear otherwise.
+    pub fn get_by_field_id(&self, field_id: u32) -> Option<Variant<'m, 'v>> {
+        let id = |i| {
+            self.try_field_id(i)
+                .expect("Invalid variant object field id")
+        };
+        let i = if self.metadata.is_sorted() {
+            // Object fields are ordered by name, which a sorted dictionary also orders by id
+            let cmp = |i| Some(id(i).cmp(&field_id));
+            try_binary_search_range_by(0..self.len(), cmp)?.ok()?
+        } else {
+            (0..self.len()).find(|&i| id(i) == field_id)?
+        };
+        self.field(i)
+    }
 }
 
 // Custom implementation of PartialEq for variant objects
@@ -770,6 +807,28 @@ mod tests {
         // 2^24
     }
 
+    #[test]
+    fn test_get_by_field_id() {
+        for sorted in [false, true] {
+            let mut b = VariantBuilder::new().with_sorted_keys(sorted);
+            b.new_object()
+                .with_field("zebra", 1)
+                .with_field("apple", 2)
+                .with_field("mango", 3)
+                .finish();
+            let (m, v) = b.finish();
+            let variant = Variant::try_new(&m, &v).unwrap();
+            let obj = variant.as_object().unwrap();
+            assert_eq!(obj.metadata.is_sorted(), sorted);
+
+            for name in ["zebra", "apple", "mango"] {
+                let (field_id, _) = obj.metadata.get_entry(name).unwrap();
+                assert_eq!(obj.get_by_field_id(field_id), obj.get(name));
+            }
+            assert_eq!(obj.get_by_field_id(3), None);
+        }
+    }
+
     #[test]
     fn test_objects_with_same_fields_are_equal() {
         let mut b = VariantBuilder::new();
@@ -918,6 +977,25 @@ mod tests {
         assert_eq!(v1, v2);
     }
 
+    #[test]
+    fn test_object_field_id() {
+        let mut b = VariantBuilder::new();
+        let mut o = b.new_object();
+        o.insert("b", false);
+        o.insert("a", ());
+        o.finish();
+        let (m, v) = b.finish();
+
+        // Fields are stored sorted by name, but ids follow insertion order
+        let variant = Variant::try_new(&m, &v).unwrap();
+        let object = variant.as_object().unwrap();
+        assert_eq!(object.field_name(0), Some("a"));
+        assert_eq!(object.field_id(0), Some(1));
+        assert_eq!(object.field_name(1), Some("b"));
+        assert_eq!(object.field_id(1), Some(0));
+        assert_eq!(object.field_id(2), None);
+    }
+
     #[test]
     fn test_objects_with_differing_metadata_are_equal() {
         let mut b = VariantBuilder::new();
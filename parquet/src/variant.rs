@@ -140,18 +140,66 @@
 pub use parquet_variant::*;
 pub use parquet_variant_compute::*;
 
+use crate::arrow::ArrowWriter;
+use crate::errors::Result;
+use crate::file::metadata::ParquetMetaData;
+use crate::file::properties::WriterProperties;
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::Schema;
+use std::io::Write;
+use std::sync::Arc;
+
+/// Writes `array` to `writer` as a Parquet file with a single column named `column_name`,
+/// annotated with the Parquet `VARIANT` logical type, and returns the file's metadata.
+///
+/// Shredded arrays keep their `typed_value` columns, and unshredded arrays are written as just
+/// `metadata` and `value`. The rows are split into row groups according to `props` (see
+/// [`WriterProperties::max_row_group_size`]).
+///
+/// # Example
+/// ```
+/// # use parquet::variant::{VariantArrayBuilder, VariantBuilderExt, write_variant_parquet};
+/// let mut builder = VariantArrayBuilder::new(2);
+/// builder.new_object().with_field("name", "Alice").finish();
+/// builder.append_value(42);
+/// let array = builder.build();
+///
+/// let mut buffer = vec![];
+/// let metadata = write_variant_parquet(&array, "data", &mut buffer, None)?;
+/// assert_eq!(metadata.file_metadata().num_rows(), 2);
+/// # Ok::<(), parquet::errors::ParquetError>(())
+/// ```
+pub fn write_variant_parquet<W: Write + Send>(
+    array: &VariantArray,
+    column_name: &str,
+    writer: W,
+    props: Option<WriterProperties>,
+) -> Result<ParquetMetaData> {
+    let schema = Arc::new(Schema::new(vec![array.field(column_name)]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![ArrayRef::from(array.clone())])?;
+    let mut writer = ArrowWriter::try_new(writer, schema, props)?;
+    writer.write(&batch)?;
+    writer.close()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::arrow::ArrowWriter;
     use crate::arrow::arrow_reader::ArrowReaderBuilder;
     use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
+    use crate::file::properties::WriterProperties;
     use crate::file::reader::ChunkReader;
+    use crate::variant::write_variant_parquet;
+    use arrow::compute::concat_batches;
     use arrow::util::test_util::parquet_test_data;
-    use arrow_array::{ArrayRef, RecordBatch};
-    use arrow_schema::Schema;
+    use arrow_array::{ArrayRef, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
     use bytes::Bytes;
     use parquet_variant::{Variant, VariantBuilderExt};
-    use parquet_variant_compute::{VariantArray, VariantArrayBuilder, VariantType};
+    use parquet_variant_compute::{
+        VariantArray, VariantArrayBuilder, VariantType, json_to_variant, shred_variant,
+        unshred_variant,
+    };
     use std::path::PathBuf;
     use std::sync::Arc;
 
@@ -205,6 +253,58 @@ mod tests {
         );
     }
 
+    /// Writes shredded and unshredded arrays with the helper, split across several row groups,
+    /// and reads them back
+    #[test]
+    fn write_variant_parquet_roundtrip() {
+        let json: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": 1, "b": "x"}"#),
+            Some(r#"{"a": "two"}"#),
+            None,
+            Some("[1, 2]"),
+            Some(r#"{"b": "y"}"#),
+        ]));
+        let array = json_to_variant(&json).unwrap();
+        let shredded = shred_variant(
+            &array,
+            &DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into()),
+        )
+        .unwrap();
+
+        for array in [array, shredded] {
+            let props = WriterProperties::builder()
+                .set_max_row_group_row_count(Some(2))
+                .build();
+            let mut buffer = vec![];
+            let metadata = write_variant_parquet(&array, "data", &mut buffer, Some(props)).unwrap();
+            assert_eq!(metadata.num_row_groups(), 3);
+            let root = metadata.file_metadata().schema_descr().root_schema();
+            assert_eq!(
+                root.get_fields()[0].get_basic_info().logical_type_ref(),
+                Some(&crate::basic::LogicalType::Variant {
+                    specification_version: None
+                })
+            );
+
+            let reader = ArrowReaderBuilder::try_new(Bytes::from(buffer))
+                .unwrap()
+                .build()
+                .unwrap();
+            let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            let batch = concat_batches(&batches[0].schema(), &batches).unwrap();
+            assert_variant_metadata(&batch, "data");
+            let result = VariantArray::try_new(batch.column(0)).unwrap();
+            assert_eq!(result.data_type(), array.data_type());
+            // Compare the values row by row, which requires unshredding nested objects
+            let result = unshred_variant(&result).unwrap();
+            let expected = unshred_variant(&array).unwrap();
+            assert_eq!(
+                result.iter().collect::<Vec<_>>(),
+                expected.iter().collect::<Vec<_>>()
+            );
+        }
+    }
+
     /// Return a VariantArray with 3 rows:
     ///
     /// 1. `{"name": "Alice"}`